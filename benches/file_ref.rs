@@ -0,0 +1,50 @@
+use std::hint::black_box;
+use criterion::{ Criterion, criterion_group, criterion_main };
+use file_ref::{ FileRef, TempDirRef };
+
+
+
+const SHORT_PATHS:[&str; 4] = ["src/main.rs", "target/app", "C:/file.txt", "docs/readme.md"];
+const LONG_PATH:&str = "/home/user/projects/application/target/debug/build/output.rs";
+
+
+
+/// Compare creating short paths, which are stored inline, to creating long paths, which are stored on the heap.
+fn bench_new(criterion:&mut Criterion) {
+	criterion.bench_function("new short paths", |bencher| bencher.iter(|| SHORT_PATHS.map(|path| FileRef::new(black_box(path)))));
+	criterion.bench_function("new long path", |bencher| bencher.iter(|| FileRef::new(black_box(LONG_PATH))));
+}
+
+/// Compare cloning an inline path to cloning the same path stored on the heap, like every path was before inline storage.
+fn bench_clone(criterion:&mut Criterion) {
+	let inline_paths:Vec<FileRef> = SHORT_PATHS.iter().map(|path| FileRef::new(path)).collect();
+	let heap_paths:Vec<FileRef> = SHORT_PATHS.iter().map(|path| FileRef::Owned(path.to_string())).collect();
+	criterion.bench_function("clone inline paths", |bencher| bencher.iter(|| black_box(&inline_paths).clone()));
+	criterion.bench_function("clone heap paths", |bencher| bencher.iter(|| black_box(&heap_paths).clone()));
+}
+
+/// Compare sorting a large list of inline paths to sorting the same paths stored on the heap.
+fn bench_sort(criterion:&mut Criterion) {
+	let names:Vec<String> = (0..10_000).map(|index| format!("d{}/f{index}.txt", index % 100)).collect();
+	let inline_paths:Vec<FileRef> = names.iter().rev().map(|name| FileRef::new(name)).collect();
+	let heap_paths:Vec<FileRef> = names.iter().rev().map(|name| FileRef::Owned(name.clone())).collect();
+	criterion.bench_function("sort inline paths", |bencher| bencher.iter(|| { let mut paths:Vec<FileRef> = inline_paths.clone(); paths.sort(); paths }));
+	criterion.bench_function("sort heap paths", |bencher| bencher.iter(|| { let mut paths:Vec<FileRef> = heap_paths.clone(); paths.sort(); paths }));
+}
+
+
+/// Measure recursively scanning a dir tree, which creates a path for every entry.
+fn bench_scan(criterion:&mut Criterion) {
+	let temp_dir:TempDirRef = TempDirRef::new("file_ref_bench_").unwrap();
+	for dir_index in 0..20 {
+		for file_index in 0..50 {
+			(temp_dir.path() + &format!("/dir_{dir_index}/file_{file_index}.txt")).write("").unwrap();
+		}
+	}
+	criterion.bench_function("scan dir tree", |bencher| bencher.iter(|| temp_dir.path().scanner().include_files().include_dirs().recurse().collect::<Vec<FileRef>>()));
+}
+
+
+
+criterion_group!(benches, bench_new, bench_clone, bench_sort, bench_scan);
+criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,137 @@
+use std::{ collections::HashSet, error::Error };
+use crate::{ DirEntries, DirRef, FileOperation, FileRef, FileRefError, HashAlgorithm, SEPARATOR, TempFileRef };
+
+
+
+const BLOB_EXTENSION:&str = "blob";
+const SHARD_LENGTH:usize = 2;
+
+
+
+/// A content-addressed store of files in a dir. Every blob is stored once under its hash, like 'ab/cdef0123.blob' for hash 'abcdef0123', so identical contents are deduplicated. The '.blob' extension keeps the files recognizable as files to FileRef.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlobStore {
+	root:FileRef,
+	algorithm:HashAlgorithm
+}
+impl BlobStore {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a store in the given dir using SHA-256 hashes. Creates the dir if it does not exist.
+	pub fn new(root:&FileRef) -> Result<BlobStore, Box<dyn Error>> {
+		BlobStore::with_algorithm(root, HashAlgorithm::Sha256)
+	}
+
+	/// Create a store in the given dir using the given hash algorithm. Creates the dir if it does not exist.
+	pub fn with_algorithm(root:&FileRef, algorithm:HashAlgorithm) -> Result<BlobStore, Box<dyn Error>> {
+		root.require_std_file_system(FileOperation::Write)?;
+		let root:DirRef = DirRef::create(root)?;
+		Ok(BlobStore { root: root.file_ref().clone().trim_end_matches(SEPARATOR), algorithm })
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the dir the store keeps its blobs in.
+	pub fn root(&self) -> &FileRef {
+		&self.root
+	}
+
+	/// Get the hash algorithm the store addresses blobs by.
+	pub fn algorithm(&self) -> HashAlgorithm {
+		self.algorithm
+	}
+
+	/// Get the file a blob with the given hash is stored in, whether it exists or not. Returns None if the hash is not a valid lowercase hex digest.
+	fn blob_file(&self, hash:&str) -> Option<FileRef> {
+		if hash.len() <= SHARD_LENGTH || !hash.bytes().all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte)) {
+			return None;
+		}
+		Some(FileRef::new(&format!("{}{SEPARATOR}{}{SEPARATOR}{}.{BLOB_EXTENSION}", self.root.path(), &hash[..SHARD_LENGTH], &hash[SHARD_LENGTH..])))
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Store a copy of the file. Returns the hash it is stored under. The file is copied to a staging file first and the copy is hashed, so the hash always matches the stored contents, even if the file changes meanwhile. Staged copies of contents that are already in the store are discarded.
+	pub fn put(&self, file:&FileRef) -> Result<String, Box<dyn Error>> {
+		file.require_std_file_system(FileOperation::Copy)?;
+		let staged:TempFileRef = self.stage(|staged| std::fs::copy(file.os_path(), staged.os_path()).map(|_| ()).map_err(|error| FileRefError::new(FileOperation::Copy, file, error).with_target(staged).into()))?;
+		let hash:String = staged.hash(self.algorithm)?;
+		let blob_file:FileRef = self.blob_file(&hash).unwrap();
+		if !blob_file.exists() {
+			self.commit(staged, &blob_file)?;
+		}
+		Ok(hash)
+	}
+
+	/// Store the given bytes. Returns the hash they are stored under.
+	pub fn put_bytes(&self, data:&[u8]) -> Result<String, Box<dyn Error>> {
+		let hash:String = self.algorithm.hash_bytes(data);
+		let blob_file:FileRef = self.blob_file(&hash).unwrap();
+		if !blob_file.exists() {
+			let staged:TempFileRef = self.stage(|staged| std::fs::write(staged.os_path(), data).map_err(|error| staged.error(FileOperation::Write, error)))?;
+			self.commit(staged, &blob_file)?;
+		}
+		Ok(hash)
+	}
+
+	/// Get the file the blob with the given hash is stored in. Returns None if the store does not contain the blob.
+	pub fn get(&self, hash:&str) -> Option<FileRef> {
+		self.blob_file(&hash.to_ascii_lowercase()).filter(|blob_file| blob_file.exists())
+	}
+
+	/// Check if the store contains a blob with the given hash.
+	pub fn contains(&self, hash:&str) -> bool {
+		self.get(hash).is_some()
+	}
+
+	/// Get the hashes of all blobs in the store.
+	pub fn hashes(&self) -> Result<Vec<String>, Box<dyn Error>> {
+		Ok(DirEntries::collect(&self.root)?.files.iter().filter_map(|(file, _)| self.hash_of(file)).collect())
+	}
+
+	/// Remove all blobs whose hash is not in the referenced set, and the shard dirs left empty. Returns the hashes of the removed blobs.
+	pub fn gc(&self, referenced:&HashSet<String>) -> Result<Vec<String>, Box<dyn Error>> {
+		let entries:DirEntries = DirEntries::collect(&self.root)?;
+		let mut removed:Vec<String> = Vec::new();
+		for (file, _) in &entries.files {
+			if let Some(hash) = self.hash_of(file).filter(|hash| !referenced.contains(hash)) {
+				std::fs::remove_file(file.os_path()).map_err(|error| file.error(FileOperation::Delete, error))?;
+				removed.push(hash);
+			}
+		}
+		for shard_dir in &entries.dirs {
+			if std::fs::read_dir(shard_dir.os_path()).is_ok_and(|mut shard_entries| shard_entries.next().is_none()) {
+				std::fs::remove_dir(shard_dir.os_path()).map_err(|error| shard_dir.error(FileOperation::Delete, error))?;
+			}
+		}
+		Ok(removed)
+	}
+
+	/// Get the hash of a blob file in the store. Returns None for files that are not blobs, like files being staged.
+	fn hash_of(&self, file:&FileRef) -> Option<String> {
+		let relative:FileRef = file.relative_to_dir(&self.root);
+		let (shard, name):(&str, &str) = relative.path().split_once(SEPARATOR)?;
+		let hash:String = format!("{shard}{}", name.strip_suffix(&format!(".{BLOB_EXTENSION}"))?);
+		self.blob_file(&hash).filter(|blob_file| blob_file == file).map(|_| hash)
+	}
+
+	/// Write a new blob to a temporary file in the store, so it can be moved into place in one step.
+	fn stage<T>(&self, write:T) -> Result<TempFileRef, Box<dyn Error>> where T:FnOnce(&FileRef) -> Result<(), Box<dyn Error>> {
+		let staged:TempFileRef = TempFileRef::new_in(&self.root, "staging")?;
+		write(staged.file_ref())?;
+		Ok(staged)
+	}
+
+	/// Move a staged blob into place. Another writer storing the same contents at the same time is fine, as both blobs are identical.
+	fn commit(&self, staged:TempFileRef, blob_file:&FileRef) -> Result<(), Box<dyn Error>> {
+		DirRef::create(&blob_file.parent_dir()?)?;
+		std::fs::rename(staged.os_path(), blob_file.os_path()).map_err(|error| staged.error(FileOperation::Move, error))?;
+		staged.keep();
+		Ok(())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+	use std::collections::HashSet;
+	use crate::{ BlobStore, FileRef, HashAlgorithm, TempDirRef };
+
+
+
+	#[test]
+	fn test_blob_store_put_get() {
+		let temp_dir:TempDirRef = TempDirRef::new("blob_store_test_").unwrap();
+		let store:BlobStore = BlobStore::new(&(temp_dir.path() + "/blobs")).unwrap();
+		let source:FileRef = temp_dir.path() + "/artifact.txt";
+		source.write("artifact").unwrap();
+
+		let hash:String = store.put(&source).unwrap();
+		assert_eq!(hash, HashAlgorithm::Sha256.hash_bytes(b"artifact"));
+		let blob:FileRef = store.get(&hash).unwrap();
+		assert_eq!(blob.path(), format!("{}/{}/{}.blob", store.root().path(), &hash[..2], &hash[2..]));
+		assert_eq!(blob.read().unwrap(), "artifact");
+		assert_eq!(store.put_bytes(b"artifact").unwrap(), hash);
+		assert_eq!(store.put(&source).unwrap(), hash);
+		assert_eq!(store.hashes().unwrap(), vec![hash.clone()]);
+
+		assert!(store.contains(&hash.to_uppercase()));
+		assert!(store.get(&HashAlgorithm::Sha256.hash_bytes(b"other")).is_none());
+		assert!(store.get("../escape").is_none());
+	}
+
+	#[test]
+	fn test_blob_store_gc() {
+		let temp_dir:TempDirRef = TempDirRef::new("blob_store_gc_test_").unwrap();
+		let store:BlobStore = BlobStore::with_algorithm(&temp_dir.path(), HashAlgorithm::Crc32).unwrap();
+		let kept:String = store.put_bytes(b"kept").unwrap();
+		let dropped:String = store.put_bytes(b"dropped").unwrap();
+
+		let removed:Vec<String> = store.gc(&HashSet::from([kept.clone()])).unwrap();
+		assert_eq!(removed, vec![dropped.clone()]);
+		assert!(store.contains(&kept));
+		assert!(!store.contains(&dropped));
+		assert!(!(temp_dir.path() + "/" + &dropped[..2]).exists() || dropped[..2] == kept[..2]);
+	}
+}
\ No newline at end of file
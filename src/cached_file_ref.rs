@@ -0,0 +1,95 @@
+use std::{ error::Error, io::{ Error as IoError, ErrorKind }, sync::{ Mutex, MutexGuard }, time::{ Duration, Instant } };
+use crate::{ FileMetadata, FileOperation, FileRef };
+
+
+
+#[derive(Clone, Copy)]
+struct CachedMetadata {
+	metadata:Option<FileMetadata>,
+	fetched:Instant
+}
+
+
+
+/// A FileRef that remembers the results of metadata lookups, so checking the same file repeatedly does not hit the filesystem every time. All values are derived from a single metadata lookup, which is kept until it is older than the TTL, or until `invalidate` is called.
+pub struct CachedFileRef {
+	file:FileRef,
+	ttl:Option<Duration>,
+	cache:Mutex<Option<CachedMetadata>>
+}
+impl CachedFileRef {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new cache for the file. Values are kept until `invalidate` is called.
+	pub fn new(file:&FileRef) -> CachedFileRef {
+		CachedFileRef { file: file.clone(), ttl: None, cache: Mutex::new(None) }
+	}
+
+	/// Return self with cached values expiring after the given duration.
+	pub fn with_ttl(mut self, ttl:Duration) -> Self {
+		self.ttl = Some(ttl);
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the file the metadata belongs to.
+	pub fn file(&self) -> &FileRef {
+		&self.file
+	}
+
+	/// Check if the file exists.
+	pub fn exists(&self) -> bool {
+		self.metadata().is_some()
+	}
+
+	/// Check if the file can be accessed, which is the case if its metadata could be read.
+	pub fn is_accessible(&self) -> bool {
+		self.metadata().is_some()
+	}
+
+	/// Get the size of the file in bytes.
+	pub fn size(&self) -> Result<u64, Box<dyn Error>> {
+		self.metadata().map(|metadata| metadata.size).ok_or_else(|| self.file.error(FileOperation::Read, IoError::from(ErrorKind::NotFound)))
+	}
+
+	/// Get the metadata of the file, or None if it could not be read.
+	pub fn metadata(&self) -> Option<FileMetadata> {
+		let mut cache:MutexGuard<Option<CachedMetadata>> = self.lock_cache();
+		if let Some(cached) = *cache {
+			if self.ttl.is_none_or(|ttl| cached.fetched.elapsed() < ttl) {
+				return cached.metadata;
+			}
+		}
+		let metadata:Option<FileMetadata> = self.file.metadata().ok();
+		*cache = Some(CachedMetadata { metadata, fetched: Instant::now() });
+		metadata
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Forget the cached metadata, for example after modifying the file.
+	pub fn invalidate(&self) {
+		*self.lock_cache() = None;
+	}
+
+	/// Lock the cache, recovering it if another thread panicked while holding it.
+	fn lock_cache(&self) -> MutexGuard<'_, Option<CachedMetadata>> {
+		self.cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+
+
+impl FileRef {
+
+	/// Create a metadata cache for the file.
+	pub fn cached(&self) -> CachedFileRef {
+		CachedFileRef::new(self)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+	use crate::{ CachedFileRef, FileRef, TempDirRef };
+
+
+
+	#[test]
+	fn test_cached_until_invalidated() {
+		let temp_dir:TempDirRef = TempDirRef::new("cached_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/data.txt";
+		file.write("1234").unwrap();
+
+		let cached:CachedFileRef = file.cached();
+		assert!(cached.exists());
+		assert!(cached.is_accessible());
+		assert_eq!(cached.size().unwrap(), 4);
+		assert!(cached.metadata().unwrap().is_file());
+
+		file.write("123456").unwrap();
+		assert_eq!(cached.size().unwrap(), 4);
+		assert_eq!(cached.metadata().unwrap().size, 4);
+		file.delete().unwrap();
+		assert!(cached.exists());
+
+		cached.invalidate();
+		assert!(!cached.exists());
+		assert!(cached.size().is_err());
+	}
+
+	#[test]
+	fn test_cached_ttl() {
+		let temp_dir:TempDirRef = TempDirRef::new("cached_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/data.txt";
+
+		let cached:CachedFileRef = CachedFileRef::new(&file).with_ttl(Duration::ZERO);
+		assert!(!cached.exists());
+		file.write("contents").unwrap();
+		assert!(cached.exists());
+
+		let cached:CachedFileRef = CachedFileRef::new(&file).with_ttl(Duration::from_secs(60));
+		assert_eq!(cached.size().unwrap(), 8);
+		file.write("more contents").unwrap();
+		assert_eq!(cached.size().unwrap(), 8);
+	}
+}
\ No newline at end of file
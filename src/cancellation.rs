@@ -0,0 +1,97 @@
+use std::{ error::Error, fmt::{ self, Debug, Display, Formatter }, sync::{ Arc, atomic::{ AtomicBool, Ordering } } };
+use crate::{ Progress, ProgressUpdate };
+
+
+
+/// A flag that can be set from another thread to stop long running operations. Clones share the same flag.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+	cancelled:Arc<AtomicBool>
+}
+impl CancellationToken {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new token that is not cancelled.
+	pub fn new() -> CancellationToken {
+		CancellationToken::default()
+	}
+
+	/// Combine the token with a progress reporter, so an operation can both report its progress and be cancelled.
+	pub fn with_progress<T:Progress>(&self, progress:T) -> CancellableProgress<T> {
+		CancellableProgress { progress, token: self.clone() }
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Request all operations using this token to stop.
+	pub fn cancel(&self) {
+		self.cancelled.store(true, Ordering::SeqCst);
+	}
+
+	/// Check if cancellation was requested.
+	pub fn is_cancelled(&self) -> bool {
+		self.cancelled.load(Ordering::SeqCst)
+	}
+}
+impl Progress for CancellationToken {
+	fn update(&self, _update:&ProgressUpdate) {}
+
+	fn is_cancelled(&self) -> bool {
+		CancellationToken::is_cancelled(self)
+	}
+}
+
+
+
+/// A progress reporter that can be cancelled by a token.
+pub struct CancellableProgress<T:Progress> {
+	progress:T,
+	token:CancellationToken
+}
+impl<T:Progress> Progress for CancellableProgress<T> {
+	fn update(&self, update:&ProgressUpdate) {
+		self.progress.update(update);
+	}
+
+	fn is_cancelled(&self) -> bool {
+		self.token.is_cancelled() || self.progress.is_cancelled()
+	}
+}
+
+
+
+/// The error returned by an operation that was cancelled. Contains the partial result of the operation, like the report of the files synced so far.
+pub struct Cancelled<T> {
+	partial:T
+}
+impl<T> Cancelled<T> {
+
+	/// Create a new cancellation error with the partial result of the operation.
+	pub fn new(partial:T) -> Cancelled<T> {
+		Cancelled { partial }
+	}
+
+	/// Get the partial result of the operation.
+	pub fn partial(&self) -> &T {
+		&self.partial
+	}
+
+	/// Take the partial result of the operation.
+	pub fn into_partial(self) -> T {
+		self.partial
+	}
+}
+impl<T:Debug> Debug for Cancelled<T> {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Cancelled").field("partial", &self.partial).finish()
+	}
+}
+impl<T> Display for Cancelled<T> {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(f, "Operation was cancelled.")
+	}
+}
+impl<T:Debug> Error for Cancelled<T> {}
\ No newline at end of file
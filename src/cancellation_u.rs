@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+	use std::error::Error;
+	use crate::{ CancellableProgress, CancellationToken, Cancelled, FileRef, ProgressUpdate, SyncOptions, SyncReport, TempDirRef };
+
+
+
+	fn create_source_dir(temp_dir:&TempDirRef) -> FileRef {
+		let source_dir:FileRef = temp_dir.path() + "/source";
+		for index in 0..5 {
+			(source_dir.clone() + &format!("/file_{index}.txt")).write("contents").unwrap();
+		}
+		source_dir
+	}
+
+
+
+	#[test]
+	fn test_token_shared_between_clones() {
+		let token:CancellationToken = CancellationToken::new();
+		let clone:CancellationToken = token.clone();
+		assert!(!clone.is_cancelled());
+		token.cancel();
+		assert!(clone.is_cancelled());
+	}
+
+	#[test]
+	fn test_cancel_sync_returns_partial_report() {
+		let temp_dir:TempDirRef = TempDirRef::new("cancellation_test_").unwrap();
+		let source_dir:FileRef = create_source_dir(&temp_dir);
+
+		// Cancel after the second file was synced.
+		let token:CancellationToken = CancellationToken::new();
+		let callback_token:CancellationToken = token.clone();
+		let progress:CancellableProgress<_> = token.with_progress(move |update:&ProgressUpdate| if update.bytes_done >= 16 { callback_token.cancel() });
+		let error:Box<dyn Error> = source_dir.sync_to_with_progress(&(temp_dir.path() + "/target"), SyncOptions::new(), &progress).unwrap_err();
+		let report:&SyncReport = error.downcast_ref::<Cancelled<SyncReport>>().unwrap().partial();
+		assert_eq!(report.created.len(), 2);
+		assert_eq!((temp_dir.path() + "/target").list_files().len(), 2);
+	}
+
+	#[test]
+	#[cfg(feature="hashing")]
+	fn test_cancel_hash() {
+		use crate::HashAlgorithm;
+
+		let temp_dir:TempDirRef = TempDirRef::new("cancellation_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/data.bin";
+		file.write_bytes(&[1; 1000]).unwrap();
+
+		let token:CancellationToken = CancellationToken::new();
+		token.cancel();
+		let error:Box<dyn Error> = file.hash_with_progress(HashAlgorithm::Sha256, &token).unwrap_err();
+		assert_eq!(error.downcast_ref::<Cancelled<u64>>().unwrap().partial(), &0);
+	}
+
+	#[test]
+	fn test_cancel_scanner() {
+		let temp_dir:TempDirRef = TempDirRef::new("cancellation_test_").unwrap();
+		let source_dir:FileRef = create_source_dir(&temp_dir);
+
+		let token:CancellationToken = CancellationToken::new();
+		let mut found:Vec<FileRef> = Vec::new();
+		for file in source_dir.scanner().include_files().cancel_on(&token) {
+			found.push(file);
+			if found.len() == 3 {
+				token.cancel();
+			}
+		}
+		assert_eq!(found.len(), 3);
+	}
+}
\ No newline at end of file
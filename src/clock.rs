@@ -0,0 +1,91 @@
+use std::{ cell::RefCell, sync::{ Arc, Mutex, MutexGuard }, time::{ Duration, SystemTime } };
+
+
+
+thread_local! {
+	static CLOCK_OVERRIDE:RefCell<Option<Arc<dyn Clock>>> = const { RefCell::new(None) };
+}
+
+
+
+/// A source of the current time. Time-dependent operations, like `cleanup_older_than` and `timestamped`, read the time from the clock set with `with_clock`, or from the system clock if none is set.
+pub trait Clock:Send + Sync {
+
+	/// Get the current time.
+	fn now(&self) -> SystemTime;
+}
+
+
+
+/// The clock of the OS.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+impl Clock for SystemClock {
+	fn now(&self) -> SystemTime {
+		SystemTime::now()
+	}
+}
+
+
+
+/// A clock that only moves when told to, for deterministic tests of time-dependent operations.
+#[derive(Debug)]
+pub struct ManualClock {
+	time:Mutex<SystemTime>
+}
+impl ManualClock {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new clock stopped at the given time.
+	pub fn new(time:SystemTime) -> ManualClock {
+		ManualClock { time: Mutex::new(time) }
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Set the time of the clock.
+	pub fn set(&self, time:SystemTime) {
+		*self.time.lock().unwrap() = time;
+	}
+
+	/// Move the clock forward by the given duration.
+	pub fn advance(&self, duration:Duration) {
+		let mut time:MutexGuard<SystemTime> = self.time.lock().unwrap();
+		*time += duration;
+	}
+}
+impl Clock for ManualClock {
+	fn now(&self) -> SystemTime {
+		*self.time.lock().unwrap()
+	}
+}
+
+
+
+/// Restores the previous clock of the thread when dropped, so a panicking action does not leave its clock behind.
+struct ClockOverrideGuard {
+	previous:Option<Arc<dyn Clock>>
+}
+impl Drop for ClockOverrideGuard {
+	fn drop(&mut self) {
+		let previous:Option<Arc<dyn Clock>> = self.previous.take();
+		CLOCK_OVERRIDE.with(|clock| *clock.borrow_mut() = previous);
+	}
+}
+
+
+
+/// Run an action with the given clock as the source of the current time. Only affects operations on the current thread, so tests running in parallel do not influence each other.
+pub fn with_clock<T, U>(clock:Arc<dyn Clock>, action:T) -> U where T:FnOnce() -> U {
+	let previous:Option<Arc<dyn Clock>> = CLOCK_OVERRIDE.with(|current| current.borrow_mut().replace(clock));
+	let _guard:ClockOverrideGuard = ClockOverrideGuard { previous };
+	action()
+}
+
+/// Get the current time from the clock of this thread.
+pub(crate) fn now() -> SystemTime {
+	CLOCK_OVERRIDE.with(|clock| clock.borrow().as_ref().map(|clock| clock.now())).unwrap_or_else(SystemTime::now)
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+	use std::{ sync::Arc, time::{ Duration, SystemTime, UNIX_EPOCH } };
+	use crate::{ Clock, FileRef, ManualClock, clock::now, with_clock };
+
+
+
+	#[test]
+	fn test_manual_clock() {
+		let clock:ManualClock = ManualClock::new(UNIX_EPOCH);
+		clock.advance(Duration::from_secs(90));
+		assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(90));
+		clock.set(UNIX_EPOCH + Duration::from_secs(5));
+		assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(5));
+	}
+
+	#[test]
+	fn test_with_clock() {
+		let clock:Arc<ManualClock> = Arc::new(ManualClock::new(UNIX_EPOCH + Duration::from_secs(1717245005)));
+		let name:String = with_clock(clock.clone(), || {
+			assert_eq!(now(), clock.now());
+			clock.advance(Duration::from_secs(1));
+			assert_eq!(now(), UNIX_EPOCH + Duration::from_secs(1717245006));
+			FileRef::new("backups").timestamped("backup", "tar").name().to_string()
+		});
+		assert_eq!(name, "backup-2024-06-01T12-30-06.tar");
+
+		// Other threads and code after the action use the system clock again.
+		let system_time:SystemTime = with_clock(clock, || std::thread::spawn(now).join().unwrap());
+		assert!(system_time > UNIX_EPOCH + Duration::from_secs(1717245006));
+		assert!(now() > UNIX_EPOCH + Duration::from_secs(1717245006));
+	}
+
+	#[test]
+	fn test_clock_restored_after_panic() {
+		let clock:Arc<ManualClock> = Arc::new(ManualClock::new(UNIX_EPOCH));
+		assert!(std::panic::catch_unwind(|| with_clock(clock, || panic!("action failed"))).is_err());
+		assert!(now() > UNIX_EPOCH);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CompressionLevel {
+	Store,
+	Fast,
+	#[default]
+	Default,
+	Best
+}
\ No newline at end of file
@@ -0,0 +1,131 @@
+use std::{ error::Error, marker::PhantomData };
+use serde::{ Serialize, de::DeserializeOwned };
+use crate::{ FileOperation, FileRef, default_if_not_found };
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigFormat {
+	#[cfg(feature="json")]
+	Json,
+	#[cfg(feature="toml")]
+	Toml,
+	#[cfg(feature="yaml")]
+	Yaml
+}
+impl ConfigFormat {
+
+	/// Find the format belonging to a file extension. Returns None for unknown extensions or formats whose feature is not enabled.
+	pub fn from_extension(extension:&str) -> Option<ConfigFormat> {
+		match extension.to_lowercase().as_str() {
+			#[cfg(feature="json")]
+			"json" => Some(ConfigFormat::Json),
+			#[cfg(feature="toml")]
+			"toml" => Some(ConfigFormat::Toml),
+			#[cfg(feature="yaml")]
+			"yaml" | "yml" => Some(ConfigFormat::Yaml),
+			_ => None
+		}
+	}
+
+	/// Serialize a value to text in this format.
+	pub fn serialize<T:Serialize>(&self, value:&T) -> Result<String, Box<dyn Error>> {
+		Ok(match self {
+			#[cfg(feature="json")]
+			ConfigFormat::Json => serde_json::to_string_pretty(value)?,
+			#[cfg(feature="toml")]
+			ConfigFormat::Toml => toml::to_string_pretty(value)?,
+			#[cfg(feature="yaml")]
+			ConfigFormat::Yaml => serde_yaml::to_string(value)?
+		})
+	}
+
+	/// Deserialize a value from text in this format.
+	pub fn deserialize<T:DeserializeOwned>(&self, text:&str) -> Result<T, Box<dyn Error>> {
+		Ok(match self {
+			#[cfg(feature="json")]
+			ConfigFormat::Json => serde_json::from_str(text)?,
+			#[cfg(feature="toml")]
+			ConfigFormat::Toml => toml::from_str(text)?,
+			#[cfg(feature="yaml")]
+			ConfigFormat::Yaml => serde_yaml::from_str(text)?
+		})
+	}
+}
+
+
+
+/// A file containing a typed config, stored as json, toml or yaml.
+pub struct ConfigFile<T:Serialize + DeserializeOwned> {
+	file:FileRef,
+	format:ConfigFormat,
+	config_type:PhantomData<T>
+}
+impl<T:Serialize + DeserializeOwned> ConfigFile<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new config file, detecting the format from the extension of the file.
+	pub fn new(file:&FileRef) -> Result<ConfigFile<T>, Box<dyn Error>> {
+		match file.extension().and_then(ConfigFormat::from_extension) {
+			Some(format) => Ok(ConfigFile::with_format(file, format)),
+			None => Err(file.error(FileOperation::Read, "Unknown config format extension."))
+		}
+	}
+
+	/// Create a new config file stored in a specific format, regardless of its extension.
+	pub fn with_format(file:&FileRef, format:ConfigFormat) -> ConfigFile<T> {
+		ConfigFile { file: file.clone(), format, config_type: PhantomData }
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the file the config is stored in.
+	pub fn file(&self) -> &FileRef {
+		&self.file
+	}
+
+	/// Get the format the config is stored in.
+	pub fn format(&self) -> ConfigFormat {
+		self.format
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Load the config from the file.
+	pub fn load(&self) -> Result<T, Box<dyn Error>> {
+		let text:String = self.file.read()?;
+		self.format.deserialize(&text).map_err(|error| self.file.error(FileOperation::Read, error))
+	}
+
+	/// Save the config to the file. The file is replaced atomically, so a crash while saving never leaves a half written config.
+	pub fn save(&self, config:&T) -> Result<(), Box<dyn Error>> {
+		let text:String = self.format.serialize(config)?;
+		self.file.write_atomic(text.as_bytes())
+	}
+}
+impl<T:Serialize + DeserializeOwned + Default> ConfigFile<T> {
+
+	/// Load the config from the file, or return the default config if the file does not exist.
+	pub fn load_or_default(&self) -> Result<T, Box<dyn Error>> {
+		default_if_not_found(self.load(), T::default)
+	}
+}
+
+
+
+#[cfg(feature="json")]
+impl FileRef {
+
+	/// Read the file as json, or return the default value if the file does not exist. Other errors, including invalid json, are still returned.
+	pub fn read_json_or_default<T:DeserializeOwned + Default>(&self) -> Result<T, Box<dyn Error>> {
+		match default_if_not_found(self.read().map(Some), || None)? {
+			Some(text) => ConfigFormat::Json.deserialize(&text),
+			None => Ok(T::default())
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+	use serde::{ Deserialize, Serialize };
+	use crate::{ ConfigFile, ConfigFormat, FileRef, TempDirRef };
+
+
+
+	#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+	struct TestConfig {
+		name:String,
+		retries:u32,
+		verbose:bool
+	}
+
+	fn test_config() -> TestConfig {
+		TestConfig { name: "service".to_string(), retries: 3, verbose: true }
+	}
+
+	fn assert_round_trip(extension:&str) {
+		let temp_dir:TempDirRef = TempDirRef::new("config_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + &format!("/nested/config.{extension}");
+
+		let config_file:ConfigFile<TestConfig> = ConfigFile::new(&file).unwrap();
+		config_file.save(&test_config()).unwrap();
+		assert_eq!(config_file.load().unwrap(), test_config());
+		assert!(!(file.clone() + ".tmp").exists());
+	}
+
+
+
+	#[test]
+	#[cfg(feature="json")]
+	fn test_config_json() {
+		assert_round_trip("json");
+	}
+
+	#[test]
+	#[cfg(feature="toml")]
+	fn test_config_toml() {
+		assert_round_trip("toml");
+	}
+
+	#[test]
+	#[cfg(feature="yaml")]
+	fn test_config_yaml() {
+		assert_round_trip("yaml");
+		assert_eq!(ConfigFormat::from_extension("YML"), Some(ConfigFormat::Yaml));
+	}
+
+	#[test]
+	fn test_config_unknown_extension() {
+		let temp_dir:TempDirRef = TempDirRef::new("config_file_test_").unwrap();
+		assert!(ConfigFile::<TestConfig>::new(&(temp_dir.path() + "/config.ini")).is_err());
+		assert_eq!(ConfigFormat::from_extension("ini"), None);
+	}
+
+	#[test]
+	#[cfg(feature="json")]
+	fn test_config_load_or_default() {
+		let temp_dir:TempDirRef = TempDirRef::new("config_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/config.json";
+		let config_file:ConfigFile<TestConfig> = ConfigFile::new(&file).unwrap();
+		assert_eq!(config_file.load_or_default().unwrap(), TestConfig::default());
+
+		file.write("{ \"name\": 5 }").unwrap();
+		assert!(config_file.load_or_default().is_err());
+	}
+
+	#[test]
+	#[cfg(feature="json")]
+	fn test_read_json_or_default() {
+		let temp_dir:TempDirRef = TempDirRef::new("config_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/state.json";
+		assert_eq!(file.read_json_or_default::<TestConfig>().unwrap(), TestConfig::default());
+
+		ConfigFile::new(&file).unwrap().save(&test_config()).unwrap();
+		assert_eq!(file.read_json_or_default::<TestConfig>().unwrap(), test_config());
+
+		file.write("not json").unwrap();
+		assert!(file.read_json_or_default::<TestConfig>().is_err());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+use std::{ collections::BTreeMap, error::Error, sync::Arc };
+use crate::{ DirRef, FileOperation, FileRef, FileSystem, SEPARATOR };
+
+
+
+enum DirBuilderEntry {
+	File(Vec<u8>),
+	Dir(DirBuilder)
+}
+
+
+
+/// A declarative description of a dir structure that can be created in one call, like test fixtures or project scaffolding. The `dir_tree!` macro builds one from a nested literal.
+#[derive(Default)]
+pub struct DirBuilder {
+	entries:Vec<(String, DirBuilderEntry)>
+}
+impl DirBuilder {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new builder without any entries.
+	pub fn new() -> DirBuilder {
+		DirBuilder::default()
+	}
+
+	/// Return self with a file with the given contents. Names may contain separators to place the file in sub-dirs.
+	pub fn file<T>(mut self, name:&str, contents:T) -> Self where T:AsRef<[u8]> {
+		self.entries.push((name.to_string(), DirBuilderEntry::File(contents.as_ref().to_vec())));
+		self
+	}
+
+	/// Return self with a sub-dir containing the entries of the given builder.
+	pub fn dir(mut self, name:&str, contents:DirBuilder) -> Self {
+		self.entries.push((name.to_string(), DirBuilderEntry::Dir(contents)));
+		self
+	}
+
+	/// Return self with an empty sub-dir.
+	pub fn empty_dir(self, name:&str) -> Self {
+		self.dir(name, DirBuilder::new())
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Create all entries in the given dir, creating the dir itself if it does not exist. Existing files are overwritten, names do not need an extension to be created as files.
+	pub fn build(&self, root:&FileRef) -> Result<DirRef, Box<dyn Error>> {
+		let root:DirRef = DirRef::create(root)?;
+		for (name, entry) in &self.entries {
+			let path:FileRef = root.child(name.trim_start_matches(SEPARATOR).trim_end_matches(SEPARATOR));
+			match entry {
+				DirBuilderEntry::Dir(contents) => {
+					contents.build(&path)?;
+				},
+				DirBuilderEntry::File(data) => {
+					DirRef::create(&path.parent_dir()?)?;
+					let file_system:Arc<dyn FileSystem> = path.file_system();
+					if !file_system.exists(path.path()) {
+						file_system.create(path.path(), false).map_err(|error| path.error(FileOperation::Create, error))?;
+					}
+					file_system.write_at(path.path(), None, data).map_err(|error| path.io_failure(FileOperation::Write, error))?;
+				}
+			}
+		}
+		Ok(root)
+	}
+
+	/// Get all entries the builder creates by their path relative to the root, including the dirs files are placed in. Files map to their contents, dirs to None.
+	pub(crate) fn expected_entries(&self) -> BTreeMap<String, Option<Vec<u8>>> {
+		let mut expected:BTreeMap<String, Option<Vec<u8>>> = BTreeMap::new();
+		for (name, entry) in &self.entries {
+			let path:FileRef = FileRef::new(name.trim_start_matches(SEPARATOR).trim_end_matches(SEPARATOR));
+			for parent_dir in path.prefixes().filter(|prefix| prefix != &path) {
+				expected.insert(parent_dir.path().to_string(), None);
+			}
+			match entry {
+				DirBuilderEntry::File(data) => {
+					expected.insert(path.path().to_string(), Some(data.clone()));
+				},
+				DirBuilderEntry::Dir(contents) => {
+					expected.insert(path.path().to_string(), None);
+					expected.extend(contents.expected_entries().into_iter().map(|(child_path, child)| (format!("{}{SEPARATOR}{child_path}", path.path()), child)));
+				}
+			}
+		}
+		expected
+	}
+}
+
+
+
+/// Build a `DirBuilder` from a nested literal like `{ "Cargo.toml" => "[package]", "src" => { "main.rs" => "fn main() {}" } }`, where braces describe dirs. File contents must be a single token tree implementing `AsRef<[u8]>`, so wrap longer expressions in parentheses.
+#[macro_export]
+macro_rules! dir_tree {
+	(@entry $builder:ident, $name:expr, { $($inner:tt)* }) => {
+		$builder.dir($name, $crate::dir_tree!{ $($inner)* })
+	};
+	(@entry $builder:ident, $name:expr, $contents:expr) => {
+		$builder.file($name, $contents)
+	};
+	($($name:literal => $contents:tt),* $(,)?) => {{
+		let builder:$crate::DirBuilder = $crate::DirBuilder::new();
+		$(let builder:$crate::DirBuilder = $crate::dir_tree!(@entry builder, $name, $contents);)*
+		builder
+	}};
+}
\ No newline at end of file
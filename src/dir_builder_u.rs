@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ DirBuilder, DirRef, FileRef, MemoryFs, TempDirRef, dir_tree };
+
+
+
+	#[test]
+	fn test_dir_builder() {
+		let temp_dir:TempDirRef = TempDirRef::new("dir_builder_test_").unwrap();
+		let root:FileRef = temp_dir.path() + "/project";
+		let built:DirRef = DirBuilder::new()
+			.file("Cargo.toml", "[package]")
+			.file("Makefile", b"all:")
+			.dir("src", DirBuilder::new().file("main.rs", "fn main() {}").file("bin/tool.rs", ""))
+			.empty_dir("target.d")
+			.build(&root)
+			.unwrap();
+
+		assert_eq!(built.file_ref(), &root.clone().absolute());
+		assert_eq!((root.clone() + "/Cargo.toml").read().unwrap(), "[package]");
+		assert_eq!(std::fs::read_to_string((root.clone() + "/Makefile").path()).unwrap(), "all:");
+		assert_eq!((root.clone() + "/src/main.rs").read().unwrap(), "fn main() {}");
+		assert!((root.clone() + "/src/bin/tool.rs").exists());
+		assert!(std::fs::metadata((root.clone() + "/target.d").path()).unwrap().is_dir());
+	}
+
+	#[test]
+	fn test_dir_tree_macro() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let fixture:DirBuilder = dir_tree! {
+			"Cargo.toml" => "[package]",
+			"src" => {
+				"main.rs" => "fn main() {}",
+				"generated.rs" => ("// ".repeat(2)),
+			},
+			"empty" => {}
+		};
+		fixture.build(&memory_fs.file_ref("project")).unwrap();
+
+		assert_eq!(memory_fs.file_ref("project/Cargo.toml").read().unwrap(), "[package]");
+		assert_eq!(memory_fs.file_ref("project/src/main.rs").read().unwrap(), "fn main() {}");
+		assert_eq!(memory_fs.file_ref("project/src/generated.rs").read().unwrap(), "// // ");
+		assert!(memory_fs.file_ref("project/empty").as_dir().is_ok());
+	}
+}
\ No newline at end of file
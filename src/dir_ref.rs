@@ -0,0 +1,171 @@
+use core::fmt::{ self, Debug, Display, Formatter };
+use std::error::Error;
+use crate::{ FileOperation, FileRef, FileScanner, FileSystem, SEPARATOR };
+
+
+
+/// A FileRef that was verified to point to an existing dir. Only offers operations that make sense on dirs, so reading or writing a dir is caught at compile time instead of failing on the extension heuristic at runtime.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DirRef {
+	dir:FileRef
+}
+impl DirRef {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a DirRef for an existing dir. Fails if the path does not exist or is not a dir.
+	pub fn new(dir:&FileRef) -> Result<DirRef, Box<dyn Error>> {
+		let is_dir:bool = match dir.mounted_file_system() {
+			Some(file_system) => file_system.list(dir.path()).is_ok(),
+			None => std::fs::metadata(dir.os_path()).is_ok_and(|metadata| metadata.is_dir())
+		};
+		if is_dir {
+			Ok(DirRef { dir: dir.clone() })
+		} else if dir.exists() {
+			Err(dir.error(FileOperation::Scan, "Path is not a dir."))
+		} else {
+			Err(dir.error(FileOperation::Scan, std::io::Error::new(std::io::ErrorKind::NotFound, "Dir does not exist.")))
+		}
+	}
+
+	/// Create the dir and its parent dirs if they do not exist yet, then return a DirRef for it. Unlike `FileRef::create`, names do not matter, so dirs with a dot in their name are created as dirs too.
+	pub fn create(dir:&FileRef) -> Result<DirRef, Box<dyn Error>> {
+		let dir:FileRef = dir.clone().absolute();
+		for missing_dir in dir.prefixes().filter(|prefix| !prefix.exists()) {
+			missing_dir.file_system().create(missing_dir.path(), true).map_err(|error| missing_dir.error(FileOperation::Create, error))?;
+		}
+		DirRef::new(&dir)
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the path of the dir as a FileRef.
+	pub fn file_ref(&self) -> &FileRef {
+		&self.dir
+	}
+
+	/// Get the raw path of the dir.
+	pub fn path(&self) -> &str {
+		self.dir.path()
+	}
+
+	/// Get the name of the dir.
+	pub fn name(&self) -> &str {
+		self.dir.name()
+	}
+
+	/// Check if the dir still exists.
+	pub fn exists(&self) -> bool {
+		self.dir.exists()
+	}
+
+	/// Get a FileRef for an entry in the dir. The entry does not need to exist.
+	pub fn child(&self, name:&str) -> FileRef {
+		FileRef::new(&format!("{}{SEPARATOR}{name}", self.dir.path().trim_end_matches(SEPARATOR)))
+	}
+
+	/// Get a DirRef for an existing sub-dir.
+	pub fn child_dir(&self, name:&str) -> Result<DirRef, Box<dyn Error>> {
+		DirRef::new(&self.child(name))
+	}
+
+
+
+	/* SCANNING METHODS */
+
+	/// Create a file-scanner on this dir.
+	pub fn scanner(&self) -> FileScanner {
+		self.dir.scanner()
+	}
+
+	/// List the direct entries of the dir, files and dirs alike.
+	pub fn entries(&self) -> Result<Vec<FileRef>, Box<dyn Error>> {
+		let file_system:std::sync::Arc<dyn FileSystem> = self.dir.file_system();
+		Ok(file_system.list(self.path()).map_err(|error| self.dir.error(FileOperation::Scan, error))?.iter().map(|path| FileRef::new(path)).collect())
+	}
+
+	/// List the files in the dir. Entries are classified by their actual type, so extensionless files and dirs with a dot in their name are handled correctly.
+	pub fn list_files(&self) -> Vec<FileRef> {
+		self.entries().unwrap_or_default().into_iter().filter(|entry| entry.metadata().is_ok_and(|metadata| metadata.is_file())).collect()
+	}
+
+	/// List the files in the dir and all of its sub-dirs. Entries are classified by their actual type. Symlinks to dirs are not followed.
+	pub fn list_files_recurse(&self) -> Vec<FileRef> {
+		let mut files:Vec<FileRef> = Vec::new();
+		let mut pending_dirs:Vec<DirRef> = vec![self.clone()];
+		while let Some(dir) = pending_dirs.pop() {
+			for entry in dir.entries().unwrap_or_default() {
+				match entry.symlink_metadata() {
+					Ok(metadata) if metadata.is_dir() => pending_dirs.push(DirRef { dir: entry }),
+					Ok(_) if entry.metadata().is_ok_and(|metadata| metadata.is_file()) => files.push(entry),
+					_ => {}
+				}
+			}
+		}
+		files
+	}
+
+	/// List the sub-dirs of the dir. Every entry is verified to be a dir, regardless of its name.
+	pub fn list_dirs(&self) -> Result<Vec<DirRef>, Box<dyn Error>> {
+		Ok(self.entries()?.iter().filter_map(|entry| DirRef::new(entry).ok()).collect())
+	}
+
+
+
+	/* MODIFICATION METHODS */
+
+	/// Delete all entries in the dir, keeping the dir itself.
+	pub fn delete_contents(&self) -> Result<(), Box<dyn Error>> {
+		let file_system:std::sync::Arc<dyn FileSystem> = self.dir.file_system();
+		for entry in self.entries()? {
+			file_system.remove(entry.path()).map_err(|error| entry.error(FileOperation::Delete, error))?;
+		}
+		Ok(())
+	}
+
+	/// Delete the dir and all of its contents.
+	pub fn delete(self) -> Result<(), Box<dyn Error>> {
+		self.dir.delete()
+	}
+}
+impl TryFrom<FileRef> for DirRef {
+	type Error = Box<dyn Error>;
+
+	fn try_from(dir:FileRef) -> Result<DirRef, Box<dyn Error>> {
+		DirRef::new(&dir)
+	}
+}
+impl TryFrom<&FileRef> for DirRef {
+	type Error = Box<dyn Error>;
+
+	fn try_from(dir:&FileRef) -> Result<DirRef, Box<dyn Error>> {
+		DirRef::new(dir)
+	}
+}
+impl From<DirRef> for FileRef {
+	fn from(dir:DirRef) -> FileRef {
+		dir.dir
+	}
+}
+impl Display for DirRef {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.path())
+	}
+}
+impl Debug for DirRef {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.path())
+	}
+}
+
+
+
+impl FileRef {
+
+	/// Verify that the path is an existing dir and get it as a DirRef.
+	pub fn as_dir(&self) -> Result<DirRef, Box<dyn Error>> {
+		DirRef::new(self)
+	}
+}
\ No newline at end of file
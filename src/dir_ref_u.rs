@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ DirRef, FileRef, MemoryFs, TempDirRef };
+
+
+
+	#[test]
+	fn test_dir_ref_verification() {
+		let temp_dir:TempDirRef = TempDirRef::new("dir_ref_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/file.txt";
+		file.write("contents").unwrap();
+
+		assert!(DirRef::new(&temp_dir.path()).is_ok());
+		assert!(DirRef::try_from(&file).is_err());
+		assert!((temp_dir.path() + "/missing").as_dir().is_err());
+
+		// Dirs with a dot in their name are still dirs.
+		let dotted:DirRef = DirRef::create(&(temp_dir.path() + "/config.d/nested.v2")).unwrap();
+		assert_eq!(dotted.name(), "nested.v2");
+		assert!(std::fs::metadata(dotted.path()).unwrap().is_dir());
+	}
+
+	#[test]
+	fn test_dir_ref_operations() {
+		let temp_dir:TempDirRef = TempDirRef::new("dir_ref_test_").unwrap();
+		let dir:DirRef = temp_dir.path().as_dir().unwrap();
+		dir.child("a.txt").write("a").unwrap();
+		dir.child("sub/b.txt").write("b").unwrap();
+
+		assert_eq!(dir.entries().unwrap().len(), 2);
+		assert_eq!(dir.list_files(), vec![dir.child("a.txt")]);
+		assert_eq!(dir.list_files_recurse().len(), 2);
+		let sub_dirs:Vec<DirRef> = dir.list_dirs().unwrap();
+		assert_eq!(sub_dirs, vec![dir.child_dir("sub").unwrap()]);
+		assert!(dir.child_dir("a.txt").is_err());
+
+		dir.delete_contents().unwrap();
+		assert!(dir.exists());
+		assert!(dir.entries().unwrap().is_empty());
+		assert_eq!(FileRef::from(dir.clone()), temp_dir.path());
+	}
+
+	#[test]
+	fn test_dir_ref_lists_by_actual_type() {
+		let temp_dir:TempDirRef = TempDirRef::new("dir_ref_test_").unwrap();
+		let dir:DirRef = temp_dir.path().as_dir().unwrap();
+		std::fs::write(dir.child("Makefile").os_path(), "all:").unwrap();
+		dir.child("a.txt").write("a").unwrap();
+		DirRef::create(&dir.child("build.v2")).unwrap();
+		std::fs::write(dir.child("build.v2/LICENSE").os_path(), "license").unwrap();
+
+		let mut files:Vec<FileRef> = dir.list_files();
+		files.sort();
+		assert_eq!(files, vec![dir.child("Makefile"), dir.child("a.txt")]);
+		let mut files:Vec<FileRef> = dir.list_files_recurse();
+		files.sort();
+		assert_eq!(files, vec![dir.child("Makefile"), dir.child("a.txt"), dir.child("build.v2/LICENSE")]);
+	}
+
+	#[test]
+	fn test_dir_ref_on_memory_fs() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		memory_fs.file_ref("data/file.txt").write("contents").unwrap();
+		assert!(memory_fs.file_ref("data").as_dir().is_ok());
+		assert!(memory_fs.file_ref("data/file.txt").as_dir().is_err());
+	}
+}
\ No newline at end of file
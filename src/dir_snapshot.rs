@@ -0,0 +1,108 @@
+use std::{ collections::BTreeMap, error::Error, time::{ Duration, SystemTime, UNIX_EPOCH } };
+use crate::{ DirEntries, FileOperation, FileRef };
+
+
+
+const SNAPSHOT_FIELD_SEPARATOR:char = '\t';
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature="rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature="bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct SnapshotEntry {
+	pub size:u64,
+	#[cfg_attr(feature="rkyv", rkyv(with=rkyv::with::AsUnixTime))]
+	pub modified:SystemTime
+}
+
+
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature="rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature="bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct DirSnapshot {
+	entries:BTreeMap<String, SnapshotEntry>
+}
+impl DirSnapshot {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new empty snapshot. Useful as the baseline of a dir that was never synced.
+	pub fn new() -> DirSnapshot {
+		DirSnapshot::default()
+	}
+
+	/// Capture the size and modification time of all files in the given dir, recursively. Files are found by their metadata, so extensionless files are included and dirs with a dot in their name are recursed into.
+	pub fn capture(dir:&FileRef) -> Result<DirSnapshot, Box<dyn Error>> {
+		dir.require_std_file_system(FileOperation::Scan)?;
+		let dir:FileRef = dir.clone().absolute();
+		let mut entries:BTreeMap<String, SnapshotEntry> = BTreeMap::new();
+		if dir.exists() {
+			for (file, _) in DirEntries::collect(&dir)?.files {
+				let metadata:std::fs::Metadata = std::fs::metadata(file.os_path())?;
+				entries.insert(file.relative_to_dir(&dir).path().to_owned(), SnapshotEntry { size: metadata.len(), modified: metadata.modified()? });
+			}
+		}
+		Ok(DirSnapshot { entries })
+	}
+
+	/// Load a snapshot previously stored with `save`.
+	pub fn load(file:&FileRef) -> Result<DirSnapshot, Box<dyn Error>> {
+		let mut entries:BTreeMap<String, SnapshotEntry> = BTreeMap::new();
+		for line in file.read()?.lines().filter(|line| !line.is_empty()) {
+			let fields:Vec<&str> = line.splitn(3, SNAPSHOT_FIELD_SEPARATOR).collect();
+			if fields.len() != 3 {
+				return Err(file.error(FileOperation::Read, format!("Invalid snapshot line \"{line}\".")));
+			}
+			let modified_nanos:u64 = fields[1].parse()?;
+			entries.insert(FileRef::new(fields[2]).path().to_owned(), SnapshotEntry { size: fields[0].parse()?, modified: UNIX_EPOCH + Duration::from_nanos(modified_nanos) });
+		}
+		Ok(DirSnapshot { entries })
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the entry of a file by its path relative to the snapshotted dir.
+	pub fn get(&self, relative_path:&FileRef) -> Option<&SnapshotEntry> {
+		self.entries.get(relative_path.path())
+	}
+
+	/// Get all entries in the snapshot, ordered by their relative path.
+	pub fn entries(&self) -> impl Iterator<Item = (FileRef, &SnapshotEntry)> {
+		self.entries.iter().map(|(relative_path, entry)| (FileRef::new(relative_path), entry))
+	}
+
+	/// Get the amount of files in the snapshot.
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Check if the snapshot contains no files.
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Store the snapshot in a file, so it can be used as the baseline of a later sync.
+	pub fn save(&self, file:&FileRef) -> Result<(), Box<dyn Error>> {
+		let mut contents:String = String::new();
+		for (relative_path, entry) in &self.entries {
+			let modified_nanos:u128 = entry.modified.duration_since(UNIX_EPOCH).map(|duration| duration.as_nanos()).unwrap_or_default();
+			contents += &format!("{}{SNAPSHOT_FIELD_SEPARATOR}{modified_nanos}{SNAPSHOT_FIELD_SEPARATOR}{}\n", entry.size, relative_path);
+		}
+		file.write(&contents)
+	}
+}
+impl FileRef {
+
+	/// Capture a snapshot of the size and modification time of all files in this dir.
+	pub fn snapshot(&self) -> Result<DirSnapshot, Box<dyn Error>> {
+		DirSnapshot::capture(self)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ DirSnapshot, FileRef, SnapshotEntry, TempDirRef };
+
+
+
+	#[test]
+	fn test_capture() {
+		let temp_dir:TempDirRef = TempDirRef::new("snapshot_test_").unwrap();
+		(temp_dir.path() + "/file1.txt").write("one").unwrap();
+		(temp_dir.path() + "/sub_dir/file2.txt").write("three").unwrap();
+
+		let snapshot:DirSnapshot = temp_dir.path().snapshot().unwrap();
+		assert_eq!(snapshot.len(), 2);
+		assert_eq!(snapshot.get(&FileRef::new("file1.txt")).map(|entry| entry.size), Some(3));
+		assert_eq!(snapshot.get(&FileRef::new_const("sub_dir/file2.txt")).map(|entry| entry.size), Some(5));
+		assert!(snapshot.get(&FileRef::new("missing.txt")).is_none());
+	}
+
+	#[test]
+	fn test_capture_extensionless_files_and_dotted_dirs() {
+		let temp_dir:TempDirRef = TempDirRef::new("snapshot_test_").unwrap();
+		std::fs::create_dir((temp_dir.path() + "/lib.v2").os_path()).unwrap();
+		std::fs::write((temp_dir.path() + "/Makefile").os_path(), "all:").unwrap();
+		std::fs::write((temp_dir.path() + "/lib.v2/LICENSE").os_path(), "MIT").unwrap();
+
+		let snapshot:DirSnapshot = temp_dir.path().snapshot().unwrap();
+		assert_eq!(snapshot.entries().map(|(path, entry)| (path.path().to_owned(), entry.size)).collect::<Vec<(String, u64)>>(), vec![("Makefile".to_owned(), 4), ("lib.v2/LICENSE".to_owned(), 3)]);
+	}
+
+	#[test]
+	fn test_capture_missing_dir() {
+		let temp_dir:TempDirRef = TempDirRef::new("snapshot_test_").unwrap();
+		assert!(DirSnapshot::capture(&(temp_dir.path() + "/missing")).unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_save_and_load() {
+		let temp_dir:TempDirRef = TempDirRef::new("snapshot_test_").unwrap();
+		(temp_dir.path() + "/data/file1.txt").write("one").unwrap();
+		(temp_dir.path() + "/data/sub_dir/file2.txt").write("two").unwrap();
+		let snapshot_file:FileRef = temp_dir.path() + "/snapshot.txt";
+
+		let snapshot:DirSnapshot = (temp_dir.path() + "/data").snapshot().unwrap();
+		snapshot.save(&snapshot_file).unwrap();
+		let loaded:DirSnapshot = DirSnapshot::load(&snapshot_file).unwrap();
+		assert_eq!(loaded, snapshot);
+		let entries:Vec<(FileRef, SnapshotEntry)> = loaded.entries().map(|(path, entry)| (path, *entry)).collect();
+		assert_eq!(entries[0].0, FileRef::new("file1.txt"));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use std::{ error::Error, fs::File };
+use crate::FileRef;
+
+
+
+pub(crate) const LOCK_SIDECAR_EXTENSION:&str = ".lock";
+
+
+
+pub struct FileLockGuard {
+	file_ref:FileRef,
+	file:Option<File>
+}
+impl FileLockGuard {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new guard around an already locked file handle.
+	pub(crate) fn new(file_ref:&FileRef, file:File) -> FileLockGuard {
+		FileLockGuard {
+			file_ref: file_ref.clone(),
+			file: Some(file)
+		}
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the file that is locked.
+	pub fn file_ref(&self) -> &FileRef {
+		&self.file_ref
+	}
+
+	/// Get the locked file handle.
+	pub fn file(&self) -> &File {
+		self.file.as_ref().unwrap()
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Release the lock. Unlike dropping the guard, this reports any error that occurs while unlocking.
+	pub fn unlock(mut self) -> Result<(), Box<dyn Error>> {
+		match self.file.take() {
+			Some(file) => file.unlock().map_err(|error| error.into()),
+			None => Ok(())
+		}
+	}
+}
+impl Drop for FileLockGuard {
+	fn drop(&mut self) {
+		if let Some(file) = self.file.take() {
+			let _ = file.unlock();
+		}
+	}
+}
\ No newline at end of file
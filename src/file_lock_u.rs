@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+	use unit_test_support::TempFile;
+	use crate::{ FileLockGuard, FileRef };
+
+
+
+	#[test]
+	fn test_guard_releases_on_drop() {
+		let temp_file:TempFile = TempFile::new(Some("lock"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+
+		let guard:FileLockGuard = temp_file_ref.lock_exclusive().unwrap();
+		assert_eq!(guard.file_ref(), &temp_file_ref);
+		assert!(temp_file_ref.try_lock().unwrap().is_none());
+
+		drop(guard);
+		assert!(temp_file_ref.try_lock().unwrap().is_some());
+	}
+
+	#[test]
+	fn test_guard_unlock() {
+		let temp_file:TempFile = TempFile::new(Some("lock"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+
+		let guard:FileLockGuard = temp_file_ref.lock_exclusive().unwrap();
+		guard.unlock().unwrap();
+		assert!(temp_file_ref.try_lock().unwrap().is_some());
+	}
+
+	#[test]
+	fn test_with_lock() {
+		let temp_file:TempFile = TempFile::new(Some("lock"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+
+		let was_locked:bool = temp_file_ref.with_lock(|| temp_file_ref.try_lock().unwrap().is_none()).unwrap();
+		assert!(was_locked);
+		assert!(temp_file_ref.try_lock().unwrap().is_some());
+	}
+
+	#[test]
+	fn test_sidecar_lock() {
+		let temp_file:TempFile = TempFile::new(Some("txt"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+		let sidecar:FileRef = temp_file_ref.lock_sidecar_file();
+		assert_eq!(sidecar.path(), temp_file_ref.path().to_owned() + ".lock");
+
+		temp_file_ref.with_sidecar_lock(|| {
+			assert!(sidecar.try_lock().unwrap().is_none());
+			temp_file_ref.write("replaced").unwrap();
+		}).unwrap();
+		assert!(sidecar.try_lock().unwrap().is_some());
+		assert_eq!(temp_file_ref.read().unwrap(), "replaced");
+
+		sidecar.delete().unwrap();
+	}
+}
\ No newline at end of file
@@ -0,0 +1,238 @@
+use std::{ error::Error, fs::{ File, FileTimes, Metadata, Permissions }, io::{ Error as IoError, ErrorKind }, time::SystemTime };
+use crate::{ DirEntries, FileOperation, FileRef, FileSystem, now };
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileKind {
+	File,
+	Dir,
+	Symlink,
+	Other
+}
+
+
+
+/// The metadata of a file or dir. Timestamps the platform or filesystem does not record are None.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FileMetadata {
+	pub kind:FileKind,
+	pub size:u64,
+	pub created:Option<SystemTime>,
+	pub modified:Option<SystemTime>,
+	pub accessed:Option<SystemTime>,
+	pub readonly:bool
+}
+impl FileMetadata {
+
+	/// Check if the metadata belongs to a file.
+	pub fn is_file(&self) -> bool {
+		self.kind == FileKind::File
+	}
+
+	/// Check if the metadata belongs to a dir.
+	pub fn is_dir(&self) -> bool {
+		self.kind == FileKind::Dir
+	}
+
+	/// Check if the metadata belongs to a symlink. Only possible for metadata from `symlink_metadata`.
+	pub fn is_symlink(&self) -> bool {
+		self.kind == FileKind::Symlink
+	}
+}
+impl From<&Metadata> for FileMetadata {
+	fn from(metadata:&Metadata) -> FileMetadata {
+		let file_type:std::fs::FileType = metadata.file_type();
+		FileMetadata {
+			kind: if file_type.is_symlink() { FileKind::Symlink } else if file_type.is_dir() { FileKind::Dir } else if file_type.is_file() { FileKind::File } else { FileKind::Other },
+			size: metadata.len(),
+			created: metadata.created().ok(),
+			modified: metadata.modified().ok(),
+			accessed: metadata.accessed().ok(),
+			readonly: metadata.permissions().readonly()
+		}
+	}
+}
+
+
+
+impl FileRef {
+
+	/* METADATA METHODS */
+
+	/// Get the metadata of the file or dir, following symlinks. Files on mounted filesystems only report their kind and size.
+	pub fn metadata(&self) -> Result<FileMetadata, Box<dyn Error>> {
+		match self.mounted_file_system() {
+			Some(file_system) => self.mounted_metadata(file_system.as_ref()),
+			None => std::fs::metadata(self.os_path()).map(|metadata| FileMetadata::from(&metadata)).map_err(|error| self.error(FileOperation::Read, error))
+		}
+	}
+
+	/// Get the metadata of the file or dir without following symlinks, so symlinks report their own metadata.
+	pub fn symlink_metadata(&self) -> Result<FileMetadata, Box<dyn Error>> {
+		match self.mounted_file_system() {
+			Some(file_system) => self.mounted_metadata(file_system.as_ref()),
+			None => std::fs::symlink_metadata(self.os_path()).map(|metadata| FileMetadata::from(&metadata)).map_err(|error| self.error(FileOperation::Read, error))
+		}
+	}
+
+	/// Get the metadata of a file on a mounted filesystem, which only knows the kind and size of files.
+	fn mounted_metadata(&self, file_system:&dyn FileSystem) -> Result<FileMetadata, Box<dyn Error>> {
+		if !file_system.exists(self.path()) {
+			return Err(self.error(FileOperation::Read, IoError::from(ErrorKind::NotFound)));
+		}
+		let is_dir:bool = file_system.is_dir(self.path());
+		let size:u64 = if is_dir { 0 } else { file_system.size(self.path()).map_err(|error| self.error(FileOperation::Read, error))? };
+		Ok(FileMetadata { kind: if is_dir { FileKind::Dir } else { FileKind::File }, size, created: None, modified: None, accessed: None, readonly: false })
+	}
+
+
+
+	/* TIMESTAMP METHODS */
+
+	/// Get the time the file was created. Fails if the platform or filesystem does not record it.
+	pub fn created(&self) -> Result<SystemTime, Box<dyn Error>> {
+		self.timestamp(self.metadata()?.created, "creation")
+	}
+
+	/// Get the time the file was last modified.
+	pub fn modified(&self) -> Result<SystemTime, Box<dyn Error>> {
+		self.timestamp(self.metadata()?.modified, "modification")
+	}
+
+	/// Get the time the file was last accessed. Many systems only update it lazily or not at all, so it is only an indication.
+	pub fn accessed(&self) -> Result<SystemTime, Box<dyn Error>> {
+		self.timestamp(self.metadata()?.accessed, "access")
+	}
+
+	/// Create the file if it does not exist, and set its modification and access time to now. Files on mounted filesystems have no timestamps, so they are only created. Extensionless paths are created as files.
+	pub fn touch(&self) -> Result<(), Box<dyn Error>> {
+		if !self.exists() {
+			self.with_operation_hooks(FileOperation::Create, None, || {
+				self.guarantee_parent_dir()?;
+				self.file_system().create(self.path(), false).map_err(|error| self.error(FileOperation::Create, error))
+			})?;
+		}
+		if self.mounted_file_system().is_some() {
+			return Ok(());
+		}
+		let now:SystemTime = now();
+		self.set_times(FileTimes::new().set_modified(now).set_accessed(now))
+	}
+
+	/// Set the time the file was last modified, without changing its contents.
+	pub fn set_modified(&self, time:SystemTime) -> Result<(), Box<dyn Error>> {
+		self.with_operation_hooks(FileOperation::Write, None, || self.traced("write", None, |_| None, || self.set_times(FileTimes::new().set_modified(time))))
+	}
+
+	/// Set the time the file was last accessed, without changing its contents.
+	pub fn set_accessed(&self, time:SystemTime) -> Result<(), Box<dyn Error>> {
+		self.with_operation_hooks(FileOperation::Write, None, || self.traced("write", None, |_| None, || self.set_times(FileTimes::new().set_accessed(time))))
+	}
+
+	/// Apply timestamps to the file or dir. Only supported on the filesystem of the OS.
+	pub(crate) fn set_times(&self, times:FileTimes) -> Result<(), Box<dyn Error>> {
+		if self.mounted_file_system().is_some() {
+			return Err(self.error(FileOperation::Write, "Timestamps are only supported on the filesystem of the OS."));
+		}
+		let is_dir:bool = std::fs::metadata(self.os_path()).map_err(|error| self.error(FileOperation::Write, error))?.is_dir();
+		let file:File = if is_dir { File::open(self.os_path()) } else { File::options().write(true).open(self.os_path()) }.map_err(|error| self.error(FileOperation::Write, error))?;
+		file.set_times(times).map_err(|error| self.error(FileOperation::Write, error))
+	}
+
+	/// Return the timestamp, or an error naming the missing timestamp.
+	fn timestamp(&self, timestamp:Option<SystemTime>, name:&str) -> Result<SystemTime, Box<dyn Error>> {
+		timestamp.ok_or_else(|| self.error(FileOperation::Read, format!("The {name} time is not available on this platform or filesystem.")))
+	}
+
+
+
+	/* PERMISSION METHODS */
+
+	/// Check if the file or dir is read-only. Files on mounted filesystems are never read-only.
+	pub fn is_readonly(&self) -> Result<bool, Box<dyn Error>> {
+		Ok(self.metadata()?.readonly)
+	}
+
+	/// Make the file or dir read-only or writable. On Unix, making it read-only removes all write bits and making it writable only adds the write bit of the owner.
+	pub fn set_readonly(&self, readonly:bool) -> Result<(), Box<dyn Error>> {
+		self.with_operation_hooks(FileOperation::Write, None, || self.traced("write", None, |_| None, || {
+			let mut permissions:Permissions = self.permissions()?;
+			#[cfg(unix)]
+			{
+				use std::os::unix::fs::PermissionsExt;
+				let mode:u32 = permissions.mode();
+				permissions.set_mode(if readonly { mode & !0o222 } else { mode | 0o200 });
+			}
+			#[cfg(not(unix))]
+			#[allow(clippy::permissions_set_readonly_false)]
+			permissions.set_readonly(readonly);
+			self.set_permissions(permissions)
+		}))
+	}
+
+	/// Get the Unix permission bits of the file or dir, like 0o644.
+	#[cfg(unix)]
+	pub fn mode(&self) -> Result<u32, Box<dyn Error>> {
+		use std::os::unix::fs::PermissionsExt;
+		Ok(self.permissions()?.mode() & 0o7777)
+	}
+
+	/// Set the Unix permission bits of the file or dir, like 0o755.
+	#[cfg(unix)]
+	pub fn set_mode(&self, mode:u32) -> Result<(), Box<dyn Error>> {
+		use std::os::unix::fs::PermissionsExt;
+		self.with_operation_hooks(FileOperation::Write, None, || self.traced("write", None, |_| None, || self.set_permissions(Permissions::from_mode(mode))))
+	}
+
+	/// Get the permissions of the file or dir. Only supported on the filesystem of the OS.
+	fn permissions(&self) -> Result<Permissions, Box<dyn Error>> {
+		if self.mounted_file_system().is_some() {
+			return Err(self.error(FileOperation::Read, "Permissions are only supported on the filesystem of the OS."));
+		}
+		std::fs::metadata(self.os_path()).map(|metadata| metadata.permissions()).map_err(|error| self.error(FileOperation::Read, error))
+	}
+
+	/// Apply permissions to the file or dir. Only supported on the filesystem of the OS.
+	fn set_permissions(&self, permissions:Permissions) -> Result<(), Box<dyn Error>> {
+		if self.mounted_file_system().is_some() {
+			return Err(self.error(FileOperation::Write, "Permissions are only supported on the filesystem of the OS."));
+		}
+		std::fs::set_permissions(self.os_path(), permissions).map_err(|error| self.error(FileOperation::Write, error))
+	}
+
+
+
+	/* SIZE METHODS */
+
+	/// Get the size of the file in bytes. Fails for dirs, use `dir_size` for those.
+	pub fn size(&self) -> Result<u64, Box<dyn Error>> {
+		if self.is_dir_fs() {
+			return Err(self.error(FileOperation::Read, "Only able to get the size of files, use dir_size for dirs."));
+		}
+		self.file_system().size(self.path()).map_err(|error| self.io_failure(FileOperation::Read, error))
+	}
+
+	/// Get the total size in bytes of all files in the dir, recursively. Uses the filesystem instead of the names of entries to tell files and dirs apart. Symlinks are not followed.
+	pub fn dir_size(&self) -> Result<u64, Box<dyn Error>> {
+		match self.mounted_file_system() {
+			Some(file_system) => mounted_dir_size(file_system.as_ref(), self),
+			None => Ok(DirEntries::collect(self)?.files.iter().map(|(_, metadata)| metadata.len()).sum())
+		}
+	}
+}
+
+
+
+/// Sum the sizes of all files in a dir on a mounted filesystem, recursively.
+fn mounted_dir_size(file_system:&dyn FileSystem, dir:&FileRef) -> Result<u64, Box<dyn Error>> {
+	let mut size:u64 = 0;
+	for entry in file_system.list(dir.path()).map_err(|error| dir.io_failure(FileOperation::Scan, error))? {
+		size += if file_system.is_dir(&entry) {
+			mounted_dir_size(file_system, &FileRef::new(&entry))?
+		} else {
+			file_system.size(&entry).map_err(|error| FileRef::new(&entry).io_failure(FileOperation::Read, error))?
+		};
+	}
+	Ok(size)
+}
\ No newline at end of file
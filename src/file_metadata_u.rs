@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+	use std::time::{ Duration, SystemTime };
+	use crate::{ FileKind, FileMetadata, FileRef, MemoryFs, TempDirRef };
+
+
+
+	#[test]
+	fn test_metadata() {
+		let temp_dir:TempDirRef = TempDirRef::new("metadata_test_").unwrap();
+		let file:FileRef = temp_dir.path().join("file.txt").unwrap();
+		file.write("contents").unwrap();
+
+		let metadata:FileMetadata = file.metadata().unwrap();
+		assert!(metadata.is_file());
+		assert_eq!(metadata.size, 8);
+		assert!(metadata.modified.is_some_and(|modified| modified <= SystemTime::now()));
+		assert!(!metadata.readonly);
+		assert_eq!(temp_dir.path().metadata().unwrap().kind, FileKind::Dir);
+		assert!(temp_dir.path().join("missing.txt").unwrap().metadata().is_err());
+
+		#[cfg(unix)]
+		{
+			let link:FileRef = temp_dir.path().join("link.txt").unwrap();
+			std::os::unix::fs::symlink(file.path(), link.path()).unwrap();
+			assert!(link.metadata().unwrap().is_file());
+			assert!(link.symlink_metadata().unwrap().is_symlink());
+		}
+	}
+
+	#[test]
+	fn test_metadata_mounted() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let file:FileRef = memory_fs.file_ref("dir/file.txt");
+		file.write("contents").unwrap();
+
+		let metadata:FileMetadata = file.metadata().unwrap();
+		assert_eq!((metadata.kind, metadata.size, metadata.modified), (FileKind::File, 8, None));
+		assert!(memory_fs.file_ref("dir").metadata().unwrap().is_dir());
+		assert!(memory_fs.file_ref("missing.txt").metadata().unwrap_err().downcast_ref::<crate::FileRefError>().unwrap().is_not_found());
+	}
+
+	#[test]
+	fn test_size() {
+		let temp_dir:TempDirRef = TempDirRef::new("size_test_").unwrap();
+		temp_dir.path().join("a.txt").unwrap().write("12345").unwrap();
+		temp_dir.path().join("sub/deeper/b.txt").unwrap().write("123").unwrap();
+		std::fs::write(temp_dir.path().join("sub/Makefile").unwrap().path(), "12").unwrap();
+
+		assert_eq!(temp_dir.path().join("a.txt").unwrap().size().unwrap(), 5);
+		assert_eq!(temp_dir.path().join("sub/Makefile").unwrap().size().unwrap(), 2);
+		assert!(temp_dir.path().size().is_err());
+		assert!(temp_dir.path().join("missing.txt").unwrap().size().is_err());
+		assert_eq!(temp_dir.path().dir_size().unwrap(), 10);
+		assert_eq!(temp_dir.path().join("sub").unwrap().dir_size().unwrap(), 5);
+
+		let memory_fs:MemoryFs = MemoryFs::new();
+		memory_fs.file_ref("a.txt").write("12345").unwrap();
+		memory_fs.file_ref("dir/b.txt").write("123").unwrap();
+		assert_eq!(memory_fs.root().dir_size().unwrap(), 8);
+		assert_eq!(memory_fs.file_ref("dir/b.txt").size().unwrap(), 3);
+	}
+
+	#[test]
+	fn test_timestamps() {
+		let temp_dir:TempDirRef = TempDirRef::new("timestamps_test_").unwrap();
+		let source:FileRef = temp_dir.path().join("source.txt").unwrap();
+		let derived:FileRef = temp_dir.path().join("derived.txt").unwrap();
+		source.write("source").unwrap();
+		source.set_modified(SystemTime::UNIX_EPOCH).unwrap();
+		derived.write("derived").unwrap();
+
+		assert_eq!(source.modified().unwrap(), SystemTime::UNIX_EPOCH);
+		assert!(derived.modified().unwrap() > source.modified().unwrap());
+		assert!(derived.accessed().is_ok());
+		assert!(temp_dir.path().join("missing.txt").unwrap().modified().is_err());
+
+		let memory_fs:MemoryFs = MemoryFs::new();
+		memory_fs.file_ref("file.txt").write("contents").unwrap();
+		assert!(memory_fs.file_ref("file.txt").modified().unwrap_err().to_string().contains("modification time is not available"));
+	}
+
+	#[test]
+	fn test_touch() {
+		let temp_dir:TempDirRef = TempDirRef::new("touch_test_").unwrap();
+		let file:FileRef = temp_dir.path().join("build/output.txt").unwrap();
+		file.touch().unwrap();
+		assert_eq!(file.read().unwrap(), "");
+
+		file.write("contents").unwrap();
+		file.set_modified(SystemTime::UNIX_EPOCH).unwrap();
+		file.set_accessed(SystemTime::UNIX_EPOCH + Duration::from_secs(60)).unwrap();
+		assert_eq!(file.modified().unwrap(), SystemTime::UNIX_EPOCH);
+		assert_eq!(file.accessed().unwrap(), SystemTime::UNIX_EPOCH + Duration::from_secs(60));
+		file.touch().unwrap();
+		assert!(file.modified().unwrap() > SystemTime::UNIX_EPOCH);
+		assert_eq!(file.read().unwrap(), "contents");
+
+		let extensionless:FileRef = temp_dir.path().join("Makefile").unwrap();
+		extensionless.touch().unwrap();
+		assert!(extensionless.is_file_fs());
+		temp_dir.path().set_modified(SystemTime::UNIX_EPOCH).unwrap();
+		assert_eq!(temp_dir.path().modified().unwrap(), SystemTime::UNIX_EPOCH);
+
+		let memory_fs:MemoryFs = MemoryFs::new();
+		memory_fs.file_ref("file.txt").touch().unwrap();
+		assert!(memory_fs.file_ref("file.txt").exists());
+		assert!(memory_fs.file_ref("file.txt").set_modified(SystemTime::UNIX_EPOCH).is_err());
+	}
+
+	#[test]
+	fn test_permissions() {
+		let temp_dir:TempDirRef = TempDirRef::new("permissions_test_").unwrap();
+		let file:FileRef = temp_dir.path().join("config.toml").unwrap();
+		file.write("key = 1").unwrap();
+		assert!(!file.is_readonly().unwrap());
+
+		file.set_readonly(true).unwrap();
+		assert!(file.is_readonly().unwrap());
+		file.set_readonly(false).unwrap();
+		assert!(!file.is_readonly().unwrap());
+
+		#[cfg(unix)]
+		{
+			file.set_mode(0o640).unwrap();
+			assert_eq!(file.mode().unwrap(), 0o640);
+			file.set_readonly(true).unwrap();
+			assert_eq!(file.mode().unwrap(), 0o440);
+			file.set_readonly(false).unwrap();
+			assert_eq!(file.mode().unwrap(), 0o640);
+		}
+
+		let memory_fs:MemoryFs = MemoryFs::new();
+		memory_fs.file_ref("file.txt").write("contents").unwrap();
+		assert!(!memory_fs.file_ref("file.txt").is_readonly().unwrap());
+		assert!(memory_fs.file_ref("file.txt").set_readonly(true).is_err());
+	}
+}
\ No newline at end of file
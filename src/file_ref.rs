@@ -1,6 +1,6 @@
 use core::fmt::{ self, Display, Debug, Formatter };
-use std::{error::Error, ops::{Add, AddAssign}};
-use crate::FileScanner;
+use std::{cmp::Ordering, error::Error, ops::{Add, AddAssign, Bound, RangeBounds}, path::PathBuf};
+use crate::{ CopyOptions, DirRef, FileScanner, FileLockGuard, TempFileRef, LOCK_SIDECAR_EXTENSION, FileOperation, FileRefError, default_if_not_found, file_ref_copy::copy_file_contents, file_ref_verbatim::strip_verbatim_prefix, platform };
 
 
 
@@ -8,13 +8,52 @@ use crate::FileScanner;
 pub(crate) const SEPARATOR:&str = "/";
 const INVALID_SEPARATOR:&str = "\\";
 const DOUBLE_SEPARATOR:&str = "//";
-const DISK_SEPARATOR:&str = ":";
+pub(crate) const DISK_SEPARATOR:&str = ":";
+const INLINE_PATH_CAPACITY:usize = 15;
 
 
 
-#[derive(Clone, Eq, PartialOrd, Ord)]
+/// A short path stored inline, so it does not need a heap allocation. The capacity is limited so a FileRef stays as small as a String.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct InlinePath {
+	length:u8,
+	bytes:[u8; INLINE_PATH_CAPACITY]
+}
+impl InlinePath {
+
+	/// Join the nodes of a path into an inline path. Returns None if the path does not fit.
+	fn from_nodes(nodes:&[&str]) -> Option<InlinePath> {
+		let length:usize = nodes.iter().map(|node| node.len()).sum::<usize>() + nodes.len().saturating_sub(1) * SEPARATOR.len();
+		if length > INLINE_PATH_CAPACITY {
+			return None;
+		}
+		let mut path:InlinePath = InlinePath { length: length as u8, bytes: [0; INLINE_PATH_CAPACITY] };
+		let mut cursor:usize = 0;
+		for (index, node) in nodes.iter().enumerate() {
+			if index > 0 {
+				path.bytes[cursor..cursor + SEPARATOR.len()].copy_from_slice(SEPARATOR.as_bytes());
+				cursor += SEPARATOR.len();
+			}
+			path.bytes[cursor..cursor + node.len()].copy_from_slice(node.as_bytes());
+			cursor += node.len();
+		}
+		Some(path)
+	}
+
+	/// Get the path as str.
+	pub fn as_str(&self) -> &str {
+		// SAFETY: The bytes are copied from complete str nodes and separators, so they are valid UTF-8.
+		unsafe { std::str::from_utf8_unchecked(&self.bytes[..self.length as usize]) }
+	}
+}
+
+
+
+#[derive(Clone, Eq)]
+#[non_exhaustive]
 pub enum FileRef {
 	StaticStr(&'static str),
+	Inline(InlinePath),
 	Owned(String)
 }
 impl FileRef {
@@ -25,7 +64,7 @@ impl FileRef {
 	pub fn new(path:&str) -> FileRef {
 		
 		// Fix incorrect or messy separators.
-		let mut path:String = path.replace(INVALID_SEPARATOR, SEPARATOR);
+		let mut path:String = strip_verbatim_prefix(path).replace(INVALID_SEPARATOR, SEPARATOR);
 		while path.contains(DOUBLE_SEPARATOR) {
 			path = path.replace(DOUBLE_SEPARATOR, SEPARATOR);
 		}
@@ -36,10 +75,11 @@ impl FileRef {
 		if nodes.len() >= 2 {
 			let mut index:usize = 1;
 			while index < nodes.len() {
-				if nodes[index] == ".." && nodes[index - 1] != ".." {
+				let is_root:bool = index == 1 && (nodes[0].is_empty() || nodes[0].contains(DISK_SEPARATOR));
+				if nodes[index] == ".." && nodes[index - 1] != ".." && !is_root {
 					nodes.remove(index);
 					nodes.remove(index - 1);
-					index = 0; // Restart after all modifications, required tow fix paths like a/b/../..
+					index = 1; // Restart after all modifications, required tow fix paths like a/b/../..
 				} else {
 					index += 1;
 				}
@@ -51,8 +91,11 @@ impl FileRef {
 			nodes.retain(|node| *node != ".");
 		}
 
-		// Return new file.
-		FileRef::Owned(nodes.join(SEPARATOR))
+		// Return new file, stored inline if it is short enough.
+		match InlinePath::from_nodes(&nodes) {
+			Some(path) => FileRef::Inline(path),
+			None => FileRef::Owned(nodes.join(SEPARATOR))
+		}
 	}
 
 	/// Create a new statically borrowed path. This may behave unexpectedly for messy paths (using '.' or '..').
@@ -60,9 +103,67 @@ impl FileRef {
 		FileRef::StaticStr(path)
 	}
 
+	/// Create a new uniquely named file in the temp dir of the OS. The file is deleted when the returned TempFileRef is dropped.
+	pub fn temp(extension:&str) -> Result<TempFileRef, Box<dyn Error>> {
+		TempFileRef::new(extension)
+	}
+
 	/// Get the working dir of the application.
 	pub fn working_dir() -> FileRef {
-		FileRef::new(&std::env::current_dir().unwrap().display().to_string())
+		FileRef::new(&platform::working_dir().unwrap())
+	}
+
+	/// Get the current working dir of the process. Unlike `working_dir`, fails instead of panicking when the dir was removed or can not be accessed.
+	pub fn current_dir() -> Result<FileRef, Box<dyn Error>> {
+		let dir:String = platform::working_dir().map_err(|error| format!("Could not get current dir. {error}"))?;
+		Ok(FileRef::new(&dir))
+	}
+
+	/// Get the executable file of the running process.
+	pub fn current_exe() -> Result<FileRef, Box<dyn Error>> {
+		let exe:PathBuf = std::env::current_exe().map_err(|error| format!("Could not get current executable. {error}"))?;
+		Ok(FileRef::new(&exe.display().to_string()))
+	}
+
+	/// Get the dir containing the executable of the running process, useful for locating resources shipped next to the binary.
+	pub fn exe_dir() -> Result<FileRef, Box<dyn Error>> {
+		FileRef::current_exe()?.parent_dir()
+	}
+
+	/// Find an executable in the dirs of the PATH environment variable, like the 'which' command. On Windows, the extensions in PATHEXT are tried as well. Names containing a separator are checked directly.
+	pub fn which(program:&str) -> Option<FileRef> {
+		let extensions:Vec<String> = if cfg!(windows) {
+			let path_extensions:String = std::env::var("PATHEXT").unwrap_or(".COM;.EXE;.BAT;.CMD".to_owned());
+			[String::new()].into_iter().chain(path_extensions.split(';').filter(|extension| !extension.is_empty()).map(|extension| extension.to_lowercase())).collect()
+		} else {
+			vec![String::new()]
+		};
+		let candidates:Vec<FileRef> = if program.contains(SEPARATOR) || program.contains(INVALID_SEPARATOR) {
+			extensions.iter().map(|extension| FileRef::new(&(program.to_owned() + extension))).collect()
+		} else {
+			std::env::split_paths(&std::env::var_os("PATH")?)
+				.filter(|dir| !dir.as_os_str().is_empty())
+				.flat_map(|dir| extensions.iter().map(move |extension| FileRef::new(&format!("{}{SEPARATOR}{program}{extension}", dir.display()))))
+				.collect()
+		};
+		candidates.into_iter().find(|candidate| candidate.is_executable())
+	}
+
+	/// Check if the path points to an executable file. Files on mounted filesystems can not be executed.
+	fn is_executable(&self) -> bool {
+		if self.mounted_file_system().is_some() {
+			return false;
+		}
+		let Ok(metadata) = std::fs::metadata(self.os_path()) else { return false };
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+		}
+		#[cfg(not(unix))]
+		{
+			metadata.is_file()
+		}
 	}
 
 	/// Return self with a absolute path.
@@ -74,6 +175,20 @@ impl FileRef {
 		}
 	}
 
+	/// Get the absolute path, resolving relative paths against the current working dir. Unlike `canonicalize`, the file does not need to exist and symlinks are not resolved.
+	pub fn to_absolute(&self) -> FileRef {
+		self.clone().absolute()
+	}
+
+	/// Get the absolute path of the existing file with all symlinks and relative components resolved, for stable comparisons. Files on mounted filesystems have no symlinks, so only their path is made absolute.
+	pub fn canonicalize(&self) -> Result<FileRef, Box<dyn Error>> {
+		if self.mounted_file_system().is_some() {
+			return if self.exists() { Ok(self.to_absolute()) } else { Err(self.error(FileOperation::Resolve, std::io::Error::from(std::io::ErrorKind::NotFound))) };
+		}
+		let canonical:PathBuf = std::fs::canonicalize(self.os_path()).map_err(|error| self.error(FileOperation::Resolve, error))?;
+		Ok(FileRef::new(&canonical.to_string_lossy()))
+	}
+
 	/// Return self with a relatvie path.
 	pub fn relative(self) -> FileRef {
 		let working_dir:FileRef = FileRef::working_dir();
@@ -91,7 +206,8 @@ impl FileRef {
 	/// Get the raw path.
 	pub fn path(&self) -> &str {
 		match self {
-			FileRef::StaticStr(path) => *path,
+			FileRef::StaticStr(path) => path,
+			FileRef::Inline(path) => path.as_str(),
 			FileRef::Owned(path) => path.as_str()
 		}
 	}
@@ -99,18 +215,14 @@ impl FileRef {
 	/// Get the directory the file is in.
 	pub fn parent_dir(&self) -> Result<FileRef, Box<dyn Error>> {
 		let path:&str = self.path();
-		let nodes:Vec<&str> = self.path_nodes();
-		if *nodes.last().unwrap_or(&"") == ".." {
+		if self.last_node() == ".." {
 			Ok(self.clone() + "/..")
-		} else if nodes.len() <= 1 {
-			if self.is_relative_path() {
-				self.clone().absolute().parent_dir()
-			} else {
-				Err(format!("Could not get dir of file \"{path}\", as it only contains the file name.").into())
-			}
 		} else {
-			let parent_dir_len:usize = nodes[..nodes.len() - 1].join(SEPARATOR).len();
-			Ok(FileRef::new(&path[..parent_dir_len]))
+			match path.rfind(SEPARATOR) {
+				Some(separator_index) => Ok(FileRef::new(&path[..separator_index])),
+				None if self.is_relative_path() => self.clone().absolute().parent_dir(),
+				None => Err(self.error(FileOperation::Resolve, "Path only contains the file name, so it has no dir."))
+			}
 		}
 	}
 
@@ -119,14 +231,122 @@ impl FileRef {
 		self.path().split(SEPARATOR).collect()
 	}
 
-	/// Get the last node of the path.
+	/// Get the last node of the path. Searches from the end, so the rest of the path is never split.
 	pub(crate) fn last_node(&self) -> &str {
-		self.path().split(SEPARATOR).last().unwrap_or_default()
+		let path:&str = self.path();
+		path.rfind(SEPARATOR).map(|separator_index| &path[separator_index + SEPARATOR.len()..]).unwrap_or(path)
+	}
+
+	/// Get the amount of nodes in the path.
+	pub fn depth(&self) -> usize {
+		self.path().matches(SEPARATOR).count() + 1
+	}
+
+	/// Get the node at the given index in the path. Absolute paths starting with a separator have an empty first node.
+	pub fn segment(&self, index:usize) -> Option<&str> {
+		self.path().split(SEPARATOR).nth(index)
+	}
+
+	/// Get a path containing only the nodes in the given range. The range is limited to the nodes in the path.
+	pub fn segments_range<T:RangeBounds<usize>>(&self, range:T) -> FileRef {
+		let depth:usize = self.depth();
+		let start:usize = match range.start_bound() { Bound::Included(index) => *index, Bound::Excluded(index) => index.saturating_add(1), Bound::Unbounded => 0 }.min(depth);
+		let end:usize = match range.end_bound() { Bound::Included(index) => index.saturating_add(1), Bound::Excluded(index) => *index, Bound::Unbounded => depth }.clamp(start, depth);
+		if start == end {
+			return FileRef::new("");
+		}
+		let end_index:usize = if end == depth { self.path().len() } else { self.segment_start_index(end) - SEPARATOR.len() };
+		FileRef::new(&self.path()[self.segment_start_index(start)..end_index])
+	}
+
+	/// Split the path into its first node and the path of everything after it. Returns None if the path only has one node.
+	pub fn split_first_segment(&self) -> Option<(&str, FileRef)> {
+		self.path().split_once(SEPARATOR).map(|(first, rest)| (first, FileRef::new(rest)))
+	}
+
+	/// Get a path with the given relative segment appended, inserting a separator where needed. The segment may contain multiple nodes, like 'dir/file.txt'. Fails if the segment is an absolute path, as it can not be placed inside another path.
+	pub fn join(&self, segment:&str) -> Result<FileRef, Box<dyn Error>> {
+		self.join_ref(&FileRef::new(segment))
+	}
+
+	/// Get a path with the given relative path appended, inserting a separator where needed. Fails if the path is absolute, as it can not be placed inside another path.
+	pub fn join_ref(&self, segment:&FileRef) -> Result<FileRef, Box<dyn Error>> {
+		if segment.is_absolute_path() {
+			return Err(self.error(FileOperation::Resolve, format!("Only able to join relative paths, \"{}\" is absolute.", segment.path())));
+		}
+		Ok(if segment.is_empty() {
+			self.clone()
+		} else if self.is_empty() {
+			segment.clone()
+		} else {
+			FileRef::new(&format!("{}{SEPARATOR}{}", self.path().trim_end_matches(SEPARATOR), segment.path()))
+		})
+	}
+
+	/// Get the byte index in the path at which the node with the given index starts.
+	fn segment_start_index(&self, index:usize) -> usize {
+		let path:&str = self.path();
+		match index {
+			0 => 0,
+			_ => path.match_indices(SEPARATOR).nth(index - 1).map(|(separator_index, _)| separator_index + SEPARATOR.len()).unwrap_or(path.len())
+		}
+	}
+
+	/// Remove trailing separators from a dir path, keeping the separator of root dirs like '/' and 'C:/', as those would otherwise point somewhere else.
+	pub(crate) fn trim_dir_separator(self) -> FileRef {
+		let trimmed:&str = self.path().trim_end_matches(SEPARATOR);
+		if trimmed.is_empty() || trimmed.ends_with(DISK_SEPARATOR) { self } else { FileRef::new(trimmed) }
+	}
+
+	/// Get the path of this entry relative to the given dir. Returns self if the entry is not in the dir.
+	pub(crate) fn relative_to_dir(&self, dir:&FileRef) -> FileRef {
+		self.strip_prefix(&(dir.path().trim_end_matches(SEPARATOR).to_owned() + SEPARATOR)).unwrap_or(self.clone())
+	}
+
+	/// Get the path that leads from the base dir to this path, adding '..' nodes where needed. Only compares the paths, so symlinks are not resolved. Fails if only one of the paths is absolute, if they are on different roots, or if the base climbs out of an unknown dir with '..' nodes.
+	pub fn relative_to(&self, base:&FileRef) -> Result<FileRef, Box<dyn Error>> {
+		let error = |reason:&str| -> Box<dyn Error> { FileRefError::new(FileOperation::Resolve, self, reason).with_target(base).into() };
+		if self.is_absolute_path() != base.is_absolute_path() {
+			return Err(error("Only able to compare two absolute or two relative paths."));
+		}
+		let nodes:Vec<&str> = self.path_nodes().into_iter().filter(|node| !node.is_empty() && *node != ".").collect();
+		let base_nodes:Vec<&str> = base.path_nodes().into_iter().filter(|node| !node.is_empty() && *node != ".").collect();
+		let root = |nodes:&[&str]| -> Option<String> { nodes.first().filter(|node| node.contains(DISK_SEPARATOR)).map(|node| node.to_lowercase()) };
+		if root(&nodes) != root(&base_nodes) {
+			return Err(error("Paths are on different roots."));
+		}
+
+		let common_length:usize = nodes.iter().zip(&base_nodes).enumerate().take_while(|(index, (node, base_node))| node == base_node || (*index == 0 && node.eq_ignore_ascii_case(base_node))).count();
+		if base_nodes[common_length..].contains(&"..") {
+			return Err(error("Base climbs out of an unknown dir."));
+		}
+		let relative_nodes:Vec<&str> = std::iter::repeat_n("..", base_nodes.len() - common_length).chain(nodes[common_length..].iter().copied()).collect();
+		Ok(if relative_nodes.is_empty() { FileRef::new(".") } else { FileRef::new(&relative_nodes.join(SEPARATOR)) })
+	}
+
+	/// Get the path with '.' nodes, duplicate separators and trailing separators removed and '..' nodes resolved against the nodes before them. Purely lexical, so symlinks are not resolved. '..' nodes that would climb above the root of an absolute path are dropped, leading '..' nodes of relative paths are kept.
+	pub fn normalize(&self) -> FileRef {
+		let mut remaining_nodes:std::str::Split<&str> = self.path().split(SEPARATOR);
+		let root:Option<&str> = if self.starts_with(SEPARATOR) || self.path_nodes()[0].contains(DISK_SEPARATOR) { remaining_nodes.next() } else { None };
+		let mut nodes:Vec<&str> = Vec::new();
+		for node in remaining_nodes {
+			match node {
+				"" | "." => {},
+				".." if nodes.last().is_some_and(|last| *last != "..") => { nodes.pop(); },
+				".." if root.is_some() => {},
+				_ => nodes.push(node)
+			}
+		}
+		match root {
+			Some(root) => FileRef::new(&format!("{root}{SEPARATOR}{}", nodes.join(SEPARATOR))),
+			None if nodes.is_empty() => FileRef::new("."),
+			None => FileRef::new(&nodes.join(SEPARATOR))
+		}
 	}
 
 	/// Check if the path is a relative or absolute path.
 	pub fn is_absolute_path(&self) -> bool {
-		self.contains(DISK_SEPARATOR)
+		self.starts_with(SEPARATOR) || self.contains(DISK_SEPARATOR)
 	}
 
 	/// Check if the path is a relative or absolute path.
@@ -148,6 +368,16 @@ impl FileRef {
 		!self.is_dir()
 	}
 
+	/// Check if self is an existing dir. Unlike `is_dir`, which guesses from the extension, this asks the filesystem, so extensionless files and dirs with a dot in their name are classified correctly.
+	pub fn is_dir_fs(&self) -> bool {
+		self.file_system().is_dir(self.path())
+	}
+
+	/// Check if self is an existing file. Unlike `is_file`, which guesses from the extension, this asks the filesystem.
+	pub fn is_file_fs(&self) -> bool {
+		self.exists() && !self.is_dir_fs()
+	}
+
 	/// Get the name of the file/dir.
 	pub fn name(&self) -> &str {
 		self.last_node()
@@ -155,27 +385,22 @@ impl FileRef {
 
 	/// Get the name of the file without extension.
 	pub fn file_name_no_extension(&self) -> &str {
-		self.name().trim_end_matches(&self.extension().map(|extension| (".".to_owned() + extension)).unwrap_or_default())
+		self.name().trim_end_matches(&self.extension().map(|extension| ".".to_owned() + extension).unwrap_or_default())
 	}
 
 	/// Get the extension of the file.
 	pub fn extension(&self) -> Option<&str> {
-		let file_name:&str = self.name();
-		if file_name.contains('.') {
-			file_name.split('.').last()
-		} else {
-			None
-		}
+		self.name().rsplit_once('.').map(|(_, extension)| extension)
 	}
 
-	/// Check if the files exists.
+	/// Check if the files exists. Symlinks are followed, so a link to a missing target does not exist, use `is_symlink` to detect the link itself.
 	pub fn exists(&self) -> bool {
-		std::path::Path::new(&self.path()).exists() && std::fs::metadata(&self.path()).is_ok()
+		self.file_system().exists(self.path())
 	}
 	
 	/// Check if the file can be accessed.
 	pub fn is_accessible(&self) -> bool {
-		if self.is_dir() { true } else { std::fs::File::open(&self.path()).is_ok() }
+		self.file_system().is_accessible(self.path())
 	}
 
 
@@ -184,50 +409,54 @@ impl FileRef {
 
 	/// Read the contents of the file as a string.
 	pub fn read(&self) -> Result<String, Box<dyn Error>> {
-		use std::{ fs::File, io::Read };
-		
-		if self.is_dir() {
-			Err(format!("Could not read dir \"{}\". Only able to read files.", self.path()).into())
-		} else if !self.exists() {
-			Err(format!("Could not read file \"{}\". File does not exist.", self.path()).into())
-		} else {
-			let mut file:File = File::open(self.path())?;
-			let mut contents:String = String::new();
-			file.read_to_string(&mut contents)?;
-			Ok(contents)
-		}
+		String::from_utf8(self.read_bytes()?).map_err(|error| self.error(FileOperation::Read, error))
+	}
+
+	/// Read the contents of the file as a string, or return the given default if the file does not exist. Other errors are still returned.
+	pub fn read_or(&self, default:&str) -> Result<String, Box<dyn Error>> {
+		self.read_or_else(|| default.to_owned())
+	}
+
+	/// Read the contents of the file as a string, or return an empty string if the file does not exist. Other errors are still returned.
+	pub fn read_or_default(&self) -> Result<String, Box<dyn Error>> {
+		self.read_or_else(String::new)
+	}
+
+	/// Read the contents of the file as a string, or return the result of the given function if the file does not exist. Other errors are still returned.
+	pub fn read_or_else<T:FnOnce() -> String>(&self, default:T) -> Result<String, Box<dyn Error>> {
+		default_if_not_found(self.read(), default)
 	}
 
 	/// Read the contents of the file as bytes.
 	pub fn read_bytes(&self) -> Result<Vec<u8>, Box<dyn Error>> {
-		use std::{ fs::File, io::Read };
-		
-		if self.is_dir() {
-			Err(format!("Could not read dir \"{}\". Only able to read files.", self.path()).into())
-		} else if !self.exists() {
-			Err(format!("Could not read file \"{}\". File does not exist.", self.path()).into())
-		} else {
-			let mut file:File = File::open(self.path())?;
-			let mut content:Vec<u8> = Vec::new();
-			file.read_to_end(&mut content)?;
-			Ok(content)
-		}
+		self.traced("read", None, |data:&Vec<u8>| Some(data.len() as u64), || {
+			if self.is_dir() {
+				Err(self.error(FileOperation::Read, "Only able to read files."))
+			} else {
+				self.file_system().read(self.path()).map_err(|error| self.io_failure(FileOperation::Read, error))
+			}
+		})
 	}
 	
 	/// Read a specific range of bytes from the file.
 	pub fn read_range(&self, start:u64, end:u64) -> Result<Vec<u8>, Box<dyn Error>> {
-		use std::{ fs::File, io::{ Read, Seek, SeekFrom } };
+		self.traced("read", None, |data:&Vec<u8>| Some(data.len() as u64), || {
+			if self.is_dir() {
+				Err(self.error(FileOperation::Read, "Only able to read files."))
+			} else {
+				self.file_system().read_range(self.path(), start, end).map_err(|error| self.io_failure(FileOperation::Read, error))
+			}
+		})
+	}
 
-		if self.is_dir() {
-			Err(format!("Could not read dir \"{}\". Only able to read files.", self.path()).into())
-		} else if !self.exists() {
-			Err(format!("Could not read file \"{}\". File does not exist.", self.path()).into())
+	/// Wrap an error returned by the filesystem. Errors for missing files get a clear message, but keep their kind.
+	pub(crate) fn io_failure(&self, operation:FileOperation, error:Box<dyn Error>) -> Box<dyn Error> {
+		use std::io::{ Error as IoError, ErrorKind };
+
+		if error.downcast_ref::<IoError>().is_some_and(|error| error.kind() == ErrorKind::NotFound) {
+			self.error(operation, IoError::new(ErrorKind::NotFound, "File does not exist."))
 		} else {
-			let mut file:File = File::open(self.path())?;
-			let mut buffer:Vec<u8> = vec![0; (end - start) as usize];
-			file.seek(SeekFrom::Start(start))?;
-			file.read_exact(&mut buffer)?;
-			Ok(buffer)
+			self.error(operation, error)
 		}
 	}
 
@@ -238,7 +467,7 @@ impl FileRef {
 	/// If the file/dir does not exist, create it.
 	pub fn guarantee_exists(&self) -> Result<(), Box<dyn Error>> {
 		if !self.exists() {
-			self.create()?;
+			self.create_missing()?;
 		}
 		Ok(())
 	}
@@ -255,109 +484,287 @@ impl FileRef {
 
 	/// Create the file.
 	pub fn create(&self) -> Result<(), Box<dyn Error>> {
-		use std::fs::{ File, create_dir };
-
-		let is_dir:bool = self.is_dir();
-		if self.exists() {
-			Err(format!("Could not create {} \"{}\". {} already exists.", if is_dir { "dir" } else { "file" }, self.path(), if is_dir { "Dir" } else { "File" }).into())
-		} else {
-			self.guarantee_parent_dir()?;
-			if is_dir {
-				create_dir(self.path()).map_err(|error| error.into())
+		self.with_operation_hooks(FileOperation::Create, None, || {
+			if self.exists() {
+				Err(self.error(FileOperation::Create, if self.is_dir() { "Dir already exists." } else { "File already exists." }))
 			} else {
-				File::create(&self.path())?;
-				Ok(())
+				self.create_missing()
 			}
-		}
+		})
+	}
+
+	/// Create the file after its existence was already checked, so the check is not repeated.
+	fn create_missing(&self) -> Result<(), Box<dyn Error>> {
+		self.with_operation_hooks(FileOperation::Create, None, || {
+			self.guarantee_parent_dir()?;
+			self.file_system().create(self.path(), self.is_dir()).map_err(|error| self.error(FileOperation::Create, error))
+		})
 	}
 
 	/// Write a string to the file.
 	pub fn write(&self, contents:&str) -> Result<(), Box<dyn Error>> {
 		if self.is_dir() {
-			Err(format!("Could not write to dir \"{}\". Only able to write to files.", self.path()).into())
+			Err(self.error(FileOperation::Write, "Only able to write to files."))
 		} else {
 			self.write_bytes(contents.to_string().as_bytes())
 		}
 	}
 
-	/// Write bytes to the file.
+	/// Write bytes to the file. The write is attempted right away, the file and its parent dirs are only created if it turns out to be missing.
 	pub fn write_bytes(&self, data:&[u8]) -> Result<(), Box<dyn Error>> {
-		use std::{ fs::{ File, OpenOptions }, io::Write };
-		
-		if self.is_dir() {
-			Err(format!("Could not write to dir \"{}\". Only able to write to files.", self.path()).into())
-		} else {
-			self.guarantee_exists()?;
-			let mut file:File = OpenOptions::new().write(true).truncate(true).open(self.path())?;
-			file.write_all(data)?;
-			Ok(())
-		}
+		self.with_operation_hooks(FileOperation::Write, None, || self.traced("write", None, |_| Some(data.len() as u64), || {
+			if self.is_dir() {
+				return Err(self.error(FileOperation::Write, "Only able to write to files."));
+			}
+			match self.file_system().write_at(self.path(), None, data).map_err(|error| self.io_failure(FileOperation::Write, error)) {
+				Err(error) if error.downcast_ref::<FileRefError>().and_then(|error| error.io_error_kind()) == Some(std::io::ErrorKind::NotFound) => {
+					self.create_missing()?;
+					self.file_system().write_at(self.path(), None, data).map_err(|error| self.io_failure(FileOperation::Write, error))
+				},
+				result => result
+			}
+		}))
 	}
 	
 	/// Read a specific range of bytes from the file.
 	pub fn write_bytes_to_range(&self, start:u64, data:&[u8]) -> Result<(), Box<dyn Error>> {
-		use std::{ fs::{ File, OpenOptions }, io::{ Write, Seek, SeekFrom } };
-
-		if self.is_dir() {
-			Err(format!("Could not write to dir \"{}\". Only able to write to files.", self.path()).into())
-		} else if !self.exists() {
-			Err(format!("Could not write to file \"{}\". File does not exist.", self.path()).into())
-		} else {
-			let mut file:File = OpenOptions::new().write(true).open(self.path())?;
-			file.seek(SeekFrom::Start(start))?;
-			file.write_all(data).map_err(|error| error.into())
-		}
+		self.with_operation_hooks(FileOperation::Write, None, || self.traced("write", None, |_| Some(data.len() as u64), || {
+			if self.is_dir() {
+				Err(self.error(FileOperation::Write, "Only able to write to files."))
+			} else {
+				self.file_system().write_at(self.path(), Some(start), data).map_err(|error| self.io_failure(FileOperation::Write, error))
+			}
+		}))
 	}
 
 	/// Append bytes to the file.
 	pub fn append_bytes(&self, data:&[u8]) -> Result<(), Box<dyn Error>> {
-		use std::{ fs::{ File, OpenOptions }, io::Write };
+		self.with_operation_hooks(FileOperation::Append, None, || self.traced("append", None, |_| Some(data.len() as u64), || {
+			if self.is_dir() {
+				Err(self.error(FileOperation::Append, "Only able to append to files."))
+			} else {
+				self.file_system().append(self.path(), data).map_err(|error| self.io_failure(FileOperation::Append, error))
+			}
+		}))
+	}
 
-		if self.is_dir() {
-			Err(format!("Could not append to dir \"{}\". Only able to append to files.", self.path()).into())
-		} else if !self.exists() {
-			Err(format!("Could not append to file \"{}\". File does not exist.", self.path()).into())
-		} else {
-			self.guarantee_exists()?;
-			let mut file:File = OpenOptions::new().append(true).open(self.path())?;
-			file.write_all(data)?;
+	/// Replace the contents of the file by writing a uniquely named temporary sibling file and renaming it over the file, so readers never see a partially written file. The permissions of an existing file are kept. Files on mounted filesystems are written directly.
+	pub fn write_atomic(&self, data:&[u8]) -> Result<(), Box<dyn Error>> {
+		use std::{ fs::File, io::Write };
+
+		self.with_operation_hooks(FileOperation::Write, None, || self.traced("write", None, |_| Some(data.len() as u64), || {
+			if self.is_dir() {
+				return Err(self.error(FileOperation::Write, "Only able to write to files."));
+			} else if self.mounted_file_system().is_some() {
+				return self.write_bytes(data);
+			}
+			self.guarantee_parent_dir()?;
+			let temp_file:TempFileRef = TempFileRef::new_in(&self.parent_dir()?, "tmp")?;
+			let permissions:Option<std::fs::Permissions> = std::fs::metadata(self.os_path()).ok().map(|metadata| metadata.permissions());
+			File::create(temp_file.file_ref().os_path())
+				.and_then(|mut file| { file.write_all(data)?; file.sync_all() })
+				.and_then(|_| permissions.map(|permissions| std::fs::set_permissions(temp_file.file_ref().os_path(), permissions)).unwrap_or(Ok(())))
+				.and_then(|_| std::fs::rename(temp_file.file_ref().os_path(), self.os_path()))
+				.map_err(|error| self.error(FileOperation::Write, error))?;
+			temp_file.keep();
+
+			// Persist the rename itself. Not all platforms can sync dirs, so this is best effort.
+			#[cfg(unix)]
+			if let Ok(dir) = File::open(self.parent_dir()?.os_path()) {
+				let _ = dir.sync_all();
+			}
 			Ok(())
-		}
+		}))
+	}
+
+	/// Reserve disk space for the file up front, creating the file if it does not exist and extending it to at least the given length. Fails right away if the disk does not have enough space. On Linux with the 'libc' feature the space is reserved using `posix_fallocate`, elsewhere the file is extended, which allocates the space on NTFS.
+	pub fn preallocate(&self, length:u64) -> Result<(), Box<dyn Error>> {
+		use std::fs::{ File, OpenOptions };
+
+		self.with_operation_hooks(FileOperation::Write, None, || {
+			if self.is_dir() {
+				return Err(self.error(FileOperation::Write, "Only able to preallocate files."));
+			} else if self.mounted_file_system().is_some() {
+				return Err(self.error(FileOperation::Write, "Preallocation is only supported on the filesystem of the OS."));
+			}
+			self.guarantee_parent_dir()?;
+			let file:File = OpenOptions::new().write(true).create(true).truncate(false).open(self.os_path()).map_err(|error| self.error(FileOperation::Write, error))?;
+			if length == 0 {
+				return Ok(());
+			}
+
+			#[cfg(all(target_os="linux", feature="libc"))]
+			{
+				use std::os::unix::io::AsRawFd;
+
+				let length:libc::off_t = libc::off_t::try_from(length).map_err(|_| self.error(FileOperation::Write, "Length is too large."))?;
+				let result:i32 = unsafe { libc::posix_fallocate(file.as_raw_fd(), 0, length) };
+				if result != 0 {
+					return Err(self.error(FileOperation::Write, std::io::Error::from_raw_os_error(result)));
+				}
+			}
+			#[cfg(not(all(target_os="linux", feature="libc")))]
+			if file.metadata().map_err(|error| self.error(FileOperation::Write, error))?.len() < length {
+				file.set_len(length).map_err(|error| self.error(FileOperation::Write, error))?;
+			}
+			Ok(())
+		})
 	}
 
 
 
 	/* FILE MOVING METHODS */
 
-	/// Copy the file to another location. Returns the number of bytes written.
+	/// Copy the file to another location. Returns the number of bytes written. Files are streamed through memory when copying between different filesystems.
 	pub fn copy_to(&self, target:&FileRef) -> Result<u64, Box<dyn Error>> {
-		use std::fs::copy;
+		self.with_operation_hooks(FileOperation::Copy, Some(target), || self.traced("copy", Some(target), |bytes:&u64| Some(*bytes), || {
+			if self.is_dir() {
+				Err(self.error(FileOperation::Copy, "Only able to copy files."))
+			} else if !self.exists() {
+				Err(self.error(FileOperation::Copy, "File does not exist."))
+			} else {
+				target.guarantee_parent_dir()?;
+				if self.shares_file_system(target) {
+					self.file_system().copy(self.path(), target.path()).map_err(|error| FileRefError::new(FileOperation::Copy, self, error).with_target(target).into())
+				} else {
+					let data:Vec<u8> = self.read_bytes()?;
+					target.write_bytes(&data)?;
+					Ok(data.len() as u64)
+				}
+			}
+		}))
+	}
 
-		if self.is_dir() {
-			Err(format!("Could not copy dir \"{}\". Only able to copy files.", self.path()).into())
-		} else if !self.exists() {
-			Err(format!("Could not copy file \"{}\". File does not exist.", self.path()).into())
-		} else {
-			target.guarantee_parent_dir()?;
-			copy(self.path(), target.path()).map_err(|error| error.into())
-		}
+
+	/// Copy the file into the given dir, keeping its name. Creates the dir if it does not exist. Returns the copy.
+	pub fn copy_into(&self, dir:&FileRef) -> Result<FileRef, Box<dyn Error>> {
+		let target:FileRef = DirRef::create(dir)?.child(self.name());
+		self.copy_to(&target)?;
+		Ok(target)
+	}
+
+	/// Move the file into the given dir, keeping its name. Creates the dir if it does not exist. Returns the moved file.
+	pub fn move_into(&self, dir:&FileRef) -> Result<FileRef, Box<dyn Error>> {
+		let target:FileRef = DirRef::create(dir)?.child(self.name());
+		self.move_to(&target)?;
+		Ok(target)
 	}
 
+	/// Move the file or dir to another location, creating the parent dir of the target if needed. Replaces an existing target file. Renames when possible, and falls back to copying and deleting the original when the target is on a different device or filesystem.
+	pub fn move_to(&self, target:&FileRef) -> Result<(), Box<dyn Error>> {
+		use std::io::ErrorKind;
+
+		self.with_operation_hooks(FileOperation::Move, Some(target), || {
+			let move_error = |source:Box<dyn Error>| -> Box<dyn Error> { Box::new(FileRefError::new(FileOperation::Move, self, source).with_target(target)) };
+			if !self.exists() && !self.is_symlink() {
+				return Err(move_error("File does not exist.".into()));
+			}
+			target.guarantee_parent_dir()?;
+
+			if self.mounted_file_system().is_none() && target.mounted_file_system().is_none() {
+				match std::fs::rename(self.os_path(), target.os_path()) {
+					Ok(_) => return Ok(()),
+					Err(error) if error.kind() != ErrorKind::CrossesDevices => return Err(move_error(error.into())),
+					Err(_) => {}
+				}
+			}
+			if self.is_dir_fs() {
+				self.copy_dir_to(target, CopyOptions::new())?;
+			} else {
+				copy_file_contents(self, target)?;
+			}
+			self.delete()
+		})
+	}
 
+	/// Rename the file or dir within its dir. Returns the file at its new location.
+	pub fn rename_to(&self, name:&str) -> Result<FileRef, Box<dyn Error>> {
+		if name.is_empty() || name.contains(SEPARATOR) || name.contains(INVALID_SEPARATOR) {
+			return Err(self.error(FileOperation::Move, format!("Invalid name \"{name}\", use move_to to move to another dir.")));
+		}
+		let target:FileRef = match self.path().rfind(SEPARATOR) {
+			Some(separator_index) => FileRef::new(&format!("{}{name}", &self.path()[..separator_index + SEPARATOR.len()])),
+			None => FileRef::new(name)
+		};
+		self.move_to(&target)?;
+		Ok(target)
+	}
 
 	/* FILE REMOVING METHODS */
 
 	/// Delete the file.
 	pub fn delete(&self) -> Result<(), Box<dyn Error>> {
-		use std::fs::{ remove_dir_all, remove_file };
+		self.with_operation_hooks(FileOperation::Delete, None, || {
+			self.file_system().remove(self.path()).map_err(|error| self.error(FileOperation::Delete, error))
+		})
+	}
+
+
+
+	/* FILE LOCKING METHODS */
+
+	/// Open the file for locking. Creates the file if it does not exist yet, so processes can coordinate on a state file before it is written.
+	fn open_for_lock(&self) -> Result<std::fs::File, Box<dyn Error>> {
+		use std::fs::OpenOptions;
 
 		if self.is_dir() {
-			remove_dir_all(self.path()).map_err(|error| error.into())
+			Err(self.error(FileOperation::Lock, "Only able to lock files."))
 		} else {
-			remove_file(self.path()).map_err(|error| error.into())
+			self.require_std_file_system(FileOperation::Lock)?;
+			self.guarantee_exists()?;
+			OpenOptions::new().read(true).write(true).open(self.os_path()).map_err(|error| self.error(FileOperation::Lock, error))
 		}
 	}
 
+	/// Acquire an exclusive advisory lock on the file, blocking until it is available. Uses flock on Unix and LockFileEx on Windows. The lock is released when the returned guard is dropped.
+	pub fn lock_exclusive(&self) -> Result<FileLockGuard, Box<dyn Error>> {
+		let file:std::fs::File = self.open_for_lock()?;
+		file.lock().map_err(|error| self.error(FileOperation::Lock, error))?;
+		Ok(FileLockGuard::new(self, file))
+	}
+
+	/// Acquire a shared advisory lock on the file, blocking until it is available. Multiple processes can hold a shared lock at the same time, but not while any process holds an exclusive lock. The lock is released when the returned guard is dropped.
+	pub fn lock_shared(&self) -> Result<FileLockGuard, Box<dyn Error>> {
+		let file:std::fs::File = self.open_for_lock()?;
+		file.lock_shared().map_err(|error| self.error(FileOperation::Lock, error))?;
+		Ok(FileLockGuard::new(self, file))
+	}
+
+	/// Try to acquire an exclusive advisory lock on the file without blocking. Returns None if another handle already holds a lock on the file.
+	pub fn try_lock(&self) -> Result<Option<FileLockGuard>, Box<dyn Error>> {
+		use std::fs::TryLockError;
+
+		let file:std::fs::File = self.open_for_lock()?;
+		match file.try_lock() {
+			Ok(_) => Ok(Some(FileLockGuard::new(self, file))),
+			Err(TryLockError::WouldBlock) => Ok(None),
+			Err(TryLockError::Error(error)) => Err(self.error(FileOperation::Lock, error))
+		}
+	}
+
+	/// Run the given function while holding an exclusive lock on the file. The lock is released when the function returns.
+	pub fn with_lock<T, U>(&self, function:T) -> Result<U, Box<dyn Error>> where T:FnOnce() -> U {
+		let guard:FileLockGuard = self.lock_exclusive()?;
+		let result:U = function();
+		guard.unlock()?;
+		Ok(result)
+	}
+
+	/// Get the '.lock' sidecar file next to this file. Locking the sidecar instead of the file itself keeps the lock valid while the file is replaced by an atomic rename.
+	pub fn lock_sidecar_file(&self) -> FileRef {
+		self.clone() + LOCK_SIDECAR_EXTENSION
+	}
+
+	/// Acquire an exclusive lock on the '.lock' sidecar of this file, blocking until it is available.
+	pub fn lock_sidecar(&self) -> Result<FileLockGuard, Box<dyn Error>> {
+		self.lock_sidecar_file().lock_exclusive()
+	}
+
+	/// Run the given function while holding an exclusive lock on the '.lock' sidecar of this file.
+	pub fn with_sidecar_lock<T, U>(&self, function:T) -> Result<U, Box<dyn Error>> where T:FnOnce() -> U {
+		self.lock_sidecar_file().with_lock(function)
+	}
+
 
 
 	/* QUICK SCANNER METHODS */
@@ -392,6 +799,16 @@ impl PartialEq<FileRef> for FileRef {
 		self.path() == other.path() || self.clone().absolute().path() == other.clone().absolute().path()
 	}
 }
+impl PartialOrd<FileRef> for FileRef {
+	fn partial_cmp(&self, other:&FileRef) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+impl Ord for FileRef {
+	fn cmp(&self, other:&FileRef) -> Ordering {
+		self.path().cmp(other.path())
+	}
+}
 impl Add<&str> for FileRef {
 	type Output = FileRef;
 
@@ -404,6 +821,16 @@ impl AddAssign<&str> for FileRef {
 		*self = FileRef::new(&(self.path().to_owned() + rhs));
 	}
 }
+impl<'a> FromIterator<&'a str> for FileRef {
+	fn from_iter<T:IntoIterator<Item = &'a str>>(segments:T) -> FileRef {
+		FileRef::new(&segments.into_iter().collect::<Vec<&str>>().join(SEPARATOR))
+	}
+}
+impl FromIterator<String> for FileRef {
+	fn from_iter<T:IntoIterator<Item = String>>(segments:T) -> FileRef {
+		FileRef::new(&segments.into_iter().collect::<Vec<String>>().join(SEPARATOR))
+	}
+}
 impl Display for FileRef {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		write!(f, "{}", self.path())
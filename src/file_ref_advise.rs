@@ -0,0 +1,81 @@
+use std::{ error::Error, fs::{ File, OpenOptions }, io };
+use crate::{ FileOperation, FileRef };
+
+
+
+/// A hint to the OS on how a file will be accessed, so it can tune read-ahead and caching.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Access {
+	#[default]
+	Normal,
+	Sequential,
+	Random,
+	WillNeed,
+	DontNeed
+}
+impl Access {
+
+	/// Apply the hint to an open file. Sequential and random hints on Windows can only be given when opening a file, so those are ignored here. On Linux, hints are only applied with the 'libc' feature.
+	pub fn apply(&self, file:&File) -> io::Result<()> {
+		#[cfg(all(target_os="linux", feature="libc"))]
+		{
+			use std::os::unix::io::AsRawFd;
+
+			let advice:i32 = match self {
+				Access::Normal => libc::POSIX_FADV_NORMAL,
+				Access::Sequential => libc::POSIX_FADV_SEQUENTIAL,
+				Access::Random => libc::POSIX_FADV_RANDOM,
+				Access::WillNeed => libc::POSIX_FADV_WILLNEED,
+				Access::DontNeed => libc::POSIX_FADV_DONTNEED
+			};
+			let result:i32 = unsafe { libc::posix_fadvise(file.as_raw_fd(), 0, 0, advice) };
+			if result != 0 {
+				return Err(io::Error::from_raw_os_error(result));
+			}
+		}
+		#[cfg(not(all(target_os="linux", feature="libc")))]
+		let _ = file;
+		Ok(())
+	}
+
+	/// Get the flags to open a file with on Windows.
+	#[cfg(windows)]
+	fn open_flags(&self) -> u32 {
+		const FILE_FLAG_SEQUENTIAL_SCAN:u32 = 0x08000000;
+		const FILE_FLAG_RANDOM_ACCESS:u32 = 0x10000000;
+
+		match self {
+			Access::Sequential => FILE_FLAG_SEQUENTIAL_SCAN,
+			Access::Random => FILE_FLAG_RANDOM_ACCESS,
+			_ => 0
+		}
+	}
+}
+
+
+
+impl FileRef {
+
+	/* ACCESS HINT METHODS */
+
+	/// Tell the OS how the file will be accessed. Prefetching with `WillNeed` and dropping cached pages with `DontNeed` affect all later reads, sequential and random hints only last as long as the handle, so use `open_read_with_access` for those. Hints are ignored where the OS does not support them.
+	pub fn advise(&self, access:Access) -> Result<(), Box<dyn Error>> {
+		if self.mounted_file_system().is_some() {
+			return Ok(());
+		}
+		let file:File = self.open_read_with_access(access)?;
+		access.apply(&file).map_err(|error| self.error(FileOperation::Read, error))
+	}
+
+	/// Open the file for reading with an access hint, using `posix_fadvise` on Linux and `FILE_FLAG` hints on Windows.
+	pub fn open_read_with_access(&self, access:Access) -> Result<File, Box<dyn Error>> {
+		self.require_std_file_system(FileOperation::Read)?;
+		let mut options:OpenOptions = OpenOptions::new();
+		options.read(true);
+		#[cfg(windows)]
+		std::os::windows::fs::OpenOptionsExt::custom_flags(&mut options, access.open_flags());
+		let file:File = options.open(self.os_path()).map_err(|error| self.error(FileOperation::Read, error))?;
+		access.apply(&file).map_err(|error| self.error(FileOperation::Read, error))?;
+		Ok(file)
+	}
+}
\ No newline at end of file
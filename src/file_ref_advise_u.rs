@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+	use std::{ fs::File, io::{ Read, Seek, SeekFrom } };
+	use crate::{ Access, FileRef, MemoryFs, TempDirRef };
+
+
+
+	#[test]
+	fn test_advise() {
+		let temp_dir:TempDirRef = TempDirRef::new("advise_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/media.bin";
+		file.write_bytes(&[5; 4096]).unwrap();
+
+		for access in [Access::Normal, Access::Sequential, Access::Random, Access::WillNeed, Access::DontNeed] {
+			file.advise(access).unwrap();
+		}
+		assert!((temp_dir.path() + "/missing.bin").advise(Access::WillNeed).is_err());
+
+		// Hints on mounted filesystems are ignored.
+		let memory_fs:MemoryFs = MemoryFs::new();
+		assert!(memory_fs.file_ref("file.bin").advise(Access::WillNeed).is_ok());
+	}
+
+	#[test]
+	fn test_open_read_with_access() {
+		let temp_dir:TempDirRef = TempDirRef::new("advise_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/index.bin";
+		file.write_bytes(&(0..=255).collect::<Vec<u8>>()).unwrap();
+
+		let mut handle:File = file.open_read_with_access(Access::Random).unwrap();
+		let mut buffer:[u8; 2] = [0; 2];
+		handle.seek(SeekFrom::Start(200)).unwrap();
+		handle.read_exact(&mut buffer).unwrap();
+		assert_eq!(buffer, [200, 201]);
+	}
+}
\ No newline at end of file
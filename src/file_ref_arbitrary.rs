@@ -0,0 +1,85 @@
+/// Path segments that commonly trip up path handling, mixed into generated paths next to random ones.
+const ADVERSARIAL_SEGMENTS:[&str; 14] = ["file.txt", "dir", ".", "..", ".hidden", "trailing.", "a.b.c", "name with spaces", "ünïcödé", "日本語.md", "emoji_😀", "CON", "C:", "x"];
+const MAX_SEGMENTS:usize = 8;
+const MAX_SEGMENT_LENGTH:usize = 300;
+
+
+
+/// Build a raw path from a root and segments, joining each segment with a forward or backward slash.
+fn join_raw_path(root:&str, segments:&[(String, bool)]) -> String {
+	let mut path:String = root.to_string();
+	for (index, (segment, backslash)) in segments.iter().enumerate() {
+		if index > 0 {
+			path.push(if *backslash { '\\' } else { '/' });
+		}
+		path.push_str(segment);
+	}
+	path
+}
+
+
+
+#[cfg(feature="arbitrary")]
+mod arbitrary_impls {
+	use arbitrary::{ Arbitrary, Result, Unstructured };
+	use crate::FileRef;
+	use super::{ ADVERSARIAL_SEGMENTS, MAX_SEGMENTS, MAX_SEGMENT_LENGTH, join_raw_path };
+
+
+
+	impl<'a> Arbitrary<'a> for FileRef {
+		fn arbitrary(u:&mut Unstructured<'a>) -> Result<FileRef> {
+			let root:String = match u.int_in_range(0..=2)? {
+				0 => String::new(),
+				1 => "/".to_string(),
+				_ => format!("{}:/", u.int_in_range(b'A'..=b'Z')? as char)
+			};
+			let mut segments:Vec<(String, bool)> = Vec::new();
+			for _ in 0..u.int_in_range(1..=MAX_SEGMENTS)? {
+				let segment:String = match u.int_in_range(0..=2)? {
+					0 => u.choose(&ADVERSARIAL_SEGMENTS)?.to_string(),
+					1 => {
+						let segment:String = String::arbitrary(u)?.chars().filter(|character| !matches!(character, '/' | '\\' | '\0')).take(MAX_SEGMENT_LENGTH).collect();
+						if segment.is_empty() { "_".to_string() } else { segment }
+					},
+					_ => "a".repeat(u.int_in_range(1..=MAX_SEGMENT_LENGTH)?)
+				};
+				segments.push((segment, bool::arbitrary(u)?));
+			}
+			Ok(FileRef::new(&join_raw_path(&root, &segments)))
+		}
+	}
+}
+
+
+
+#[cfg(feature="proptest")]
+mod proptest_impls {
+	use proptest::{ arbitrary::{ Arbitrary, any }, collection::vec, prelude::Just, prop_oneof, sample::select, strategy::{ BoxedStrategy, Strategy } };
+	use crate::FileRef;
+	use super::{ ADVERSARIAL_SEGMENTS, MAX_SEGMENTS, MAX_SEGMENT_LENGTH, join_raw_path };
+
+
+
+	/// A proptest strategy for FileRefs made from a mix of plain, unicode, dotted and very long segments, joined by mixed separators.
+	pub fn file_ref_strategy() -> BoxedStrategy<FileRef> {
+		let root = prop_oneof![Just(String::new()), Just("/".to_string()), "[A-Z]".prop_map(|drive| format!("{drive}:/"))];
+		let segment = prop_oneof![
+			select(ADVERSARIAL_SEGMENTS.to_vec()).prop_map(|segment| segment.to_string()),
+			"[^/\\\\\\x00]{1,16}",
+			(1..=MAX_SEGMENT_LENGTH).prop_map(|length| "a".repeat(length))
+		];
+		(root, vec((segment, any::<bool>()), 1..=MAX_SEGMENTS)).prop_map(|(root, segments)| FileRef::new(&join_raw_path(&root, &segments))).boxed()
+	}
+
+	impl Arbitrary for FileRef {
+		type Parameters = ();
+		type Strategy = BoxedStrategy<FileRef>;
+
+		fn arbitrary_with(_parameters:()) -> BoxedStrategy<FileRef> {
+			file_ref_strategy()
+		}
+	}
+}
+#[cfg(feature="proptest")]
+pub use proptest_impls::file_ref_strategy;
\ No newline at end of file
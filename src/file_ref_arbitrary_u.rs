@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+	use crate::FileRef;
+
+
+
+	/// Check that a generated path is in the normalized form FileRef guarantees.
+	fn assert_normalized(file:&FileRef) {
+		assert!(!file.path().contains('\\'), "{file:?}");
+		assert!(!file.path().contains("//"), "{file:?}");
+		assert_eq!(&FileRef::new(file.path()), file);
+	}
+
+
+
+	#[test]
+	fn test_new_climbing_above_leading_dir() {
+		assert_eq!(FileRef::new("a/../../file.txt").path(), "../file.txt");
+		assert_normalized(&FileRef::new("a/../../file.txt"));
+	}
+
+	#[cfg(feature="arbitrary")]
+	#[test]
+	fn test_arbitrary_file_refs() {
+		use arbitrary::{ Arbitrary, Unstructured };
+
+		let data:Vec<u8> = (0..4096u32).map(|index| (index.wrapping_mul(2654435761) >> 13) as u8).collect();
+		let mut unstructured:Unstructured = Unstructured::new(&data);
+		let mut generated:usize = 0;
+		while let Ok(file) = FileRef::arbitrary(&mut unstructured) {
+			assert_normalized(&file);
+			generated += 1;
+			if unstructured.is_empty() {
+				break;
+			}
+		}
+		assert!(generated > 10);
+	}
+
+	#[cfg(feature="proptest")]
+	proptest::proptest! {
+		#[test]
+		fn test_proptest_file_refs(file in crate::file_ref_strategy()) {
+			assert_normalized(&file);
+		}
+
+		#[test]
+		fn test_proptest_any_file_ref(file in proptest::arbitrary::any::<FileRef>()) {
+			assert_normalized(&file);
+		}
+	}
+}
\ No newline at end of file
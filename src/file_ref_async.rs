@@ -0,0 +1,146 @@
+use std::{ error::Error, sync::Arc };
+use crate::{ FileRef, FileOperation, FileRefError, FileSystem, SEPARATOR };
+
+
+
+impl FileRef {
+
+	/* ASYNC FILE READING METHODS */
+
+	/// Read the contents of the file as a string without blocking the async runtime. Files on mounted filesystems are read on the blocking thread pool.
+	pub async fn read_async(&self) -> Result<String, Box<dyn Error>> {
+		String::from_utf8(self.read_bytes_async().await?).map_err(|error| self.error(FileOperation::Read, error))
+	}
+
+	/// Read the contents of the file as bytes without blocking the async runtime. Files on mounted filesystems are read on the blocking thread pool.
+	pub async fn read_bytes_async(&self) -> Result<Vec<u8>, Box<dyn Error>> {
+		if self.is_dir() {
+			Err(self.error(FileOperation::Read, "Only able to read files."))
+		} else if self.mounted_file_system().is_some() {
+			self.mounted_blocking(FileOperation::Read, |file_system, path| file_system.read(path)).await
+		} else {
+			tokio::fs::read(self.path()).await.map_err(|error| self.error(FileOperation::Read, error))
+		}
+	}
+
+
+
+	/* ASYNC FILE WRITING METHODS */
+
+	/// Write a string to the file without blocking the async runtime. Creates the parent dirs if they do not exist.
+	pub async fn write_async(&self, contents:&str) -> Result<(), Box<dyn Error>> {
+		self.write_bytes_async(contents.as_bytes()).await
+	}
+
+	/// Write bytes to the file without blocking the async runtime. Creates the parent dirs if they do not exist.
+	pub async fn write_bytes_async(&self, data:&[u8]) -> Result<(), Box<dyn Error>> {
+		if self.is_dir() {
+			Err(self.error(FileOperation::Write, "Only able to write to files."))
+		} else if self.mounted_file_system().is_some() {
+			let parent_dirs:Vec<String> = self.parent_dir()?.prefixes().map(|prefix| prefix.path().to_owned()).collect();
+			let data:Vec<u8> = data.to_vec();
+			self.mounted_blocking(FileOperation::Write, move |file_system, path| {
+				for parent_dir in parent_dirs.iter().filter(|parent_dir| !file_system.exists(parent_dir)) {
+					file_system.create(parent_dir, true)?;
+				}
+				if !file_system.exists(path) {
+					file_system.create(path, false)?;
+				}
+				file_system.write_at(path, None, &data)
+			}).await
+		} else {
+			let parent_dir:FileRef = self.parent_dir()?;
+			tokio::fs::create_dir_all(parent_dir.path()).await.map_err(|error| parent_dir.error(FileOperation::Create, error))?;
+			tokio::fs::write(self.path(), data).await.map_err(|error| self.error(FileOperation::Write, error))
+		}
+	}
+
+
+
+	/* ASYNC FILE MOVING METHODS */
+
+	/// Copy the file to another location without blocking the async runtime. Returns the number of bytes written.
+	pub async fn copy_to_async(&self, target:&FileRef) -> Result<u64, Box<dyn Error>> {
+		if self.is_dir() {
+			Err(self.error(FileOperation::Copy, "Only able to copy files."))
+		} else if self.mounted_file_system().is_some() || target.mounted_file_system().is_some() {
+			let data:Vec<u8> = self.read_bytes_async().await?;
+			target.write_bytes_async(&data).await?;
+			Ok(data.len() as u64)
+		} else {
+			let target_dir:FileRef = target.parent_dir()?;
+			tokio::fs::create_dir_all(target_dir.path()).await.map_err(|error| target_dir.error(FileOperation::Create, error))?;
+			tokio::fs::copy(self.path(), target.path()).await.map_err(|error| FileRefError::new(FileOperation::Copy, self, error).with_target(target).into())
+		}
+	}
+
+
+
+	/* ASYNC SCANNER METHODS */
+
+	/// List all files in this dir without blocking the async runtime.
+	pub async fn list_files_async(&self) -> Vec<FileRef> {
+		self.scan_async(true, false, false).await
+	}
+
+	/// List all files in this dir and its sub-dirs without blocking the async runtime.
+	pub async fn list_files_recurse_async(&self) -> Vec<FileRef> {
+		self.scan_async(true, false, true).await
+	}
+
+	/// List all dirs in this dir without blocking the async runtime.
+	pub async fn list_dirs_async(&self) -> Vec<FileRef> {
+		self.scan_async(false, true, false).await
+	}
+
+	/// List all dirs in this dir and its sub-dirs without blocking the async runtime.
+	pub async fn list_dirs_recurse_async(&self) -> Vec<FileRef> {
+		self.scan_async(false, true, true).await
+	}
+
+	/// List the entries in this dir, like the FileScanner does. Entries are told apart by their actual type instead of their extension, and symlinks to dirs are not followed. The entries of a dir are listed before the entries of its sub-dirs.
+	async fn scan_async(&self, include_files:bool, include_dirs:bool, recurse:bool) -> Vec<FileRef> {
+		let mut results:Vec<FileRef> = Vec::new();
+		let mut pending_dirs:Vec<FileRef> = vec![self.clone().absolute().trim_end_matches(SEPARATOR)];
+		while let Some(dir) = pending_dirs.pop() {
+			let entries:Vec<(FileRef, bool)> = dir.entries_async().await;
+			results.extend(entries.iter().filter(|(_, is_dir)| if *is_dir { include_dirs } else { include_files }).map(|(entry, _)| entry.clone()));
+			if recurse {
+				pending_dirs.extend(entries.into_iter().filter(|(_, is_dir)| *is_dir).map(|(entry, _)| entry).rev());
+			}
+		}
+		results
+	}
+
+	/// List the direct children of this dir, together with whether each child is a dir. Returns an empty list if the dir can not be read.
+	async fn entries_async(&self) -> Vec<(FileRef, bool)> {
+		if self.mounted_file_system().is_some() {
+			let listed:Result<Vec<(bool, String)>, Box<dyn Error>> = self.mounted_blocking(FileOperation::Scan, |file_system, path| Ok(file_system.list(path)?.into_iter().map(|entry_path| (file_system.is_dir(&entry_path), entry_path)).collect())).await;
+			return listed.map(|entries| entries.iter().map(|(is_dir, path)| (FileRef::new(path), *is_dir)).collect()).unwrap_or_default();
+		}
+		let mut entries:Vec<(FileRef, bool)> = Vec::new();
+		if let Ok(mut read_dir) = tokio::fs::read_dir(self.os_path()).await {
+			while let Ok(Some(dir_entry)) = read_dir.next_entry().await {
+				if let (Some(path), Ok(file_type)) = (dir_entry.path().to_str(), dir_entry.file_type().await) {
+					entries.push((FileRef::new(path), file_type.is_dir()));
+				}
+			}
+		}
+		entries
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Run a call on the mounted filesystem of the file on the blocking thread pool, like tokio::fs does for the filesystem of the OS. Filesystem errors are not Send, so only IO errors keep their kind and other errors are passed on as their message.
+	async fn mounted_blocking<T, U>(&self, operation:FileOperation, action:U) -> Result<T, Box<dyn Error>> where T:Send + 'static, U:FnOnce(&dyn FileSystem, &str) -> Result<T, Box<dyn Error>> + Send + 'static {
+		let file_system:Arc<dyn FileSystem> = self.file_system();
+		let path:String = self.path().to_owned();
+		let result:Result<T, Box<dyn Error + Send + Sync>> = tokio::task::spawn_blocking(move || action(file_system.as_ref(), &path).map_err(|error| match error.downcast::<std::io::Error>() {
+			Ok(error) => error as Box<dyn Error + Send + Sync>,
+			Err(error) => error.to_string().into()
+		})).await.map_err(|error| self.error(operation, error))?;
+		result.map_err(|error| self.error(operation, error as Box<dyn Error>))
+	}
+}
\ No newline at end of file
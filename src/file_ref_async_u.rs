@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, MemoryFs, TempDirRef };
+
+
+
+	#[tokio::test]
+	async fn test_read_write_async() {
+		let temp_dir:TempDirRef = TempDirRef::new("async_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/sub/file.txt";
+		file.write_async("Hello async").await.unwrap();
+		assert_eq!(file.read_async().await.unwrap(), "Hello async");
+		assert_eq!(file.read().unwrap(), "Hello async");
+		assert!((temp_dir.path() + "/missing.txt").read_async().await.is_err());
+	}
+
+	#[tokio::test]
+	async fn test_copy_to_async() {
+		let temp_dir:TempDirRef = TempDirRef::new("async_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/source.txt";
+		source.write("contents").unwrap();
+		assert_eq!(source.copy_to_async(&(temp_dir.path() + "/copy/target.txt")).await.unwrap(), 8);
+		assert_eq!((temp_dir.path() + "/copy/target.txt").read().unwrap(), "contents");
+
+		let memory_fs:MemoryFs = MemoryFs::new();
+		assert_eq!(source.copy_to_async(&memory_fs.file_ref("target.txt")).await.unwrap(), 8);
+		assert_eq!(memory_fs.file_ref("target.txt").read_async().await.unwrap(), "contents");
+	}
+
+	#[tokio::test]
+	async fn test_mounted_async() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let file:FileRef = memory_fs.file_ref("data/sub/file.txt");
+		file.write_async("contents").await.unwrap();
+		assert_eq!(file.read().unwrap(), "contents");
+		assert_eq!(file.read_async().await.unwrap(), "contents");
+		assert_eq!(memory_fs.file_ref("data").list_files_recurse_async().await, vec![file.clone()]);
+		assert!(memory_fs.file_ref("data/missing.txt").read_async().await.is_err());
+	}
+
+	#[tokio::test]
+	async fn test_scanner_async() {
+		let temp_dir:TempDirRef = TempDirRef::new("async_test_").unwrap();
+		(temp_dir.path() + "/a.txt").write("a").unwrap();
+		(temp_dir.path() + "/sub/b.txt").write("b").unwrap();
+		(temp_dir.path() + "/sub/deeper/c.txt").write("c").unwrap();
+
+		let mut files:Vec<String> = temp_dir.path().list_files_recurse_async().await.iter().map(|file| file.name().to_owned()).collect();
+		files.sort();
+		assert_eq!(files, vec!["a.txt", "b.txt", "c.txt"]);
+		assert_eq!(temp_dir.path().list_files_async().await.len(), 1);
+		assert_eq!(temp_dir.path().list_dirs_async().await.len(), 1);
+		assert_eq!(temp_dir.path().list_dirs_recurse_async().await.len(), 2);
+	}
+
+	#[tokio::test]
+	async fn test_scanner_async_extensionless_files_and_dotted_dirs() {
+		let temp_dir:TempDirRef = TempDirRef::new("async_test_").unwrap();
+		std::fs::create_dir((temp_dir.path() + "/lib.v2").os_path()).unwrap();
+		std::fs::write((temp_dir.path() + "/Makefile").os_path(), "all:").unwrap();
+		std::fs::write((temp_dir.path() + "/lib.v2/LICENSE").os_path(), "MIT").unwrap();
+
+		let mut files:Vec<String> = temp_dir.path().list_files_recurse_async().await.iter().map(|file| file.name().to_owned()).collect();
+		files.sort();
+		assert_eq!(files, vec!["LICENSE", "Makefile"]);
+		assert_eq!(temp_dir.path().list_dirs_recurse_async().await.iter().map(|dir| dir.name()).collect::<Vec<&str>>(), vec!["lib.v2"]);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+#[cfg(feature="rkyv")]
+mod rkyv_impls {
+	use rkyv::{ Archive, Deserialize, Place, Serialize, SerializeUnsized, rancor::{ Fallible, Source }, string::{ ArchivedString, StringResolver } };
+	use crate::FileRef;
+
+
+
+	impl Archive for FileRef {
+		type Archived = ArchivedString;
+		type Resolver = StringResolver;
+
+		fn resolve(&self, resolver:StringResolver, out:Place<ArchivedString>) {
+			ArchivedString::resolve_from_str(self.path(), resolver, out);
+		}
+	}
+
+	impl<S:Fallible + ?Sized> Serialize<S> for FileRef where S::Error:Source, str:SerializeUnsized<S> {
+		fn serialize(&self, serializer:&mut S) -> Result<StringResolver, S::Error> {
+			ArchivedString::serialize_from_str(self.path(), serializer)
+		}
+	}
+
+	impl<D:Fallible + ?Sized> Deserialize<FileRef, D> for ArchivedString {
+		fn deserialize(&self, _deserializer:&mut D) -> Result<FileRef, D::Error> {
+			Ok(FileRef::new(self.as_str()))
+		}
+	}
+}
+
+
+
+#[cfg(feature="bincode")]
+mod bincode_impls {
+	use bincode::{ Decode, Encode, de::Decoder, enc::Encoder, error::{ DecodeError, EncodeError } };
+	use crate::FileRef;
+
+
+
+	impl Encode for FileRef {
+		fn encode<E:Encoder>(&self, encoder:&mut E) -> Result<(), EncodeError> {
+			self.path().encode(encoder)
+		}
+	}
+
+	impl<Context> Decode<Context> for FileRef {
+		fn decode<D:Decoder<Context = Context>>(decoder:&mut D) -> Result<FileRef, DecodeError> {
+			let path:String = String::decode(decoder)?;
+			Ok(FileRef::new(&path))
+		}
+	}
+
+	bincode::impl_borrow_decode!(FileRef);
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ DirSnapshot, FileRef, HashAlgorithm, Manifest, TempDirRef };
+
+
+
+	fn create_test_dir() -> TempDirRef {
+		let temp_dir:TempDirRef = TempDirRef::new("binary_test_").unwrap();
+		(temp_dir.path() + "/a.txt").write("first").unwrap();
+		(temp_dir.path() + "/sub/b.txt").write("second").unwrap();
+		temp_dir
+	}
+
+
+
+	#[cfg(feature="rkyv")]
+	#[test]
+	fn test_rkyv_file_ref() {
+		use rkyv::{ rancor, string::ArchivedString, util::AlignedVec };
+
+		let file:FileRef = FileRef::new("/projects/application/target/debug/output.txt");
+		let bytes:AlignedVec = rkyv::to_bytes::<rancor::Error>(&file).unwrap();
+		let archived:&ArchivedString = rkyv::access::<ArchivedString, rancor::Error>(&bytes).unwrap();
+		assert_eq!(archived.as_str(), file.path());
+		assert_eq!(rkyv::deserialize::<FileRef, rancor::Error>(archived).unwrap(), file);
+	}
+
+	#[cfg(feature="rkyv")]
+	#[test]
+	fn test_rkyv_manifest_and_snapshot_from_file() {
+		use rkyv::{ rancor, util::AlignedVec };
+		use crate::{ ArchivedDirSnapshot, ArchivedManifest };
+
+		let temp_dir:TempDirRef = create_test_dir();
+		let manifest:Manifest = Manifest::create(&temp_dir.path(), HashAlgorithm::Sha256).unwrap();
+		let snapshot:DirSnapshot = DirSnapshot::capture(&temp_dir.path()).unwrap();
+		let manifest_file:FileRef = temp_dir.path() + "/index/manifest.rkyv";
+		let snapshot_file:FileRef = temp_dir.path() + "/index/snapshot.rkyv";
+		manifest_file.write_bytes(&rkyv::to_bytes::<rancor::Error>(&manifest).unwrap()).unwrap();
+		snapshot_file.write_bytes(&rkyv::to_bytes::<rancor::Error>(&snapshot).unwrap()).unwrap();
+
+		let mut bytes:AlignedVec = AlignedVec::new();
+		bytes.extend_from_slice(&manifest_file.read_bytes().unwrap());
+		let archived:&ArchivedManifest = rkyv::access::<ArchivedManifest, rancor::Error>(&bytes).unwrap();
+		assert_eq!(rkyv::deserialize::<Manifest, rancor::Error>(archived).unwrap(), manifest);
+
+		let mut bytes:AlignedVec = AlignedVec::new();
+		bytes.extend_from_slice(&snapshot_file.read_bytes().unwrap());
+		let archived:&ArchivedDirSnapshot = rkyv::access::<ArchivedDirSnapshot, rancor::Error>(&bytes).unwrap();
+		assert_eq!(rkyv::deserialize::<DirSnapshot, rancor::Error>(archived).unwrap(), snapshot);
+
+		// Corrupt data is rejected instead of being read.
+		assert!(rkyv::access::<ArchivedManifest, rancor::Error>(&bytes[..bytes.len() / 2]).is_err());
+	}
+
+	#[cfg(feature="bincode")]
+	#[test]
+	fn test_bincode_roundtrip() {
+		let temp_dir:TempDirRef = create_test_dir();
+		let manifest:Manifest = Manifest::create(&temp_dir.path(), HashAlgorithm::Crc32).unwrap();
+		let snapshot:DirSnapshot = DirSnapshot::capture(&temp_dir.path()).unwrap();
+		let file:FileRef = FileRef::new("dir/file.txt");
+
+		let bytes:Vec<u8> = bincode::encode_to_vec((&file, &manifest, &snapshot), bincode::config::standard()).unwrap();
+		let ((decoded_file, decoded_manifest, decoded_snapshot), length):((FileRef, Manifest, DirSnapshot), usize) = bincode::decode_from_slice(&bytes, bincode::config::standard()).unwrap();
+		assert_eq!(length, bytes.len());
+		assert_eq!(decoded_file, file);
+		assert_eq!(decoded_manifest, manifest);
+		assert_eq!(decoded_snapshot, snapshot);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use camino::{ Utf8Path, Utf8PathBuf };
+use crate::FileRef;
+
+
+
+impl From<&Utf8Path> for FileRef {
+	fn from(path:&Utf8Path) -> FileRef {
+		FileRef::new(path.as_str())
+	}
+}
+impl From<&Utf8PathBuf> for FileRef {
+	fn from(path:&Utf8PathBuf) -> FileRef {
+		FileRef::new(path.as_str())
+	}
+}
+impl From<Utf8PathBuf> for FileRef {
+	fn from(path:Utf8PathBuf) -> FileRef {
+		FileRef::new(path.as_str())
+	}
+}
+impl From<&FileRef> for Utf8PathBuf {
+	fn from(file:&FileRef) -> Utf8PathBuf {
+		Utf8PathBuf::from(file.path())
+	}
+}
+impl From<FileRef> for Utf8PathBuf {
+	fn from(file:FileRef) -> Utf8PathBuf {
+		Utf8PathBuf::from(file.path())
+	}
+}
+impl AsRef<Utf8Path> for FileRef {
+	fn as_ref(&self) -> &Utf8Path {
+		Utf8Path::new(self.path())
+	}
+}
\ No newline at end of file
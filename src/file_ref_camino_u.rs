@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+	use camino::{ Utf8Path, Utf8PathBuf };
+	use crate::FileRef;
+
+
+
+	#[test]
+	fn test_camino_conversions() {
+		let path:Utf8PathBuf = Utf8PathBuf::from("target\\debug/app.exe");
+		let file:FileRef = FileRef::from(&path);
+		assert_eq!(file.path(), "target/debug/app.exe");
+		assert_eq!(FileRef::from(path.as_path()), file);
+		assert_eq!(FileRef::from(path), file);
+
+		let path:Utf8PathBuf = Utf8PathBuf::from(&file);
+		assert_eq!(path.as_str(), "target/debug/app.exe");
+		let path:&Utf8Path = file.as_ref();
+		assert_eq!(path.file_name(), Some("app.exe"));
+		assert_eq!(Utf8PathBuf::from(file).extension(), Some("exe"));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,109 @@
+use std::ffi::OsStr;
+use clap::{ Arg, Command, builder::{ TypedValueParser, ValueParserFactory }, error::{ Error, ErrorKind } };
+use crate::FileRef;
+
+
+
+#[derive(Clone, Copy, PartialEq)]
+enum FileRefCheck {
+	Exists,
+	IsFile,
+	IsDir,
+	ParentExists
+}
+impl FileRefCheck {
+
+	/// Check if the file passes the check. Returns the reason it did not if it failed.
+	fn validate(&self, file:&FileRef) -> Result<(), &'static str> {
+		let passed:bool = match self {
+			FileRefCheck::Exists => file.exists(),
+			FileRefCheck::IsFile => file.metadata().is_ok_and(|metadata| metadata.is_file()),
+			FileRefCheck::IsDir => file.metadata().is_ok_and(|metadata| metadata.is_dir()),
+			FileRefCheck::ParentExists => file.parent_dir().is_ok_and(|parent| parent.exists())
+		};
+		if passed {
+			Ok(())
+		} else {
+			Err(match self {
+				FileRefCheck::Exists => "File does not exist.",
+				FileRefCheck::IsFile => "Path is not an existing file.",
+				FileRefCheck::IsDir => "Path is not an existing directory.",
+				FileRefCheck::ParentExists => "Parent directory does not exist."
+			})
+		}
+	}
+}
+
+
+
+/// A clap value parser that turns arguments into FileRefs, optionally validating them against the filesystem.
+#[derive(Clone, Default)]
+pub struct FileRefValueParser {
+	checks:Vec<FileRefCheck>
+}
+impl FileRefValueParser {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new parser that accepts any path.
+	pub fn new() -> FileRefValueParser {
+		FileRefValueParser::default()
+	}
+
+	/// Return self requiring the path to exist.
+	pub fn exists(self) -> Self {
+		self.with_check(FileRefCheck::Exists)
+	}
+
+	/// Return self requiring the path to be an existing file.
+	pub fn is_file(self) -> Self {
+		self.with_check(FileRefCheck::IsFile)
+	}
+
+	/// Return self requiring the path to be an existing directory.
+	pub fn is_dir(self) -> Self {
+		self.with_check(FileRefCheck::IsDir)
+	}
+
+	/// Return self requiring the directory the path is in to exist.
+	pub fn parent_exists(self) -> Self {
+		self.with_check(FileRefCheck::ParentExists)
+	}
+
+	/// Return self with an additional check.
+	fn with_check(mut self, check:FileRefCheck) -> Self {
+		if !self.checks.contains(&check) {
+			self.checks.push(check);
+		}
+		self
+	}
+}
+impl TypedValueParser for FileRefValueParser {
+	type Value = FileRef;
+
+	fn parse_ref(&self, command:&Command, arg:Option<&Arg>, value:&OsStr) -> Result<FileRef, Error> {
+		let arg_name:String = arg.map(|arg| arg.to_string()).unwrap_or_else(|| "...".to_string());
+		let path:&str = value.to_str().ok_or_else(|| Error::raw(ErrorKind::InvalidUtf8, format!("invalid value for '{arg_name}': Path is not valid UTF-8.\n")).with_cmd(command))?;
+		if path.is_empty() {
+			return Err(Error::raw(ErrorKind::InvalidValue, format!("invalid value '' for '{arg_name}': Path is empty.\n")).with_cmd(command));
+		}
+
+		let file:FileRef = FileRef::new(path);
+		for check in &self.checks {
+			if let Err(reason) = check.validate(&file) {
+				return Err(Error::raw(ErrorKind::ValueValidation, format!("invalid value '{path}' for '{arg_name}': {reason}\n")).with_cmd(command));
+			}
+		}
+		Ok(file)
+	}
+}
+
+
+
+impl ValueParserFactory for FileRef {
+	type Parser = FileRefValueParser;
+
+	fn value_parser() -> FileRefValueParser {
+		FileRefValueParser::new()
+	}
+}
\ No newline at end of file
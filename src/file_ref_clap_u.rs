@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+	use clap::{ Arg, ArgMatches, Command, error::ErrorKind, value_parser };
+	use crate::{ FileRef, FileRefValueParser, TempDirRef };
+
+
+
+	fn command(parser:FileRefValueParser) -> Command {
+		Command::new("test").arg(Arg::new("path").required(true).value_parser(parser))
+	}
+
+
+
+	#[test]
+	fn test_clap_parses_file_ref() {
+		let matches:ArgMatches = Command::new("test").arg(Arg::new("path").value_parser(value_parser!(FileRef))).try_get_matches_from(["test", "dir/file.txt"]).unwrap();
+		assert_eq!(matches.get_one::<FileRef>("path").unwrap().path(), "dir/file.txt");
+	}
+
+	#[test]
+	fn test_clap_validators() {
+		let temp_dir:TempDirRef = TempDirRef::new("clap_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/file.txt";
+		file.write("contents").unwrap();
+		let missing:FileRef = temp_dir.path() + "/missing/file.txt";
+
+		assert!(command(FileRefValueParser::new().exists().is_file()).try_get_matches_from(["test", file.path()]).is_ok());
+		assert!(command(FileRefValueParser::new().is_dir()).try_get_matches_from(["test", temp_dir.path().path()]).is_ok());
+		assert!(command(FileRefValueParser::new().parent_exists()).try_get_matches_from(["test", (temp_dir.path() + "/new.txt").path()]).is_ok());
+
+		let error:clap::Error = command(FileRefValueParser::new().exists()).try_get_matches_from(["test", missing.path()]).unwrap_err();
+		assert_eq!(error.kind(), ErrorKind::ValueValidation);
+		assert!(error.to_string().contains("File does not exist."));
+		assert!(error.to_string().contains(missing.path()));
+
+		let error:clap::Error = command(FileRefValueParser::new().is_file()).try_get_matches_from(["test", temp_dir.path().path()]).unwrap_err();
+		assert!(error.to_string().contains("Path is not an existing file."));
+
+		let error:clap::Error = command(FileRefValueParser::new().parent_exists()).try_get_matches_from(["test", missing.path()]).unwrap_err();
+		assert!(error.to_string().contains("Parent directory does not exist."));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,131 @@
+use std::{ collections::BTreeSet, error::Error, fs::Metadata, time::{ Duration, SystemTime } };
+use crate::{ FileOperation, FileRef, ResultFilter, now };
+
+
+
+/// Options for removing old entries from a dir.
+pub struct CleanupOptions {
+	filter:ResultFilter,
+	remove_empty_dirs:bool,
+	dry_run:bool
+}
+impl Default for CleanupOptions {
+	fn default() -> CleanupOptions {
+		CleanupOptions { filter: Box::new(|_| true), remove_empty_dirs: true, dry_run: false }
+	}
+}
+impl CleanupOptions {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create new default cleanup options. All old files are removed, as well as dirs that are empty afterwards.
+	pub fn new() -> CleanupOptions {
+		CleanupOptions::default()
+	}
+
+	/// Return self with a filter. Only files the filter returns true for are removed.
+	pub fn filter<T>(mut self, filter:T) -> Self where T:Fn(&FileRef) -> bool + 'static {
+		self.filter = Box::new(filter);
+		self
+	}
+
+	/// Return self with a setting to keep dirs that are empty after the cleanup.
+	pub fn keep_empty_dirs(mut self) -> Self {
+		self.remove_empty_dirs = false;
+		self
+	}
+
+	/// Return self with a setting to only report what would be removed, without removing anything.
+	pub fn dry_run(mut self) -> Self {
+		self.dry_run = true;
+		self
+	}
+}
+
+
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CleanupReport {
+	pub removed_files:Vec<FileRef>,
+	pub removed_dirs:Vec<FileRef>,
+	pub bytes_freed:u64
+}
+
+
+
+/// All files and dirs in a dir, recursively. Dirs are ordered so sub-dirs come before their parents.
+pub(crate) struct DirEntries {
+	pub files:Vec<(FileRef, Metadata)>,
+	pub dirs:Vec<FileRef>
+}
+impl DirEntries {
+
+	/// Collect the entries of the given dir. Uses the metadata of the entries instead of their names to tell files and dirs apart.
+	pub fn collect(dir:&FileRef) -> Result<DirEntries, Box<dyn Error>> {
+		dir.require_std_file_system(FileOperation::Scan)?;
+		let mut entries:DirEntries = DirEntries { files: Vec::new(), dirs: Vec::new() };
+		entries.collect_dir(dir)?;
+		Ok(entries)
+	}
+
+	/// Add the entries of a single dir, recursing into its sub-dirs first.
+	fn collect_dir(&mut self, dir:&FileRef) -> Result<(), Box<dyn Error>> {
+		let entries:Vec<std::fs::DirEntry> = dir.traced("scan", None, |entries:&Vec<std::fs::DirEntry>| Some(entries.len() as u64), || {
+			std::fs::read_dir(dir.os_path()).map_err(|error| dir.io_failure(FileOperation::Scan, Box::new(error)))?.map(|entry| entry.map_err(|error| dir.error(FileOperation::Scan, error))).collect()
+		})?;
+		for entry in entries {
+			let path:FileRef = FileRef::new(&entry.path().to_string_lossy());
+			let metadata:Metadata = std::fs::symlink_metadata(path.os_path()).map_err(|error| path.error(FileOperation::Scan, error))?;
+			if metadata.is_dir() {
+				self.collect_dir(&path)?;
+				self.dirs.push(path);
+			} else {
+				self.files.push((path, metadata));
+			}
+		}
+		Ok(())
+	}
+}
+
+
+
+impl FileRef {
+
+	/* CLEANUP METHODS */
+
+	/// Remove all files in this dir, recursively, that were last modified longer ago than the given age. Dirs that are empty after removing the files are removed as well, unless disabled in the options. The dir itself is never removed.
+	pub fn cleanup_older_than(&self, age:Duration, options:CleanupOptions) -> Result<CleanupReport, Box<dyn Error>> {
+		self.require_std_file_system(FileOperation::Delete)?;
+		let cutoff:SystemTime = now().checked_sub(age).unwrap_or(SystemTime::UNIX_EPOCH);
+		let entries:DirEntries = DirEntries::collect(self)?;
+		let mut report:CleanupReport = CleanupReport::default();
+
+		// Remove old files.
+		let mut removed:BTreeSet<String> = BTreeSet::new();
+		for (file, metadata) in entries.files {
+			if metadata.modified().is_ok_and(|modified| modified < cutoff) && (options.filter)(&file) {
+				if !options.dry_run {
+					std::fs::remove_file(file.os_path()).map_err(|error| file.error(FileOperation::Delete, error))?;
+				}
+				report.bytes_freed += metadata.len();
+				removed.insert(file.path().to_owned());
+				report.removed_files.push(file);
+			}
+		}
+
+		// Remove dirs that are empty now. Sub-dirs come first, so dirs only containing empty dirs are removed too.
+		if options.remove_empty_dirs {
+			for dir in entries.dirs {
+				let is_empty:bool = std::fs::read_dir(dir.os_path()).map_err(|error| dir.error(FileOperation::Scan, error))?.all(|entry| entry.is_ok_and(|entry| removed.contains(FileRef::new(&entry.path().to_string_lossy()).path())));
+				if is_empty {
+					if !options.dry_run {
+						std::fs::remove_dir(dir.os_path()).map_err(|error| dir.error(FileOperation::Delete, error))?;
+					}
+					removed.insert(dir.path().to_owned());
+					report.removed_dirs.push(dir);
+				}
+			}
+		}
+		Ok(report)
+	}
+}
\ No newline at end of file
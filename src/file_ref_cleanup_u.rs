@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+	use std::{ fs::File, sync::Arc, time::{ Duration, SystemTime } };
+	use crate::{ CleanupOptions, CleanupReport, FileRef, ManualClock, TempDirRef, with_clock };
+
+
+
+	fn write_aged(file:&FileRef, age_in_days:u64) {
+		// Written through std, as the paths include an extensionless file and a dir with a dot in its name.
+		std::fs::create_dir_all(file.parent_dir().unwrap().path()).unwrap();
+		std::fs::write(file.path(), "contents").unwrap();
+		File::options().write(true).open(file.path()).unwrap().set_modified(SystemTime::now() - Duration::from_secs(age_in_days * 24 * 60 * 60)).unwrap();
+	}
+
+	fn create_cache() -> TempDirRef {
+		let temp_dir:TempDirRef = TempDirRef::new("cleanup_test_").unwrap();
+		write_aged(&(temp_dir.path() + "/old.txt"), 10);
+		write_aged(&(temp_dir.path() + "/new.txt"), 0);
+		write_aged(&(temp_dir.path() + "/blobs/ab12"), 10);
+		write_aged(&(temp_dir.path() + "/v1.2/old.log"), 10);
+		write_aged(&(temp_dir.path() + "/v1.2/new.log"), 0);
+		temp_dir
+	}
+
+
+
+	#[test]
+	fn test_cleanup_older_than() {
+		let temp_dir:TempDirRef = create_cache();
+		let report:CleanupReport = temp_dir.path().cleanup_older_than(Duration::from_secs(24 * 60 * 60), CleanupOptions::new()).unwrap();
+		assert_eq!(report.removed_files.len(), 3);
+		assert_eq!(report.removed_dirs, vec![temp_dir.path() + "/blobs"]);
+		assert_eq!(report.bytes_freed, 24);
+
+		assert!(!(temp_dir.path() + "/old.txt").exists());
+		assert!((temp_dir.path() + "/new.txt").exists());
+		assert!(!(temp_dir.path() + "/blobs").exists());
+		assert!((temp_dir.path() + "/v1.2/new.log").exists());
+		assert!(temp_dir.path().exists());
+	}
+
+	#[test]
+	fn test_cleanup_dry_run_and_filter() {
+		let temp_dir:TempDirRef = create_cache();
+		let report:CleanupReport = temp_dir.path().cleanup_older_than(Duration::from_secs(60), CleanupOptions::new().dry_run()).unwrap();
+		assert_eq!(report.removed_files.len(), 3);
+		assert_eq!(report.removed_dirs.len(), 1);
+		assert!((temp_dir.path() + "/old.txt").exists());
+
+		let report:CleanupReport = temp_dir.path().cleanup_older_than(Duration::from_secs(60), CleanupOptions::new().filter(|file| file.extension() == Some("log")).keep_empty_dirs()).unwrap();
+		assert_eq!(report.removed_files, vec![temp_dir.path() + "/v1.2/old.log"]);
+		assert!((temp_dir.path() + "/old.txt").exists());
+	}
+
+	#[test]
+	fn test_cleanup_with_clock() {
+		let temp_dir:TempDirRef = create_cache();
+		let clock:Arc<ManualClock> = Arc::new(ManualClock::new(SystemTime::now() + Duration::from_secs(2 * 24 * 60 * 60)));
+		let report:CleanupReport = with_clock(clock, || temp_dir.path().cleanup_older_than(Duration::from_secs(24 * 60 * 60), CleanupOptions::new())).unwrap();
+		assert_eq!(report.removed_files.len(), 5);
+		assert!(!(temp_dir.path() + "/new.txt").exists());
+	}
+}
\ No newline at end of file
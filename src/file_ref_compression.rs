@@ -0,0 +1,129 @@
+use std::{ error::Error, fs::File, io::{ Read, Write, copy } };
+use crate::{ Cancelled, CompressionLevel, FileOperation, FileRef, NoProgress, Progress, ProgressReader, ProgressTracker };
+
+
+
+#[cfg(feature="gzip")]
+const GZIP_MAGIC:&[u8] = &[0x1f, 0x8b];
+#[cfg(feature="zstd")]
+const ZSTD_MAGIC:&[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Codec {
+	#[cfg(feature="gzip")]
+	Gzip(CompressionLevel),
+	#[cfg(feature="zstd")]
+	Zstd(CompressionLevel)
+}
+impl Codec {
+
+	/// Detect the codec of compressed data based on its first bytes.
+	fn detect(header:&[u8]) -> Option<Codec> {
+		#[cfg(feature="gzip")]
+		if header.starts_with(GZIP_MAGIC) {
+			return Some(Codec::Gzip(CompressionLevel::Default));
+		}
+		#[cfg(feature="zstd")]
+		if header.starts_with(ZSTD_MAGIC) {
+			return Some(Codec::Zstd(CompressionLevel::Default));
+		}
+		None
+	}
+}
+
+
+
+impl FileRef {
+
+	/* COMPRESSION METHODS */
+
+	/// Compress the file to the target location using the given codec. The file is streamed, so it never has to fit in memory. Returns the number of bytes written to the target.
+	pub fn compress_to(&self, target:&FileRef, codec:Codec) -> Result<u64, Box<dyn Error>> {
+		self.compress_to_with_progress(target, codec, &NoProgress)
+	}
+
+	/// Compress the file to the target location using the given codec, reporting the bytes of the file compressed so far to the progress. If the progress is cancelled, the incomplete target is removed and a `Cancelled` error is returned. Returns the number of bytes written to the target.
+	pub fn compress_to_with_progress(&self, target:&FileRef, codec:Codec, progress:&dyn Progress) -> Result<u64, Box<dyn Error>> {
+		if self.is_dir() {
+			return Err(self.error(FileOperation::Read, "Only able to compress files."));
+		} else if !self.exists() {
+			return Err(self.error(FileOperation::Read, "File does not exist."));
+		}
+		self.require_std_file_system(FileOperation::Read)?;
+		target.require_std_file_system(FileOperation::Write)?;
+
+		let source_file:File = File::open(self.os_path())?;
+		let mut tracker:ProgressTracker = ProgressTracker::new(progress, source_file.metadata()?.len());
+		tracker.start_file(self);
+		let mut source:ProgressReader<File> = tracker.reader(source_file);
+		target.guarantee_parent_dir()?;
+		let target_file:File = File::create(target.os_path())?;
+		let result:Result<(), Box<dyn Error>> = match codec {
+			#[cfg(feature="gzip")]
+			Codec::Gzip(level) => {
+				use flate2::{ Compression, write::GzEncoder };
+
+				let compression:Compression = match level {
+					CompressionLevel::Store => Compression::none(),
+					CompressionLevel::Fast => Compression::fast(),
+					CompressionLevel::Default => Compression::default(),
+					CompressionLevel::Best => Compression::best()
+				};
+				let mut encoder:GzEncoder<File> = GzEncoder::new(target_file, compression);
+				copy(&mut source, &mut encoder).and_then(|_| encoder.finish()?.flush()).map_err(|error| error.into())
+			},
+			#[cfg(feature="zstd")]
+			Codec::Zstd(level) => {
+				use zstd::Encoder;
+
+				// Zstd has no uncompressed mode, storing uses the fastest level instead.
+				let level:i32 = match level {
+					CompressionLevel::Store | CompressionLevel::Fast => 1,
+					CompressionLevel::Default => zstd::DEFAULT_COMPRESSION_LEVEL,
+					CompressionLevel::Best => 19
+				};
+				let mut encoder:Encoder<File> = Encoder::new(target_file, level)?;
+				copy(&mut source, &mut encoder).and_then(|_| encoder.finish()?.flush()).map_err(|error| error.into())
+			}
+		};
+		if let Err(error) = result {
+			if progress.is_cancelled() {
+				target.delete()?;
+				return Err(Box::new(Cancelled::new(())));
+			}
+			return Err(error);
+		}
+		tracker.finish();
+		Ok(std::fs::metadata(target.os_path())?.len())
+	}
+
+	/// Decompress the file to the target location. The codec is detected from the contents of the file. The file is streamed, so it never has to fit in memory. Returns the number of decompressed bytes written.
+	pub fn decompress_to(&self, target:&FileRef) -> Result<u64, Box<dyn Error>> {
+		use std::io::{ BufRead, BufReader };
+
+		if self.is_dir() {
+			return Err(self.error(FileOperation::Read, "Only able to decompress files."));
+		} else if !self.exists() {
+			return Err(self.error(FileOperation::Read, "File does not exist."));
+		}
+		self.require_std_file_system(FileOperation::Read)?;
+		target.require_std_file_system(FileOperation::Write)?;
+
+		let mut source:BufReader<File> = BufReader::new(File::open(self.os_path())?);
+		let codec:Codec = match Codec::detect(source.fill_buf()?) {
+			Some(codec) => codec,
+			None => return Err(self.error(FileOperation::Read, "Compression format not recognized."))
+		};
+		let mut decoder:Box<dyn Read> = match codec {
+			#[cfg(feature="gzip")]
+			Codec::Gzip(_) => Box::new(flate2::bufread::GzDecoder::new(source)),
+			#[cfg(feature="zstd")]
+			Codec::Zstd(_) => Box::new(zstd::Decoder::with_buffer(source)?)
+		};
+		target.guarantee_parent_dir()?;
+		let mut target_file:File = File::create(target.os_path())?;
+		copy(&mut decoder, &mut target_file).map_err(|error| error.into())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Codec, CompressionLevel, FileRef, TempDirRef };
+
+
+
+	fn roundtrip(codec:Codec, compressed_name:&str) {
+		let temp_dir:TempDirRef = TempDirRef::new("compression_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/source.txt";
+		let compressed:FileRef = temp_dir.path() + "/" + compressed_name;
+		let restored:FileRef = temp_dir.path() + "/restored/source.txt";
+		let contents:String = "compressible contents ".repeat(1000);
+		source.write(&contents).unwrap();
+
+		let compressed_size:u64 = source.compress_to(&compressed, codec).unwrap();
+		assert!(compressed_size < contents.len() as u64);
+		assert_eq!(compressed.decompress_to(&restored).unwrap(), contents.len() as u64);
+		assert_eq!(restored.read().unwrap(), contents);
+	}
+
+	#[cfg(feature="gzip")]
+	#[test]
+	fn test_gzip_roundtrip() {
+		roundtrip(Codec::Gzip(CompressionLevel::Best), "source.txt.gz");
+	}
+
+	#[cfg(feature="zstd")]
+	#[test]
+	fn test_zstd_roundtrip() {
+		roundtrip(Codec::Zstd(CompressionLevel::Default), "source.txt.zst");
+	}
+
+	#[cfg(feature="zstd")]
+	#[test]
+	fn test_zstd_fast_roundtrip() {
+		roundtrip(Codec::Zstd(CompressionLevel::Fast), "source.txt.zst");
+	}
+
+	#[test]
+	fn test_decompress_unknown_format() {
+		let temp_dir:TempDirRef = TempDirRef::new("compression_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/source.txt";
+		source.write("not compressed").unwrap();
+		assert!(source.decompress_to(&(temp_dir.path() + "/restored.txt")).is_err());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,288 @@
+use std::{ error::Error, fs::{ File, FileTimes, Metadata }, io::{ Read, Write }, sync::Arc };
+use crate::{ Cancelled, DirRef, FileKind, FileMetadata, FileOperation, FileRef, FileRefError, FileScanner, FileSystem, NoProgress, Progress, ProgressTracker, SEPARATOR };
+
+
+
+const COPY_CHUNK_SIZE:usize = 64 * 1024;
+
+
+
+/// What to do when a file being copied already exists at the target.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverwritePolicy {
+	#[default]
+	Replace,
+	ReplaceIfNewer,
+	Skip,
+	Fail
+}
+
+
+
+#[derive(Clone, Debug, Default)]
+pub struct CopyOptions {
+	overwrite:OverwritePolicy,
+	preserve_metadata:bool,
+	preserve_ownership:bool
+}
+impl CopyOptions {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create new default copy options. Existing files at the target are replaced and only the contents of files are copied.
+	pub fn new() -> CopyOptions {
+		CopyOptions::default()
+	}
+
+	/// Return self with a policy for files that already exist at the target.
+	pub fn overwrite(mut self, policy:OverwritePolicy) -> Self {
+		self.overwrite = policy;
+		self
+	}
+
+	/// Return self with a setting to give copies the modification time, access time and permissions of the original.
+	pub fn preserve_metadata(mut self) -> Self {
+		self.preserve_metadata = true;
+		self
+	}
+
+	/// Return self with a setting to give copies the owner and group of the original. Only supported on Unix, and usually requires elevated privileges.
+	pub fn preserve_ownership(mut self) -> Self {
+		self.preserve_ownership = true;
+		self
+	}
+}
+
+
+
+impl FileRef {
+
+	/* OPTIONS COPY METHODS */
+
+	/// Copy the file to another location using the options, creating the parent dir of the target if needed. Metadata is only preserved between files on the filesystem of the OS. Returns the number of bytes copied, which is zero if the existing target was kept.
+	pub fn copy_to_with_options(&self, target:&FileRef, options:CopyOptions) -> Result<u64, Box<dyn Error>> {
+		self.with_operation_hooks(FileOperation::Copy, Some(target), || {
+			if self.is_dir_fs() {
+				return Err(self.error(FileOperation::Copy, "Only able to copy files, use copy_dir_to for dirs."));
+			} else if !self.exists() {
+				return Err(self.error(FileOperation::Copy, "File does not exist."));
+			}
+			target.guarantee_parent_dir()?;
+			copy_file_with_options(self, target, &options)
+		})
+	}
+
+
+
+	/* DIR COPY METHODS */
+
+	/// Copy this dir and all of its contents to the target dir, creating it if needed. Files already in the target are handled according to the overwrite policy of the options, other files in the target are kept. Metadata is preserved for files and dirs if enabled in the options. Uses the filesystem to tell files and dirs apart. Symlinks are copied as links instead of being followed. Returns the number of bytes copied.
+	pub fn copy_dir_to(&self, target:&FileRef, options:CopyOptions) -> Result<u64, Box<dyn Error>> {
+		self.copy_dir_to_with_progress(target, options, &NoProgress)
+	}
+
+	/// Copy this dir and all of its contents to the target dir, reporting the bytes of the source files processed so far to the progress. Skipped files count as processed. If the progress is cancelled, a `Cancelled` error with the number of bytes copied so far is returned.
+	pub fn copy_dir_to_with_progress(&self, target:&FileRef, options:CopyOptions, progress:&dyn Progress) -> Result<u64, Box<dyn Error>> {
+		self.with_operation_hooks(FileOperation::Copy, Some(target), || {
+			if !self.is_dir_fs() {
+				return Err(self.error(FileOperation::Copy, if self.exists() { "Only able to copy dirs, use copy_to for files." } else { "Dir does not exist." }));
+			}
+			let source_dir:FileRef = self.clone().absolute().normalize();
+			let target_dir:FileRef = target.clone().absolute().normalize();
+			if target_dir == source_dir || target_dir.starts_with(&(source_dir.path().to_owned() + SEPARATOR)) {
+				return Err(Box::new(FileRefError::new(FileOperation::Copy, self, "Unable to copy a dir into itself.").with_target(target)));
+			}
+
+			// List all entries before copying, so the total size is known.
+			let mut entries:Vec<CopyEntry> = Vec::new();
+			list_copy_entries(&source_dir, &target_dir, &mut entries)?;
+			let bytes_total:u64 = entries.iter().map(|entry| if let CopyEntry::File(_, _, size) = entry { *size } else { 0 }).sum();
+			let mut tracker:ProgressTracker = ProgressTracker::new(progress, bytes_total);
+			let mut bytes_copied:u64 = 0;
+			for entry in &entries {
+				if tracker.is_cancelled() {
+					return Err(Box::new(Cancelled::new(bytes_copied)));
+				}
+				match entry {
+					CopyEntry::Dir(_, target_entry) => {
+						DirRef::create(target_entry)?;
+					},
+					CopyEntry::File(source_entry, target_entry, size) => {
+						tracker.start_file(source_entry);
+						bytes_copied += copy_file_with_options(source_entry, target_entry, &options)?;
+						tracker.advance(*size);
+					},
+					CopyEntry::Symlink(source_entry, target_entry) => copy_symlink(source_entry, target_entry, &options)?
+				}
+			}
+
+			// Preserve the metadata of dirs last, as copying their contents modifies it.
+			for entry in entries.iter().rev() {
+				if let CopyEntry::Dir(source_entry, target_entry) = entry {
+					copy_metadata(source_entry, target_entry, &options)?;
+				}
+			}
+			tracker.finish();
+			Ok(bytes_copied)
+		})
+	}
+
+
+
+	/* PROGRESS COPY METHODS */
+
+	/// Copy the file to another location in chunks, calling the callback with the bytes copied so far and the total size after every chunk, starting at zero. Creates the parent dir of the target if needed. Returns the number of bytes copied.
+	pub fn copy_to_with_progress<T>(&self, target:&FileRef, mut callback:T) -> Result<u64, Box<dyn Error>> where T:FnMut(u64, u64) {
+		self.with_operation_hooks(FileOperation::Copy, Some(target), || self.traced("copy", Some(target), |bytes:&u64| Some(*bytes), || {
+			if self.is_dir_fs() {
+				return Err(self.error(FileOperation::Copy, "Only able to copy files, use copy_dir_to for dirs."));
+			} else if !self.exists() {
+				return Err(self.error(FileOperation::Copy, "File does not exist."));
+			}
+			let copy_error = |error:Box<dyn Error>| -> Box<dyn Error> { Box::new(FileRefError::new(FileOperation::Copy, self, error).with_target(target)) };
+			let source_file_system:Arc<dyn FileSystem> = self.file_system();
+			let target_file_system:Arc<dyn FileSystem> = target.file_system();
+			let total:u64 = source_file_system.size(self.path()).map_err(copy_error)?;
+			target.guarantee_parent_dir()?;
+			callback(0, total);
+
+			// Stream between files of the OS.
+			let mut copied:u64 = 0;
+			if self.mounted_file_system().is_none() && target.mounted_file_system().is_none() {
+				let mut source_file:File = File::open(self.os_path()).map_err(|error| copy_error(error.into()))?;
+				let mut target_file:File = File::create(target.os_path()).map_err(|error| copy_error(error.into()))?;
+				let mut buffer:Vec<u8> = vec![0; COPY_CHUNK_SIZE];
+				loop {
+					let length:usize = source_file.read(&mut buffer).map_err(|error| copy_error(error.into()))?;
+					if length == 0 {
+						return Ok(copied);
+					}
+					target_file.write_all(&buffer[..length]).map_err(|error| copy_error(error.into()))?;
+					copied += length as u64;
+					callback(copied, total.max(copied));
+				}
+			}
+
+			// Copy in ranges between other filesystems.
+			if !target_file_system.exists(target.path()) {
+				target_file_system.create(target.path(), false).map_err(copy_error)?;
+			}
+			target_file_system.write_at(target.path(), None, &[]).map_err(copy_error)?;
+			while copied < total {
+				let end:u64 = (copied + COPY_CHUNK_SIZE as u64).min(total);
+				let chunk:Vec<u8> = source_file_system.read_range(self.path(), copied, end).map_err(copy_error)?;
+				target_file_system.append(target.path(), &chunk).map_err(copy_error)?;
+				copied = end;
+				callback(copied, total);
+			}
+			Ok(copied)
+		}))
+	}
+}
+
+
+
+/// An entry of a dir copy, with its source and target path.
+enum CopyEntry {
+	Dir(FileRef, FileRef),
+	File(FileRef, FileRef, u64),
+	Symlink(FileRef, FileRef)
+}
+
+
+
+/// List the dir and all of its contents as entries to copy to the target dir, parents before their contents. Uses the filesystem to tell entries apart, and does not recurse into symlinks, so links to parent dirs do not cause loops.
+fn list_copy_entries(source_dir:&FileRef, target_dir:&FileRef, entries:&mut Vec<CopyEntry>) -> Result<(), Box<dyn Error>> {
+	entries.push(CopyEntry::Dir(source_dir.clone(), target_dir.clone()));
+	for source_entry in FileScanner::new(source_dir).include_files().include_dirs().collect::<Vec<FileRef>>() {
+		let target_entry:FileRef = target_dir.join(source_entry.name())?;
+		let metadata:FileMetadata = source_entry.symlink_metadata()?;
+		match metadata.kind {
+			FileKind::Symlink => entries.push(CopyEntry::Symlink(source_entry, target_entry)),
+			FileKind::Dir => list_copy_entries(&source_entry, &target_entry, entries)?,
+			_ => entries.push(CopyEntry::File(source_entry, target_entry, metadata.size))
+		}
+	}
+	Ok(())
+}
+
+/// Recreate a symlink at the target, pointing to the same path as the original. Existing targets are handled according to the overwrite policy.
+fn copy_symlink(source:&FileRef, target:&FileRef, options:&CopyOptions) -> Result<(), Box<dyn Error>> {
+	if target.exists() || target.is_symlink() {
+		match options.overwrite {
+			OverwritePolicy::Replace => target.delete()?,
+			OverwritePolicy::ReplaceIfNewer | OverwritePolicy::Skip => return Ok(()),
+			OverwritePolicy::Fail => return Err(Box::new(FileRefError::new(FileOperation::Copy, source, "Target file already exists.").with_target(target)))
+		}
+	}
+	target.symlink_to(&source.read_link()?)
+}
+
+/// Copy a file to a target with an existing parent dir, applying the overwrite policy and preserving metadata if enabled. Returns the number of bytes copied.
+fn copy_file_with_options(source:&FileRef, target:&FileRef, options:&CopyOptions) -> Result<u64, Box<dyn Error>> {
+	if target.exists() {
+		match options.overwrite {
+			OverwritePolicy::Replace => {},
+			OverwritePolicy::ReplaceIfNewer => if !source_is_newer(source, target) { return Ok(0); },
+			OverwritePolicy::Skip => return Ok(0),
+			OverwritePolicy::Fail => return Err(Box::new(FileRefError::new(FileOperation::Copy, source, "Target file already exists.").with_target(target)))
+		}
+	}
+	let bytes_copied:u64 = source.traced("copy", Some(target), |bytes:&u64| Some(*bytes), || copy_file_contents(source, target))?;
+	copy_metadata(source, target, options)?;
+	Ok(bytes_copied)
+}
+
+/// Give the target the timestamps, ownership and permissions of the source, as far as enabled in the options. Does nothing for files on mounted filesystems.
+fn copy_metadata(source:&FileRef, target:&FileRef, options:&CopyOptions) -> Result<(), Box<dyn Error>> {
+	if !(options.preserve_metadata || options.preserve_ownership) || source.mounted_file_system().is_some() || target.mounted_file_system().is_some() {
+		return Ok(());
+	}
+	let metadata_error = |error:Box<dyn Error>| -> Box<dyn Error> { Box::new(FileRefError::new(FileOperation::Copy, source, error).with_target(target)) };
+	let metadata:Metadata = std::fs::metadata(source.os_path()).map_err(|error| metadata_error(error.into()))?;
+
+	// Timestamps go first, as read-only permissions would prevent setting them.
+	if options.preserve_metadata {
+		let mut times:FileTimes = FileTimes::new();
+		if let Ok(modified) = metadata.modified() {
+			times = times.set_modified(modified);
+		}
+		if let Ok(accessed) = metadata.accessed() {
+			times = times.set_accessed(accessed);
+		}
+		target.set_times(times)?;
+	}
+	#[cfg(unix)]
+	if options.preserve_ownership {
+		use std::os::unix::fs::MetadataExt;
+		std::os::unix::fs::chown(target.os_path(), Some(metadata.uid()), Some(metadata.gid())).map_err(|error| metadata_error(error.into()))?;
+	}
+	if options.preserve_metadata {
+		std::fs::set_permissions(target.os_path(), metadata.permissions()).map_err(|error| metadata_error(error.into()))?;
+	}
+	Ok(())
+}
+
+/// Check if the source file was modified after the target file. Files without modification times are considered newer.
+fn source_is_newer(source:&FileRef, target:&FileRef) -> bool {
+	match (source.modified(), target.modified()) {
+		(Ok(source_modified), Ok(target_modified)) => source_modified > target_modified,
+		_ => true
+	}
+}
+
+/// Copy the contents of a file to the target, regardless of the extension of its name. Returns the number of bytes copied.
+pub(crate) fn copy_file_contents(source:&FileRef, target:&FileRef) -> Result<u64, Box<dyn Error>> {
+	let copy_error = |error:Box<dyn Error>| -> Box<dyn Error> { Box::new(FileRefError::new(FileOperation::Copy, source, error).with_target(target)) };
+	let source_file_system:Arc<dyn FileSystem> = source.file_system();
+	if source.shares_file_system(target) {
+		return source_file_system.copy(source.path(), target.path()).map_err(copy_error);
+	}
+	let target_file_system:Arc<dyn FileSystem> = target.file_system();
+	let data:Vec<u8> = source_file_system.read(source.path()).map_err(copy_error)?;
+	if !target_file_system.exists(target.path()) {
+		target_file_system.create(target.path(), false).map_err(copy_error)?;
+	}
+	target_file_system.write_at(target.path(), None, &data).map_err(copy_error)?;
+	Ok(data.len() as u64)
+}
\ No newline at end of file
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+	use std::{ error::Error, sync::{ Arc, Mutex }, time::SystemTime };
+	use crate::{ Cancelled, CancellableProgress, CancellationToken, CopyOptions, FileRef, MemoryFs, OverwritePolicy, ProgressUpdate, TempDirRef };
+
+
+
+	fn source_tree(temp_dir:&TempDirRef) -> FileRef {
+		let source:FileRef = temp_dir.path().join("source").unwrap();
+		std::fs::create_dir_all(source.join("v1.2/nested").unwrap().path()).unwrap();
+		std::fs::create_dir(source.join("sub").unwrap().path()).unwrap();
+		source.join("file.txt").unwrap().write("file").unwrap();
+		source.join("v1.2/nested/deep.txt").unwrap().write("deep").unwrap();
+		std::fs::write(source.join("Makefile").unwrap().path(), "all:").unwrap();
+		source
+	}
+
+	#[test]
+	fn test_copy_dir_to() {
+		let temp_dir:TempDirRef = TempDirRef::new("copy_dir_test_").unwrap();
+		let source:FileRef = source_tree(&temp_dir);
+		let target:FileRef = temp_dir.path().join("backup/source").unwrap();
+
+		assert_eq!(source.copy_dir_to(&target, CopyOptions::new()).unwrap(), 12);
+		assert_eq!(target.join("file.txt").unwrap().read().unwrap(), "file");
+		assert_eq!(target.join("v1.2/nested/deep.txt").unwrap().read().unwrap(), "deep");
+		assert_eq!(std::fs::read_to_string(target.join("Makefile").unwrap().path()).unwrap(), "all:");
+		assert!(source.join("file.txt").unwrap().exists());
+
+		assert!(source.join("file.txt").unwrap().copy_dir_to(&target, CopyOptions::new()).is_err());
+		assert!(temp_dir.path().join("missing").unwrap().copy_dir_to(&target, CopyOptions::new()).is_err());
+		assert!(source.copy_dir_to(&source.join("v1.2/copy").unwrap(), CopyOptions::new()).is_err());
+	}
+
+	#[test]
+	fn test_copy_dir_to_overwrite() {
+		let temp_dir:TempDirRef = TempDirRef::new("copy_dir_overwrite_test_").unwrap();
+		let source:FileRef = source_tree(&temp_dir);
+		let target:FileRef = temp_dir.path().join("target").unwrap();
+		target.join("file.txt").unwrap().write("changed").unwrap();
+		target.join("extra.txt").unwrap().write("extra").unwrap();
+
+		assert_eq!(source.copy_dir_to(&target, CopyOptions::new().overwrite(OverwritePolicy::Skip)).unwrap(), 8);
+		assert!(source.copy_dir_to(&target, CopyOptions::new().overwrite(OverwritePolicy::Fail)).is_err());
+		assert_eq!(target.join("file.txt").unwrap().read().unwrap(), "changed");
+
+		source.join("file.txt").unwrap().set_modified(SystemTime::UNIX_EPOCH).unwrap();
+		source.copy_dir_to(&target, CopyOptions::new().overwrite(OverwritePolicy::ReplaceIfNewer)).unwrap();
+		assert_eq!(target.join("file.txt").unwrap().read().unwrap(), "changed");
+
+		source.copy_dir_to(&target, CopyOptions::new()).unwrap();
+		assert_eq!(target.join("file.txt").unwrap().read().unwrap(), "file");
+		assert_eq!(target.join("extra.txt").unwrap().read().unwrap(), "extra");
+	}
+
+	#[test]
+	#[cfg(unix)]
+	fn test_copy_dir_to_symlinks() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let temp_dir:TempDirRef = TempDirRef::new("copy_dir_symlink_test_").unwrap();
+		let source:FileRef = source_tree(&temp_dir);
+		source.join("sub/up").unwrap().symlink_to(&FileRef::new("..")).unwrap();
+		source.join("link.txt").unwrap().symlink_to(&FileRef::new("file.txt")).unwrap();
+		std::fs::set_permissions(source.join("Makefile").unwrap().path(), std::fs::Permissions::from_mode(0o755)).unwrap();
+
+		let target:FileRef = temp_dir.path().join("target").unwrap();
+		assert_eq!(source.copy_dir_to(&target, CopyOptions::new()).unwrap(), 12);
+		assert!(target.join("sub/up").unwrap().is_symlink());
+		assert_eq!(target.join("sub/up").unwrap().read_link().unwrap(), FileRef::new(".."));
+		assert_eq!(target.join("link.txt").unwrap().read().unwrap(), "file");
+		assert_eq!(std::fs::metadata(target.join("Makefile").unwrap().path()).unwrap().permissions().mode() & 0o777, 0o755);
+
+		source.copy_dir_to(&target, CopyOptions::new()).unwrap();
+		assert!(target.join("link.txt").unwrap().is_symlink());
+	}
+
+	#[test]
+	fn test_copy_dir_to_with_progress() {
+		let temp_dir:TempDirRef = TempDirRef::new("copy_dir_progress_test_").unwrap();
+		let source:FileRef = source_tree(&temp_dir);
+
+		let updates:Arc<Mutex<Vec<ProgressUpdate>>> = Arc::new(Mutex::new(Vec::new()));
+		let recorded_updates:Arc<Mutex<Vec<ProgressUpdate>>> = updates.clone();
+		source.copy_dir_to_with_progress(&temp_dir.path().join("target").unwrap(), CopyOptions::new(), &move |update:&ProgressUpdate| recorded_updates.lock().unwrap().push(update.clone())).unwrap();
+		let updates:Vec<ProgressUpdate> = updates.lock().unwrap().clone();
+		assert_eq!(updates.first().map(|update| (update.bytes_done, update.bytes_total)), Some((0, 12)));
+		assert_eq!(updates.last().map(|update| (update.bytes_done, update.bytes_total)), Some((12, 12)));
+		assert!(updates.iter().any(|update| update.current_file.is_some()));
+
+		// Cancel after the first file was copied.
+		let token:CancellationToken = CancellationToken::new();
+		let callback_token:CancellationToken = token.clone();
+		let progress:CancellableProgress<_> = token.with_progress(move |update:&ProgressUpdate| if update.bytes_done > 0 { callback_token.cancel() });
+		let error:Box<dyn Error> = source.copy_dir_to_with_progress(&temp_dir.path().join("cancelled").unwrap(), CopyOptions::new(), &progress).unwrap_err();
+		assert!(*error.downcast_ref::<Cancelled<u64>>().unwrap().partial() > 0);
+		assert!(*error.downcast_ref::<Cancelled<u64>>().unwrap().partial() < 12);
+	}
+
+	#[test]
+	fn test_copy_dir_to_mounted() {
+		let temp_dir:TempDirRef = TempDirRef::new("copy_dir_mounted_test_").unwrap();
+		let source:FileRef = source_tree(&temp_dir);
+		let memory_fs:MemoryFs = MemoryFs::new();
+
+		source.copy_dir_to(&memory_fs.file_ref("copy"), CopyOptions::new()).unwrap();
+		assert_eq!(memory_fs.file_ref("copy/file.txt").read().unwrap(), "file");
+		assert_eq!(memory_fs.file_ref("copy/v1.2/nested/deep.txt").read().unwrap(), "deep");
+	}
+
+	#[test]
+	fn test_copy_to_with_progress() {
+		let temp_dir:TempDirRef = TempDirRef::new("copy_progress_test_").unwrap();
+		let source:FileRef = temp_dir.path().join("large.bin").unwrap();
+		source.write_bytes(&vec![7; 150 * 1024]).unwrap();
+
+		let mut updates:Vec<(u64, u64)> = Vec::new();
+		let target:FileRef = temp_dir.path().join("copies/large.bin").unwrap();
+		assert_eq!(source.copy_to_with_progress(&target, |copied, total| updates.push((copied, total))).unwrap(), 150 * 1024);
+		assert_eq!(target.read_bytes().unwrap(), vec![7; 150 * 1024]);
+		assert_eq!(updates.first(), Some(&(0, 150 * 1024)));
+		assert_eq!(updates.last(), Some(&(150 * 1024, 150 * 1024)));
+		assert!(updates.len() >= 4);
+		assert!(updates.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let mut updates:Vec<(u64, u64)> = Vec::new();
+		target.copy_to_with_progress(&memory_fs.file_ref("large.bin"), |copied, total| updates.push((copied, total))).unwrap();
+		assert_eq!(memory_fs.file_ref("large.bin").read_bytes().unwrap(), vec![7; 150 * 1024]);
+		assert_eq!(updates, vec![(0, 153600), (65536, 153600), (131072, 153600), (153600, 153600)]);
+
+		assert!(temp_dir.path().copy_to_with_progress(&target, |_, _| {}).is_err());
+		assert!(temp_dir.path().join("missing.bin").unwrap().copy_to_with_progress(&target, |_, _| {}).is_err());
+	}
+
+	#[test]
+	fn test_copy_to_with_options() {
+		let temp_dir:TempDirRef = TempDirRef::new("copy_options_test_").unwrap();
+		let source:FileRef = temp_dir.path().join("config.toml").unwrap();
+		source.write("key = 1").unwrap();
+		source.set_modified(SystemTime::UNIX_EPOCH).unwrap();
+		source.set_readonly(true).unwrap();
+
+		let plain:FileRef = temp_dir.path().join("plain/config.toml").unwrap();
+		assert_eq!(source.copy_to_with_options(&plain, CopyOptions::new()).unwrap(), 7);
+		assert!(plain.modified().unwrap() > SystemTime::UNIX_EPOCH);
+
+		let faithful:FileRef = temp_dir.path().join("faithful/config.toml").unwrap();
+		source.copy_to_with_options(&faithful, CopyOptions::new().preserve_metadata()).unwrap();
+		assert_eq!(faithful.read().unwrap(), "key = 1");
+		assert_eq!(faithful.modified().unwrap(), SystemTime::UNIX_EPOCH);
+		assert!(faithful.is_readonly().unwrap());
+		assert_eq!(source.copy_to_with_options(&faithful, CopyOptions::new().overwrite(OverwritePolicy::Skip)).unwrap(), 0);
+		assert!(source.copy_to_with_options(&faithful, CopyOptions::new().overwrite(OverwritePolicy::Fail)).is_err());
+		#[cfg(unix)]
+		source.copy_to_with_options(&temp_dir.path().join("owned/config.toml").unwrap(), CopyOptions::new().preserve_ownership()).unwrap();
+
+		let dir:FileRef = temp_dir.path().join("dir").unwrap();
+		dir.join("file.txt").unwrap().write("file").unwrap();
+		dir.join("file.txt").unwrap().set_modified(SystemTime::UNIX_EPOCH).unwrap();
+		dir.set_modified(SystemTime::UNIX_EPOCH).unwrap();
+		dir.copy_dir_to(&temp_dir.path().join("dir_copy").unwrap(), CopyOptions::new().preserve_metadata()).unwrap();
+		assert_eq!(temp_dir.path().join("dir_copy/file.txt").unwrap().modified().unwrap(), SystemTime::UNIX_EPOCH);
+		assert_eq!(temp_dir.path().join("dir_copy").unwrap().modified().unwrap(), SystemTime::UNIX_EPOCH);
+
+		for file in [&source, &plain, &faithful] {
+			file.set_readonly(false).unwrap();
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,132 @@
+use std::{ collections::BTreeMap, error::Error };
+use crate::{ DirEntries, FileOperation, FileRef, FileRefError, HashAlgorithm };
+
+
+
+const DEDUPE_HASH_ALGORITHM:HashAlgorithm = HashAlgorithm::Sha256;
+const DEDUPE_TEMP_EXTENSION:&str = ".dedupe_tmp";
+
+
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DedupeReport {
+	pub linked:Vec<FileRef>,
+	pub bytes_saved:u64
+}
+
+
+
+impl FileRef {
+
+	/* DUPLICATE DETECTION METHODS */
+
+	/// Find groups of files with identical contents in this dir, recursively. Files are first grouped by size, so only files of equal size are hashed. Only regular files are considered, found by their metadata instead of their extension. Each group is ordered by path and contains at least two files.
+	pub fn find_duplicates(&self) -> Result<Vec<Vec<FileRef>>, Box<dyn Error>> {
+		if !self.exists() {
+			return Err(self.error(FileOperation::Scan, "Dir does not exist."));
+		} else if !self.is_dir_fs() {
+			return Err(self.error(FileOperation::Scan, "Only able to find duplicates in dirs."));
+		}
+		self.require_std_file_system(FileOperation::Scan)?;
+
+		// Group by size.
+		let mut size_groups:BTreeMap<u64, Vec<FileRef>> = BTreeMap::new();
+		for (file, metadata) in DirEntries::collect(self)?.files.into_iter().filter(|(_, metadata)| metadata.is_file()) {
+			size_groups.entry(metadata.len()).or_default().push(file);
+		}
+
+		// Group files of equal size by hash.
+		let mut duplicates:Vec<Vec<FileRef>> = Vec::new();
+		for files in size_groups.into_values().filter(|files| files.len() > 1) {
+			let mut hash_groups:BTreeMap<String, Vec<FileRef>> = BTreeMap::new();
+			for file in files {
+				hash_groups.entry(file.hash(DEDUPE_HASH_ALGORITHM)?).or_default().push(file);
+			}
+			duplicates.extend(hash_groups.into_values().filter(|files| files.len() > 1));
+		}
+		for group in &mut duplicates {
+			group.sort();
+		}
+		duplicates.sort();
+		Ok(duplicates)
+	}
+
+
+
+	/* DEDUPLICATION METHODS */
+
+	/// Replace duplicate files in this dir with hardlinks to the first file of their group. Empty files and files that are already linked are left alone. Returns the replaced files and the amount of bytes saved.
+	pub fn dedupe_hardlink(&self) -> Result<DedupeReport, Box<dyn Error>> {
+		self.dedupe_hardlink_with(false)
+	}
+
+	/// Report the files `dedupe_hardlink` would replace and the bytes it would save, without modifying anything.
+	pub fn dedupe_hardlink_dry_run(&self) -> Result<DedupeReport, Box<dyn Error>> {
+		self.dedupe_hardlink_with(true)
+	}
+
+	/// Replace duplicate files with hardlinks, or only report them on a dry run.
+	fn dedupe_hardlink_with(&self, dry_run:bool) -> Result<DedupeReport, Box<dyn Error>> {
+		let mut report:DedupeReport = DedupeReport::default();
+		for group in self.find_duplicates()? {
+			let original:&FileRef = &group[0];
+			let size:u64 = std::fs::metadata(original.os_path())?.len();
+			if size == 0 {
+				continue;
+			}
+			for duplicate in &group[1..] {
+				if is_same_file(original, duplicate)? {
+					continue;
+				}
+
+				// Link to a temporary name first, so the duplicate is never missing if linking fails.
+				if !dry_run {
+					let temp_link:FileRef = duplicate.clone() + DEDUPE_TEMP_EXTENSION;
+					std::fs::hard_link(original.os_path(), temp_link.os_path()).map_err(|error| FileRefError::new(FileOperation::Link, duplicate, error).with_target(original))?;
+					std::fs::rename(temp_link.os_path(), duplicate.os_path())?;
+				}
+				report.linked.push(duplicate.clone());
+				report.bytes_saved += size;
+			}
+		}
+		Ok(report)
+	}
+}
+
+
+
+/// Check if both paths point to the same file on disk.
+#[cfg(unix)]
+fn is_same_file(left:&FileRef, right:&FileRef) -> Result<bool, Box<dyn Error>> {
+	use std::os::unix::fs::MetadataExt;
+
+	let left_metadata:std::fs::Metadata = std::fs::metadata(left.os_path())?;
+	let right_metadata:std::fs::Metadata = std::fs::metadata(right.os_path())?;
+	Ok(left_metadata.dev() == right_metadata.dev() && left_metadata.ino() == right_metadata.ino())
+}
+
+/// Check if both paths point to the same file on disk by comparing their volume serial numbers and file indices.
+#[cfg(windows)]
+fn is_same_file(left:&FileRef, right:&FileRef) -> Result<bool, Box<dyn Error>> {
+	Ok(file_id(left)? == file_id(right)?)
+}
+
+/// Get the volume serial number and file index of a file, which together identify it on disk.
+#[cfg(windows)]
+fn file_id(file:&FileRef) -> Result<(u32, u32, u32), Box<dyn Error>> {
+	use std::os::windows::io::AsRawHandle;
+	use windows_sys::Win32::{ Foundation::HANDLE, Storage::FileSystem::{ BY_HANDLE_FILE_INFORMATION, GetFileInformationByHandle } };
+
+	let handle:std::fs::File = std::fs::File::open(file.os_path()).map_err(|error| file.error(FileOperation::Read, error))?;
+	let mut information:BY_HANDLE_FILE_INFORMATION = unsafe { std::mem::zeroed() };
+	if unsafe { GetFileInformationByHandle(handle.as_raw_handle() as HANDLE, &mut information) } == 0 {
+		return Err(file.error(FileOperation::Read, std::io::Error::last_os_error()));
+	}
+	Ok((information.dwVolumeSerialNumber, information.nFileIndexHigh, information.nFileIndexLow))
+}
+
+/// Without a way to identify files on disk, already linked files can not be told apart from copies.
+#[cfg(not(any(unix, windows)))]
+fn is_same_file(left:&FileRef, _right:&FileRef) -> Result<bool, Box<dyn Error>> {
+	Err(left.error(FileOperation::Link, std::io::Error::new(std::io::ErrorKind::Unsupported, "Deduplicating files is not supported on this platform.")))
+}
\ No newline at end of file
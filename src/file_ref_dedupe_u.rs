@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ DedupeReport, FileRef, TempDirRef };
+
+
+
+	fn create_test_dir() -> TempDirRef {
+		let temp_dir:TempDirRef = TempDirRef::new("dedupe_test_").unwrap();
+		(temp_dir.path() + "/a.txt").write("duplicate contents").unwrap();
+		(temp_dir.path() + "/sub/b.txt").write("duplicate contents").unwrap();
+		(temp_dir.path() + "/sub/c.txt").write("duplicate contents").unwrap();
+		(temp_dir.path() + "/d.txt").write("unique contents!!!").unwrap();
+		(temp_dir.path() + "/e.txt").write("").unwrap();
+		(temp_dir.path() + "/f.txt").write("").unwrap();
+		temp_dir
+	}
+
+
+
+	#[test]
+	fn test_find_duplicates() {
+		let temp_dir:TempDirRef = create_test_dir();
+		let duplicates:Vec<Vec<FileRef>> = temp_dir.path().find_duplicates().unwrap();
+		let names:Vec<Vec<&str>> = duplicates.iter().map(|group| group.iter().map(|file| file.name()).collect()).collect();
+		assert_eq!(names.len(), 2);
+		assert!(names.contains(&vec!["a.txt", "b.txt", "c.txt"]));
+		assert!(names.contains(&vec!["e.txt", "f.txt"]));
+	}
+
+	#[test]
+	fn test_find_duplicates_extensionless_files_and_dotted_dirs() {
+		let temp_dir:TempDirRef = TempDirRef::new("dedupe_test_").unwrap();
+		for dir in ["conf.d", "sources.list.d"] {
+			std::fs::create_dir((temp_dir.path() + "/" + dir).os_path()).unwrap();
+		}
+		std::fs::write((temp_dir.path() + "/conf.d/LICENSE").os_path(), "license").unwrap();
+		std::fs::write((temp_dir.path() + "/sources.list.d/COPYING").os_path(), "license").unwrap();
+		let duplicates:Vec<Vec<FileRef>> = temp_dir.path().find_duplicates().unwrap();
+		let names:Vec<Vec<&str>> = duplicates.iter().map(|group| group.iter().map(|file| file.name()).collect()).collect();
+		assert_eq!(names, vec![vec!["LICENSE", "COPYING"]]);
+	}
+
+	#[test]
+	fn test_dedupe_hardlink_dry_run() {
+		let temp_dir:TempDirRef = create_test_dir();
+		let report:DedupeReport = temp_dir.path().dedupe_hardlink_dry_run().unwrap();
+		assert_eq!(report.linked.len(), 2);
+		assert_eq!(report.bytes_saved, 36);
+		assert_eq!(temp_dir.path().dedupe_hardlink_dry_run().unwrap(), report);
+	}
+
+	#[test]
+	fn test_dedupe_hardlink() {
+		let temp_dir:TempDirRef = create_test_dir();
+		let report:DedupeReport = temp_dir.path().dedupe_hardlink().unwrap();
+		assert_eq!(report.linked.len(), 2);
+		assert_eq!(report.bytes_saved, 36);
+		assert_eq!((temp_dir.path() + "/sub/c.txt").read().unwrap(), "duplicate contents");
+
+		// Linked files share their contents and are not linked again.
+		(temp_dir.path() + "/a.txt").write_bytes_to_range(0, b"D").unwrap();
+		assert_eq!((temp_dir.path() + "/sub/b.txt").read().unwrap(), "Duplicate contents");
+		assert_eq!(temp_dir.path().dedupe_hardlink().unwrap(), DedupeReport::default());
+	}
+}
\ No newline at end of file
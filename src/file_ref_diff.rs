@@ -0,0 +1,107 @@
+use std::{ collections::BTreeSet, error::Error };
+use crate::{ DirEntries, FileOperation, FileRef, SEPARATOR };
+
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FileComparison {
+	#[default]
+	SizeAndModified,
+	Contents
+}
+
+
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DirDiff {
+	pub only_in_left:Vec<FileRef>,
+	pub only_in_right:Vec<FileRef>,
+	pub differing:Vec<FileRef>,
+	pub identical:usize
+}
+impl DirDiff {
+
+	/// Check if both dirs contain the same files.
+	pub fn is_identical(&self) -> bool {
+		self.only_in_left.is_empty() && self.only_in_right.is_empty() && self.differing.is_empty()
+	}
+}
+
+
+
+impl FileRef {
+
+	/* DIFF METHODS */
+
+	/// Compare the files in this dir to the files in the other dir by their contents. All paths in the report are relative to the compared dirs.
+	pub fn diff_dir(&self, other:&FileRef) -> Result<DirDiff, Box<dyn Error>> {
+		self.diff_dir_with(other, FileComparison::Contents)
+	}
+
+	/// Compare the files in this dir to the files in the other dir using the given comparison. Entries are told apart by their metadata, so extensionless files are compared and dirs with a dot in their name are recursed into. All paths in the report are relative to the compared dirs.
+	pub fn diff_dir_with(&self, other:&FileRef, comparison:FileComparison) -> Result<DirDiff, Box<dyn Error>> {
+		for dir in [self, other] {
+			if !dir.exists() {
+				return Err(dir.error(FileOperation::Scan, "Dir does not exist."));
+			} else if !dir.is_dir_fs() {
+				return Err(dir.error(FileOperation::Scan, "Only able to diff dirs."));
+			}
+			dir.require_std_file_system(FileOperation::Scan)?;
+		}
+
+		let left_dir:FileRef = self.clone().absolute().trim_end_matches(SEPARATOR);
+		let right_dir:FileRef = other.clone().absolute().trim_end_matches(SEPARATOR);
+		let left_files:BTreeSet<FileRef> = DirEntries::collect(&left_dir)?.files.iter().map(|(file, _)| file.relative_to_dir(&left_dir)).collect();
+		let right_files:BTreeSet<FileRef> = DirEntries::collect(&right_dir)?.files.iter().map(|(file, _)| file.relative_to_dir(&right_dir)).collect();
+
+		let mut diff:DirDiff = DirDiff::default();
+		for relative_path in left_files.union(&right_files) {
+			match (left_files.contains(relative_path), right_files.contains(relative_path)) {
+				(true, false) => diff.only_in_left.push(relative_path.clone()),
+				(false, true) => diff.only_in_right.push(relative_path.clone()),
+				_ => {
+					let left_file:FileRef = left_dir.clone() + SEPARATOR + relative_path.path();
+					let right_file:FileRef = right_dir.clone() + SEPARATOR + relative_path.path();
+					if files_differ(&left_file, &right_file, comparison)? {
+						diff.differing.push(relative_path.clone());
+					} else {
+						diff.identical += 1;
+					}
+				}
+			}
+		}
+		Ok(diff)
+	}
+}
+
+
+
+/// Check if two files differ according to the given comparison.
+pub(crate) fn files_differ(left:&FileRef, right:&FileRef, comparison:FileComparison) -> Result<bool, Box<dyn Error>> {
+	use std::{ fs::{ File, Metadata }, io::{ BufReader, Read } };
+
+	let left_metadata:Metadata = std::fs::metadata(left.os_path())?;
+	let right_metadata:Metadata = std::fs::metadata(right.os_path())?;
+	if left_metadata.len() != right_metadata.len() {
+		return Ok(true);
+	}
+	match comparison {
+		FileComparison::SizeAndModified => Ok(left_metadata.modified()? != right_metadata.modified()?),
+		FileComparison::Contents => {
+			let mut left_reader:BufReader<File> = BufReader::new(File::open(left.os_path())?);
+			let mut right_reader:BufReader<File> = BufReader::new(File::open(right.os_path())?);
+			let mut left_buffer:[u8; 8192] = [0; 8192];
+			let mut right_buffer:[u8; 8192] = [0; 8192];
+			loop {
+				let read_size:usize = left_reader.read(&mut left_buffer)?;
+				if read_size == 0 {
+					return Ok(false);
+				}
+				right_reader.read_exact(&mut right_buffer[..read_size])?;
+				if left_buffer[..read_size] != right_buffer[..read_size] {
+					return Ok(true);
+				}
+			}
+		}
+	}
+}
\ No newline at end of file
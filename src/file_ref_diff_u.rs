@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ DirDiff, FileComparison, FileRef, TempDirRef };
+
+
+
+	#[test]
+	fn test_diff_dir() {
+		let temp_dir:TempDirRef = TempDirRef::new("diff_test_").unwrap();
+		let left_dir:FileRef = temp_dir.path() + "/left";
+		let right_dir:FileRef = temp_dir.path() + "/right";
+		(left_dir.clone() + "/same.txt").write("same").unwrap();
+		(right_dir.clone() + "/same.txt").write("same").unwrap();
+		(left_dir.clone() + "/sub_dir/changed.txt").write("left").unwrap();
+		(right_dir.clone() + "/sub_dir/changed.txt").write("rght").unwrap();
+		(left_dir.clone() + "/left_only.txt").write("left").unwrap();
+		(right_dir.clone() + "/right_only.txt").write("right").unwrap();
+
+		let diff:DirDiff = left_dir.diff_dir(&right_dir).unwrap();
+		assert_eq!(diff.only_in_left, vec![FileRef::new("left_only.txt")]);
+		assert_eq!(diff.only_in_right, vec![FileRef::new("right_only.txt")]);
+		assert_eq!(diff.differing, vec![FileRef::new("sub_dir/changed.txt")]);
+		assert_eq!(diff.identical, 1);
+		assert!(!diff.is_identical());
+	}
+
+	#[test]
+	fn test_diff_dir_identical() {
+		let temp_dir:TempDirRef = TempDirRef::new("diff_test_").unwrap();
+		let left_dir:FileRef = temp_dir.path() + "/left";
+		let right_dir:FileRef = temp_dir.path() + "/right";
+		(left_dir.clone() + "/file.txt").write("contents").unwrap();
+		left_dir.sync_to(&right_dir, Default::default()).unwrap();
+
+		assert!(left_dir.diff_dir(&right_dir).unwrap().is_identical());
+		assert!(left_dir.diff_dir_with(&right_dir, FileComparison::SizeAndModified).unwrap().is_identical());
+	}
+
+	#[test]
+	fn test_diff_dir_extensionless_files_and_dotted_dirs() {
+		let temp_dir:TempDirRef = TempDirRef::new("diff_test_").unwrap();
+		let left_dir:FileRef = temp_dir.path() + "/left";
+		let right_dir:FileRef = temp_dir.path() + "/right";
+		(left_dir.clone() + "/a.txt").write("a").unwrap();
+		std::fs::create_dir((left_dir.clone() + "/lib.v2").os_path()).unwrap();
+		std::fs::create_dir_all(right_dir.os_path()).unwrap();
+		std::fs::write((left_dir.clone() + "/Makefile").os_path(), "all:").unwrap();
+		std::fs::write((left_dir.clone() + "/lib.v2/LICENSE").os_path(), "MIT").unwrap();
+
+		let diff:DirDiff = left_dir.diff_dir(&right_dir).unwrap();
+		assert_eq!(diff.only_in_left, vec![FileRef::new("Makefile"), FileRef::new("a.txt"), FileRef::new("lib.v2/LICENSE")]);
+		assert!(left_dir.diff_dir(&(left_dir.clone() + "/Makefile")).is_err());
+	}
+
+	#[test]
+	fn test_diff_dir_missing() {
+		let temp_dir:TempDirRef = TempDirRef::new("diff_test_").unwrap();
+		assert!(temp_dir.path().diff_dir(&(temp_dir.path() + "/missing")).is_err());
+	}
+}
\ No newline at end of file
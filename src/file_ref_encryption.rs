@@ -0,0 +1,47 @@
+use std::error::Error;
+use chacha20poly1305::{ KeyInit, XChaCha20Poly1305, XNonce, aead::{ Aead, AeadCore, OsRng, Payload } };
+use crate::{ FileOperation, FileRef };
+
+
+
+const ENCRYPTION_MAGIC:&[u8] = b"FREC";
+const ENCRYPTION_VERSION:u8 = 1;
+const NONCE_SIZE:usize = 24;
+const HEADER_SIZE:usize = ENCRYPTION_MAGIC.len() + 1 + NONCE_SIZE;
+
+
+
+impl FileRef {
+
+	/* ENCRYPTION METHODS */
+
+	/// Encrypt the data with XChaCha20-Poly1305 using the 256-bit key and atomically replace the contents of the file with it. A random nonce is generated for every write and stored in a versioned header.
+	pub fn write_encrypted(&self, data:&[u8], key:&[u8; 32]) -> Result<(), Box<dyn Error>> {
+		let cipher:XChaCha20Poly1305 = XChaCha20Poly1305::new(key.into());
+		let nonce:XNonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+		let mut contents:Vec<u8> = Vec::with_capacity(HEADER_SIZE + data.len() + 16);
+		contents.extend_from_slice(ENCRYPTION_MAGIC);
+		contents.push(ENCRYPTION_VERSION);
+		contents.extend_from_slice(&nonce);
+
+		// The header is authenticated along with the data, so it can not be changed without detection.
+		let encrypted:Vec<u8> = cipher.encrypt(&nonce, Payload { msg: data, aad: &contents }).map_err(|_| self.error(FileOperation::Write, "Encryption failed."))?;
+		contents.extend_from_slice(&encrypted);
+		self.write_atomic(&contents)
+	}
+
+	/// Read and decrypt a file written by `write_encrypted`. Fails if the key is wrong or the file was modified.
+	pub fn read_encrypted(&self, key:&[u8; 32]) -> Result<Vec<u8>, Box<dyn Error>> {
+		let contents:Vec<u8> = self.read_bytes()?;
+		if contents.len() < HEADER_SIZE || !contents.starts_with(ENCRYPTION_MAGIC) {
+			return Err(self.error(FileOperation::Read, "File is not encrypted."));
+		} else if contents[ENCRYPTION_MAGIC.len()] != ENCRYPTION_VERSION {
+			return Err(self.error(FileOperation::Read, format!("Unsupported encryption version {}.", contents[ENCRYPTION_MAGIC.len()])));
+		}
+
+		let (header, encrypted) = contents.split_at(HEADER_SIZE);
+		let cipher:XChaCha20Poly1305 = XChaCha20Poly1305::new(key.into());
+		let nonce:&XNonce = XNonce::from_slice(&header[HEADER_SIZE - NONCE_SIZE..]);
+		cipher.decrypt(nonce, Payload { msg: encrypted, aad: header }).map_err(|_| self.error(FileOperation::Read, "Decryption failed. The key is wrong or the file was modified."))
+	}
+}
\ No newline at end of file
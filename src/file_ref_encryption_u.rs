@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, TempDirRef };
+
+
+
+	const KEY:[u8; 32] = [7; 32];
+
+
+
+	#[test]
+	fn test_encryption_round_trip() {
+		let temp_dir:TempDirRef = TempDirRef::new("encryption_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/secret.bin";
+
+		file.write_encrypted(b"api token", &KEY).unwrap();
+		assert!(!file.read_bytes().unwrap().windows(9).any(|window| window == b"api token"));
+		assert_eq!(file.read_encrypted(&KEY).unwrap(), b"api token");
+
+		// Every write uses a new nonce.
+		let first:Vec<u8> = file.read_bytes().unwrap();
+		file.write_encrypted(b"api token", &KEY).unwrap();
+		assert_ne!(file.read_bytes().unwrap(), first);
+	}
+
+	#[test]
+	fn test_encryption_wrong_key_or_tampered() {
+		let temp_dir:TempDirRef = TempDirRef::new("encryption_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/secret.bin";
+		file.write_encrypted(b"api token", &KEY).unwrap();
+		assert!(file.read_encrypted(&[8; 32]).is_err());
+
+		let mut contents:Vec<u8> = file.read_bytes().unwrap();
+		*contents.last_mut().unwrap() ^= 1;
+		file.write_bytes(&contents).unwrap();
+		assert!(file.read_encrypted(&KEY).is_err());
+	}
+
+	#[test]
+	fn test_encryption_rejects_plain_file() {
+		let temp_dir:TempDirRef = TempDirRef::new("encryption_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/plain.txt";
+		file.write("not encrypted at all, just text").unwrap();
+		assert!(file.read_encrypted(&KEY).is_err());
+	}
+}
\ No newline at end of file
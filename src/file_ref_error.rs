@@ -0,0 +1,133 @@
+use std::{ error::Error, fmt::{ self, Display, Formatter }, io::{ Error as IoError, ErrorKind } };
+use crate::FileRef;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileOperation {
+	Read,
+	Write,
+	Append,
+	Create,
+	Copy,
+	Move,
+	Link,
+	Symlink,
+	Delete,
+	Lock,
+	Scan,
+	Resolve
+}
+impl Display for FileOperation {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", match self {
+			FileOperation::Read => "read",
+			FileOperation::Write => "write to",
+			FileOperation::Append => "append to",
+			FileOperation::Create => "create",
+			FileOperation::Copy => "copy",
+			FileOperation::Move => "move",
+			FileOperation::Link => "hardlink",
+			FileOperation::Symlink => "symlink",
+			FileOperation::Delete => "delete",
+			FileOperation::Lock => "lock",
+			FileOperation::Scan => "scan",
+			FileOperation::Resolve => "resolve"
+		})
+	}
+}
+
+
+
+#[derive(Debug)]
+pub struct FileRefError {
+	operation:FileOperation,
+	path:FileRef,
+	target:Option<FileRef>,
+	source:Box<dyn Error>
+}
+impl FileRefError {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new error for an operation on the given path, wrapping the underlying error or reason.
+	pub fn new<T>(operation:FileOperation, path:&FileRef, source:T) -> FileRefError where T:Into<Box<dyn Error>> {
+		FileRefError { operation, path: path.clone(), target: None, source: source.into() }
+	}
+
+	/// Return self with the target of a copy or link operation.
+	pub fn with_target(mut self, target:&FileRef) -> Self {
+		self.target = Some(target.clone());
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the operation that failed.
+	pub fn operation(&self) -> FileOperation {
+		self.operation
+	}
+
+	/// Get the path the operation was executed on.
+	pub fn path(&self) -> &FileRef {
+		&self.path
+	}
+
+	/// Get the target of the operation, for copies and links.
+	pub fn target(&self) -> Option<&FileRef> {
+		self.target.as_ref()
+	}
+
+	/// Get the kind of the underlying IO error, if the error was caused by one.
+	pub fn io_error_kind(&self) -> Option<ErrorKind> {
+		self.source.downcast_ref::<IoError>().map(|error| error.kind())
+	}
+
+	/// Check if the operation failed because the file does not exist.
+	pub fn is_not_found(&self) -> bool {
+		self.io_error_kind() == Some(ErrorKind::NotFound)
+	}
+}
+impl Display for FileRefError {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(f, "Could not {} {} \"{}\"", self.operation, if self.path.is_dir() { "dir" } else { "file" }, self.path.path())?;
+		if let Some(target) = &self.target {
+			write!(f, " to \"{}\"", target.path())?;
+		}
+		write!(f, ". {}", self.source)
+	}
+}
+impl Error for FileRefError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		Some(self.source.as_ref())
+	}
+}
+
+
+
+impl FileRef {
+
+	/* ERROR METHODS */
+
+	/// Create an error for an operation on this file. Errors that already carry the context of a file operation are returned as they are.
+	pub(crate) fn error<T>(&self, operation:FileOperation, source:T) -> Box<dyn Error> where T:Into<Box<dyn Error>> {
+		let source:Box<dyn Error> = source.into();
+		if source.is::<FileRefError>() {
+			source
+		} else {
+			Box::new(FileRefError::new(operation, self, source))
+		}
+	}
+}
+
+
+
+/// Replace the result with a default value if it failed because a file does not exist. Other errors are kept.
+pub(crate) fn default_if_not_found<T, U:FnOnce() -> T>(result:Result<T, Box<dyn Error>>, default:U) -> Result<T, Box<dyn Error>> {
+	match result {
+		Err(error) if error.downcast_ref::<FileRefError>().is_some_and(|error| error.is_not_found()) => Ok(default()),
+		result => result
+	}
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+	use std::{ error::Error, io::ErrorKind };
+	use unit_test_support::TempFile;
+	use crate::{ FileOperation, FileRef, FileRefError };
+
+
+
+	#[test]
+	fn test_error_message() {
+		let error:FileRefError = FileRefError::new(FileOperation::Write, &FileRef::new("dir/file.txt"), "File does not exist.");
+		assert_eq!(error.to_string(), "Could not write to file \"dir/file.txt\". File does not exist.");
+
+		let error:FileRefError = FileRefError::new(FileOperation::Copy, &FileRef::new("a.txt"), "Disk full.").with_target(&FileRef::new("b.txt"));
+		assert_eq!(error.to_string(), "Could not copy file \"a.txt\" to \"b.txt\". Disk full.");
+	}
+
+	#[test]
+	fn test_error_context_on_failed_read() {
+		let temp_file:TempFile = TempFile::new(Some("txt"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+
+		let error:Box<dyn Error> = temp_file_ref.read().unwrap_err();
+		let error:&FileRefError = error.downcast_ref::<FileRefError>().unwrap();
+		assert_eq!(error.operation(), FileOperation::Read);
+		assert_eq!(error.path(), &temp_file_ref);
+		assert_eq!(error.io_error_kind(), Some(ErrorKind::NotFound));
+		assert!(error.to_string().ends_with("File does not exist."));
+	}
+
+	#[test]
+	fn test_error_on_missing_file_classified() {
+		let temp_file:TempFile = TempFile::new(Some("txt"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+
+		for error in [temp_file_ref.append_bytes(b"data").unwrap_err(), temp_file_ref.write_bytes_to_range(0, b"data").unwrap_err(), temp_file_ref.read_range(0, 1).unwrap_err()] {
+			assert_eq!(error.downcast_ref::<FileRefError>().unwrap().io_error_kind(), Some(ErrorKind::NotFound));
+		}
+		assert!(!temp_file_ref.exists());
+	}
+
+	#[test]
+	fn test_error_wraps_io_error() {
+		let temp_file:TempFile = TempFile::new(Some("txt"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+
+		let error:Box<dyn Error> = temp_file_ref.delete().unwrap_err();
+		let error:&FileRefError = error.downcast_ref::<FileRefError>().unwrap();
+		assert_eq!(error.operation(), FileOperation::Delete);
+		assert_eq!(error.io_error_kind(), Some(ErrorKind::NotFound));
+		assert!(error.source().is_some());
+	}
+
+	#[test]
+	fn test_error_not_wrapped_twice() {
+		let temp_file:TempFile = TempFile::new(Some("txt"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+		temp_file_ref.create().unwrap();
+
+		let error:Box<dyn Error> = temp_file_ref.create().unwrap_err();
+		assert_eq!(error.to_string(), format!("Could not create file \"{}\". File already exists.", temp_file_ref.path()));
+		assert!(error.downcast_ref::<FileRefError>().unwrap().source().unwrap().downcast_ref::<FileRefError>().is_none());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use std::error::Error;
+use crate::{ FileOperation, FileRef, FileRefError, SEPARATOR };
+
+
+
+const HIDDEN_NAME_PREFIX:char = '.';
+
+
+
+impl FileRef {
+
+	/* HIDDEN FILE METHODS */
+
+	/// Check if the file or dir is hidden. On Windows this checks the hidden attribute, elsewhere and on mounted filesystems it checks for a name starting with a dot.
+	pub fn is_hidden(&self) -> bool {
+		#[cfg(windows)]
+		if self.mounted_file_system().is_none() {
+			use std::os::windows::fs::MetadataExt;
+			use windows_sys::Win32::Storage::FileSystem::FILE_ATTRIBUTE_HIDDEN;
+
+			return std::fs::metadata(self.os_path()).is_ok_and(|metadata| metadata.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0);
+		}
+		self.name().starts_with(HIDDEN_NAME_PREFIX)
+	}
+
+	/// Hide or unhide the file or dir. On Windows this sets the hidden attribute, elsewhere and on mounted filesystems the file is renamed to or from a name starting with a dot. Returns the file at its new location, which is only different from self after a rename.
+	pub fn set_hidden(&self, hidden:bool) -> Result<FileRef, Box<dyn Error>> {
+		if !self.exists() {
+			return Err(self.error(FileOperation::Write, "File does not exist."));
+		}
+		if self.is_hidden() == hidden {
+			return Ok(self.clone());
+		}
+
+		#[cfg(windows)]
+		if self.mounted_file_system().is_none() {
+			set_hidden_attribute(self, hidden).map_err(|error| self.error(FileOperation::Write, error))?;
+			return Ok(self.clone());
+		}
+
+		let name:&str = self.name();
+		let hidden_name:String = if hidden { format!("{HIDDEN_NAME_PREFIX}{name}") } else { name[HIDDEN_NAME_PREFIX.len_utf8()..].to_string() };
+		let target:FileRef = match self.path().rfind(SEPARATOR) {
+			Some(separator_index) => FileRef::new(&format!("{}{hidden_name}", &self.path()[..separator_index + SEPARATOR.len()])),
+			None => FileRef::new(&hidden_name)
+		};
+		if target.exists() {
+			return Err(Box::new(FileRefError::new(FileOperation::Move, self, "Target file already exists.").with_target(&target)));
+		}
+		self.move_to(&target)?;
+		Ok(target)
+	}
+}
+
+
+
+/// Set or clear the hidden attribute of a file on Windows.
+#[cfg(windows)]
+fn set_hidden_attribute(file:&FileRef, hidden:bool) -> std::io::Result<()> {
+	use std::{ iter::once, os::windows::{ ffi::OsStrExt, fs::MetadataExt } };
+	use windows_sys::Win32::Storage::FileSystem::{ FILE_ATTRIBUTE_HIDDEN, SetFileAttributesW };
+
+	let attributes:u32 = std::fs::metadata(file.os_path())?.file_attributes();
+	let attributes:u32 = if hidden { attributes | FILE_ATTRIBUTE_HIDDEN } else { attributes & !FILE_ATTRIBUTE_HIDDEN };
+	let wide_path:Vec<u16> = file.os_path().as_os_str().encode_wide().chain(once(0)).collect();
+	if unsafe { SetFileAttributesW(wide_path.as_ptr(), attributes) } == 0 {
+		return Err(std::io::Error::last_os_error());
+	}
+	Ok(())
+}
\ No newline at end of file
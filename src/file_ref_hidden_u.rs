@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, MemoryFs, TempDirRef };
+
+
+
+	#[test]
+	fn test_set_hidden() {
+		let temp_dir:TempDirRef = TempDirRef::new("hidden_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/state.json";
+		file.write("{}").unwrap();
+		assert!(!file.is_hidden());
+
+		let hidden:FileRef = file.set_hidden(true).unwrap();
+		assert!(hidden.is_hidden());
+		assert_eq!(hidden.read().unwrap(), "{}");
+		assert_eq!(hidden.set_hidden(true).unwrap(), hidden);
+		#[cfg(not(windows))]
+		{
+			assert_eq!(hidden.name(), ".state.json");
+			assert!(!file.exists());
+		}
+
+		let visible:FileRef = hidden.set_hidden(false).unwrap();
+		assert_eq!(visible, file);
+		assert!(!visible.is_hidden());
+		assert_eq!(visible.read().unwrap(), "{}");
+	}
+
+	#[test]
+	fn test_set_hidden_on_memory_fs() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let file:FileRef = memory_fs.file_ref("dir/notes.txt");
+		file.write("notes").unwrap();
+
+		let hidden:FileRef = file.set_hidden(true).unwrap();
+		assert_eq!(hidden, memory_fs.file_ref("dir/.notes.txt"));
+		assert_eq!(hidden.read().unwrap(), "notes");
+		assert!(!file.exists());
+		assert!(memory_fs.file_ref("dir/missing.txt").set_hidden(true).is_err());
+	}
+}
\ No newline at end of file
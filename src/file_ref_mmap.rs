@@ -0,0 +1,96 @@
+use std::{ error::Error, fs::{ File, OpenOptions }, ops::{ Deref, DerefMut } };
+use memmap2::{ Mmap, MmapMut };
+use crate::{ FileOperation, FileRef };
+
+
+
+/// A read-only memory mapping of a file.
+pub struct MappedFile {
+	file:FileRef,
+	map:Mmap
+}
+impl MappedFile {
+
+	/// Get the file that is mapped.
+	pub fn file(&self) -> &FileRef {
+		&self.file
+	}
+}
+impl Deref for MappedFile {
+	type Target = [u8];
+
+	fn deref(&self) -> &Self::Target {
+		&self.map
+	}
+}
+
+
+
+/// A writable memory mapping of a file. Changes are written back to the file by the OS at some point, or right away when calling `flush`.
+pub struct MappedFileMut {
+	file:FileRef,
+	map:MmapMut
+}
+impl MappedFileMut {
+
+	/// Get the file that is mapped.
+	pub fn file(&self) -> &FileRef {
+		&self.file
+	}
+
+	/// Write all changes to the file and wait until they are on disk.
+	pub fn flush(&self) -> Result<(), Box<dyn Error>> {
+		self.map.flush().map_err(|error| self.file.error(FileOperation::Write, error))
+	}
+
+	/// Write the changes in a range of the mapping to the file and wait until they are on disk.
+	pub fn flush_range(&self, offset:usize, length:usize) -> Result<(), Box<dyn Error>> {
+		self.map.flush_range(offset, length).map_err(|error| self.file.error(FileOperation::Write, error))
+	}
+
+	/// Start writing all changes to the file without waiting for them to be on disk.
+	pub fn flush_async(&self) -> Result<(), Box<dyn Error>> {
+		self.map.flush_async().map_err(|error| self.file.error(FileOperation::Write, error))
+	}
+}
+impl Deref for MappedFileMut {
+	type Target = [u8];
+
+	fn deref(&self) -> &Self::Target {
+		&self.map
+	}
+}
+impl DerefMut for MappedFileMut {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.map
+	}
+}
+
+
+
+impl FileRef {
+
+	/* MEMORY MAPPING METHODS */
+
+	/// Map the contents of the file into memory for reading.
+	///
+	/// # Safety
+	/// The mapping reads the file directly. If the file is truncated or modified by this or another process while mapped, reads can return changed data or crash the process.
+	pub unsafe fn mmap(&self) -> Result<MappedFile, Box<dyn Error>> {
+		self.require_std_file_system(FileOperation::Read)?;
+		let handle:File = File::open(self.os_path()).map_err(|error| self.error(FileOperation::Read, error))?;
+		let map:Mmap = unsafe { Mmap::map(&handle) }.map_err(|error| self.error(FileOperation::Read, error))?;
+		Ok(MappedFile { file: self.clone(), map })
+	}
+
+	/// Map the contents of the file into memory for reading and writing in place. The file must exist and its size can not be changed through the mapping.
+	///
+	/// # Safety
+	/// The mapping writes the file directly. If the file is truncated or modified by this or another process while mapped, including through a second mapping, access can return changed data or crash the process, and concurrent writes can be lost.
+	pub unsafe fn mmap_mut(&self) -> Result<MappedFileMut, Box<dyn Error>> {
+		self.require_std_file_system(FileOperation::Write)?;
+		let handle:File = OpenOptions::new().read(true).write(true).open(self.os_path()).map_err(|error| self.error(FileOperation::Write, error))?;
+		let map:MmapMut = unsafe { MmapMut::map_mut(&handle) }.map_err(|error| self.error(FileOperation::Write, error))?;
+		Ok(MappedFileMut { file: self.clone(), map })
+	}
+}
\ No newline at end of file
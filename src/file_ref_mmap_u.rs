@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, MappedFile, MappedFileMut, MemoryFs, TempDirRef };
+
+
+
+	#[test]
+	fn test_mmap_read() {
+		let temp_dir:TempDirRef = TempDirRef::new("mmap_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/data.bin";
+		file.write("mapped contents").unwrap();
+
+		let map:MappedFile = unsafe { file.mmap() }.unwrap();
+		assert_eq!(&map[..6], b"mapped");
+		assert_eq!(map.len(), 15);
+	}
+
+	#[test]
+	fn test_mmap_mut_edit_in_place() {
+		let temp_dir:TempDirRef = TempDirRef::new("mmap_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/index.bin";
+		file.write_bytes(&[0; 16]).unwrap();
+
+		let mut map:MappedFileMut = unsafe { file.mmap_mut() }.unwrap();
+		map[4..8].copy_from_slice(b"edit");
+		map.flush_range(0, 8).unwrap();
+		map[12] = 1;
+		map.flush().unwrap();
+		drop(map);
+
+		let contents:Vec<u8> = file.read_bytes().unwrap();
+		assert_eq!(&contents[4..8], b"edit");
+		assert_eq!(contents[12], 1);
+		assert_eq!(contents.len(), 16);
+	}
+
+	#[test]
+	fn test_mmap_requires_std_file_system() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let file:FileRef = memory_fs.file_ref("data.bin");
+		file.write("contents").unwrap();
+		assert!(unsafe { file.mmap_mut() }.is_err());
+	}
+}
\ No newline at end of file
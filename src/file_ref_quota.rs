@@ -0,0 +1,60 @@
+use std::{ error::Error, fs::Metadata, time::SystemTime };
+use crate::{ DirEntries, FileOperation, FileRef };
+
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Eviction {
+	#[default]
+	OldestFirst,
+	LeastRecentlyAccessed,
+	LargestFirst
+}
+impl Eviction {
+
+	/// Sort files in the order they should be removed in.
+	fn sort(&self, files:&mut [(FileRef, Metadata)]) {
+		match self {
+			Eviction::OldestFirst => files.sort_by_key(|(_, metadata)| metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH)),
+			Eviction::LeastRecentlyAccessed => files.sort_by_key(|(_, metadata)| metadata.accessed().or_else(|_| metadata.modified()).unwrap_or(SystemTime::UNIX_EPOCH)),
+			Eviction::LargestFirst => files.sort_by_key(|(_, metadata)| std::cmp::Reverse(metadata.len()))
+		}
+	}
+}
+
+
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QuotaReport {
+	pub removed_files:Vec<FileRef>,
+	pub bytes_freed:u64,
+	pub size_before:u64,
+	pub size_after:u64
+}
+
+
+
+impl FileRef {
+
+	/* QUOTA METHODS */
+
+	/// Remove files from this dir, recursively, until the total size of its files is at most the given amount of bytes. Files are removed in the order of the eviction strategy. Dirs are kept, even if they end up empty.
+	pub fn enforce_quota(&self, max_bytes:u64, eviction:Eviction) -> Result<QuotaReport, Box<dyn Error>> {
+		self.require_std_file_system(FileOperation::Delete)?;
+		let mut files:Vec<(FileRef, Metadata)> = DirEntries::collect(self)?.files;
+		let size_before:u64 = files.iter().map(|(_, metadata)| metadata.len()).sum();
+		let mut report:QuotaReport = QuotaReport { size_before, size_after: size_before, ..QuotaReport::default() };
+
+		eviction.sort(&mut files);
+		for (file, metadata) in files {
+			if report.size_after <= max_bytes {
+				break;
+			}
+			std::fs::remove_file(file.os_path()).map_err(|error| file.error(FileOperation::Delete, error))?;
+			report.size_after -= metadata.len();
+			report.bytes_freed += metadata.len();
+			report.removed_files.push(file);
+		}
+		Ok(report)
+	}
+}
\ No newline at end of file
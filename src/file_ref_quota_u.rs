@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+	use std::{ fs::File, time::{ Duration, SystemTime } };
+	use crate::{ Eviction, FileRef, QuotaReport, TempDirRef };
+
+
+
+	fn write_aged(file:&FileRef, size:usize, age_in_minutes:u64) {
+		file.write(&"x".repeat(size)).unwrap();
+		File::options().write(true).open(file.path()).unwrap().set_modified(SystemTime::now() - Duration::from_secs(age_in_minutes * 60)).unwrap();
+	}
+
+	fn create_cache() -> TempDirRef {
+		let temp_dir:TempDirRef = TempDirRef::new("quota_test_").unwrap();
+		write_aged(&(temp_dir.path() + "/a.bin"), 100, 30);
+		write_aged(&(temp_dir.path() + "/sub/b.bin"), 300, 20);
+		write_aged(&(temp_dir.path() + "/c.bin"), 200, 10);
+		temp_dir
+	}
+
+
+
+	#[test]
+	fn test_enforce_quota_oldest_first() {
+		let temp_dir:TempDirRef = create_cache();
+		let report:QuotaReport = temp_dir.path().enforce_quota(250, Eviction::OldestFirst).unwrap();
+		assert_eq!(report.removed_files, vec![temp_dir.path() + "/a.bin", temp_dir.path() + "/sub/b.bin"]);
+		assert_eq!((report.size_before, report.size_after, report.bytes_freed), (600, 200, 400));
+		assert!((temp_dir.path() + "/c.bin").exists());
+		assert!((temp_dir.path() + "/sub").exists());
+
+		let report:QuotaReport = temp_dir.path().enforce_quota(250, Eviction::OldestFirst).unwrap();
+		assert!(report.removed_files.is_empty());
+	}
+
+	#[test]
+	fn test_enforce_quota_largest_first() {
+		let temp_dir:TempDirRef = create_cache();
+		let report:QuotaReport = temp_dir.path().enforce_quota(300, Eviction::LargestFirst).unwrap();
+		assert_eq!(report.removed_files, vec![temp_dir.path() + "/sub/b.bin"]);
+		assert_eq!(report.size_after, 300);
+	}
+}
\ No newline at end of file
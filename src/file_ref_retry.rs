@@ -0,0 +1,176 @@
+use std::{ error::Error, io::{ Error as IoError, ErrorKind }, thread::sleep, time::Duration };
+use crate::{ FileRef, FileRefError };
+
+
+
+const DEFAULT_MAX_ATTEMPTS:u32 = 3;
+const DEFAULT_INITIAL_BACKOFF:Duration = Duration::from_millis(50);
+const DEFAULT_MAX_BACKOFF:Duration = Duration::from_secs(2);
+const DEFAULT_RETRYABLE_KINDS:[ErrorKind; 8] = [
+	ErrorKind::Interrupted,
+	ErrorKind::WouldBlock,
+	ErrorKind::TimedOut,
+	ErrorKind::ResourceBusy,
+	ErrorKind::ConnectionReset,
+	ErrorKind::ConnectionAborted,
+	ErrorKind::NotConnected,
+	ErrorKind::BrokenPipe
+];
+
+
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+	max_attempts:u32,
+	initial_backoff:Duration,
+	max_backoff:Duration,
+	retryable_kinds:Vec<ErrorKind>
+}
+impl RetryPolicy {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new default retry policy. Operations are attempted 3 times, waiting 50ms before the first retry and doubling the wait up to 2s. Only transient IO errors like timeouts and interruptions are retried.
+	pub fn new() -> RetryPolicy {
+		RetryPolicy::default()
+	}
+
+	/// Return self with the maximum number of attempts, including the first one.
+	pub fn max_attempts(mut self, max_attempts:u32) -> Self {
+		self.max_attempts = max_attempts.max(1);
+		self
+	}
+
+	/// Return self with the wait before the first retry and the maximum wait. The wait doubles after every retry.
+	pub fn backoff(mut self, initial_backoff:Duration, max_backoff:Duration) -> Self {
+		self.initial_backoff = initial_backoff;
+		self.max_backoff = max_backoff.max(initial_backoff);
+		self
+	}
+
+	/// Return self with an additional kind of IO error that should be retried.
+	pub fn retry_on(mut self, kind:ErrorKind) -> Self {
+		if !self.retryable_kinds.contains(&kind) {
+			self.retryable_kinds.push(kind);
+		}
+		self
+	}
+
+	/// Return self with only the given kinds of IO errors being retried.
+	pub fn retryable_kinds(mut self, kinds:&[ErrorKind]) -> Self {
+		self.retryable_kinds = kinds.to_vec();
+		self
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Run the operation until it succeeds, it fails with an error that is not retryable, or the maximum number of attempts is reached. Returns the result of the last attempt.
+	pub fn run<T, U>(&self, mut operation:T) -> Result<U, Box<dyn Error>> where T:FnMut() -> Result<U, Box<dyn Error>> {
+		let mut backoff:Duration = self.initial_backoff;
+		let mut attempt:u32 = 1;
+		loop {
+			match operation() {
+				Err(error) if attempt < self.max_attempts && self.is_retryable(error.as_ref()) => {
+					sleep(backoff);
+					backoff = (backoff * 2).min(self.max_backoff);
+					attempt += 1;
+				},
+				result => return result
+			}
+		}
+	}
+
+	/// Check if the error is caused by an IO error of a retryable kind.
+	pub fn is_retryable(&self, error:&(dyn Error + 'static)) -> bool {
+		let kind:Option<ErrorKind> = match error.downcast_ref::<FileRefError>() {
+			Some(error) => error.io_error_kind(),
+			None => error.downcast_ref::<IoError>().map(|error| error.kind())
+		};
+		kind.map(|kind| self.retryable_kinds.contains(&kind)).unwrap_or(false)
+	}
+}
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		RetryPolicy {
+			max_attempts: DEFAULT_MAX_ATTEMPTS,
+			initial_backoff: DEFAULT_INITIAL_BACKOFF,
+			max_backoff: DEFAULT_MAX_BACKOFF,
+			retryable_kinds: DEFAULT_RETRYABLE_KINDS.to_vec()
+		}
+	}
+}
+
+
+
+#[derive(Clone, Debug)]
+pub struct RetryingFileRef {
+	file:FileRef,
+	policy:RetryPolicy
+}
+impl RetryingFileRef {
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the file the operations are executed on.
+	pub fn file(&self) -> &FileRef {
+		&self.file
+	}
+
+	/// Get the policy used to retry failed operations.
+	pub fn policy(&self) -> &RetryPolicy {
+		&self.policy
+	}
+
+
+
+	/* FILE OPERATION METHODS */
+
+	/// Read the contents of the file as a string, retrying transient failures.
+	pub fn read(&self) -> Result<String, Box<dyn Error>> {
+		self.policy.run(|| self.file.read())
+	}
+
+	/// Read the contents of the file as bytes, retrying transient failures.
+	pub fn read_bytes(&self) -> Result<Vec<u8>, Box<dyn Error>> {
+		self.policy.run(|| self.file.read_bytes())
+	}
+
+	/// Read a specific range of bytes from the file, retrying transient failures.
+	pub fn read_range(&self, start:u64, end:u64) -> Result<Vec<u8>, Box<dyn Error>> {
+		self.policy.run(|| self.file.read_range(start, end))
+	}
+
+	/// Write a string to the file, retrying transient failures.
+	pub fn write(&self, contents:&str) -> Result<(), Box<dyn Error>> {
+		self.policy.run(|| self.file.write(contents))
+	}
+
+	/// Write bytes to the file, retrying transient failures.
+	pub fn write_bytes(&self, data:&[u8]) -> Result<(), Box<dyn Error>> {
+		self.policy.run(|| self.file.write_bytes(data))
+	}
+
+	/// Copy the file to another location, retrying transient failures. Returns the number of bytes written.
+	pub fn copy_to(&self, target:&FileRef) -> Result<u64, Box<dyn Error>> {
+		self.policy.run(|| self.file.copy_to(target))
+	}
+
+	/// Delete the file, retrying transient failures.
+	pub fn delete(&self) -> Result<(), Box<dyn Error>> {
+		self.policy.run(|| self.file.delete())
+	}
+}
+
+
+
+impl FileRef {
+
+	/* RETRY METHODS */
+
+	/// Wrap the file so its operations are retried on transient IO errors according to the policy. Appending is not offered, as a retried append could write the data twice.
+	pub fn with_retry(&self, policy:RetryPolicy) -> RetryingFileRef {
+		RetryingFileRef { file: self.clone(), policy }
+	}
+}
\ No newline at end of file
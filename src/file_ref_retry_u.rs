@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+	use std::{ error::Error, io::{ Error as IoError, ErrorKind }, sync::{ Arc, atomic::{ AtomicU32, Ordering } }, time::Duration };
+	use crate::{ FileRef, FileSystem, MemoryFs, RetryPolicy, mount_file_system };
+
+
+
+	/// A filesystem that fails the given number of reads with the given error kind before forwarding them to another filesystem.
+	struct FlakyFileSystem {
+		inner:Arc<dyn FileSystem>,
+		failures_left:AtomicU32,
+		kind:ErrorKind
+	}
+	impl FileSystem for FlakyFileSystem {
+		fn exists(&self, path:&str) -> bool { self.inner.exists(path) }
+		fn is_accessible(&self, path:&str) -> bool { self.inner.is_accessible(path) }
+		fn create(&self, path:&str, is_dir:bool) -> Result<(), Box<dyn Error>> { self.inner.create(path, is_dir) }
+		fn read(&self, path:&str) -> Result<Vec<u8>, Box<dyn Error>> {
+			if self.failures_left.load(Ordering::SeqCst) > 0 {
+				self.failures_left.fetch_sub(1, Ordering::SeqCst);
+				return Err(IoError::from(self.kind).into());
+			}
+			self.inner.read(path)
+		}
+		fn write_at(&self, path:&str, offset:Option<u64>, data:&[u8]) -> Result<(), Box<dyn Error>> { self.inner.write_at(path, offset, data) }
+		fn remove(&self, path:&str) -> Result<(), Box<dyn Error>> { self.inner.remove(path) }
+		fn list(&self, path:&str) -> Result<Vec<String>, Box<dyn Error>> { self.inner.list(path) }
+	}
+
+	/// Create a file in a memory filesystem of which the first reads fail.
+	fn flaky_file(failures:u32, kind:ErrorKind) -> (MemoryFs, FileRef, Arc<FlakyFileSystem>) {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let flaky_dir:FileRef = memory_fs.file_ref("flaky");
+		let file_system:Arc<FlakyFileSystem> = Arc::new(FlakyFileSystem { inner: flaky_dir.file_system(), failures_left: AtomicU32::new(0), kind });
+		mount_file_system(&flaky_dir, file_system.clone());
+		let file:FileRef = flaky_dir + "/file.txt";
+		file.write("contents").unwrap();
+		file_system.failures_left.store(failures, Ordering::SeqCst);
+		(memory_fs, file, file_system)
+	}
+
+	fn fast_policy() -> RetryPolicy {
+		RetryPolicy::new().backoff(Duration::from_millis(1), Duration::from_millis(4))
+	}
+
+
+
+	#[test]
+	fn test_retry_transient_errors() {
+		let (_memory_fs, file, _file_system) = flaky_file(2, ErrorKind::TimedOut);
+		assert_eq!(file.with_retry(fast_policy()).read().unwrap(), "contents");
+	}
+
+	#[test]
+	fn test_retry_gives_up_after_max_attempts() {
+		let (_memory_fs, file, file_system) = flaky_file(5, ErrorKind::TimedOut);
+		assert!(file.with_retry(fast_policy().max_attempts(3)).read().is_err());
+		assert_eq!(file_system.failures_left.load(Ordering::SeqCst), 2);
+	}
+
+	#[test]
+	fn test_retry_ignores_permanent_errors() {
+		let (_memory_fs, file, file_system) = flaky_file(2, ErrorKind::PermissionDenied);
+		assert!(file.with_retry(fast_policy()).read().is_err());
+		assert_eq!(file_system.failures_left.load(Ordering::SeqCst), 1);
+
+		assert_eq!(file.with_retry(fast_policy().retry_on(ErrorKind::PermissionDenied)).read().unwrap(), "contents");
+	}
+
+	#[test]
+	fn test_retry_policy_run() {
+		let mut attempts:u32 = 0;
+		let result:Result<u32, Box<dyn Error>> = fast_policy().max_attempts(4).run(|| {
+			attempts += 1;
+			if attempts < 4 { Err(IoError::from(ErrorKind::Interrupted).into()) } else { Ok(attempts) }
+		});
+		assert_eq!(result.unwrap(), 4);
+	}
+}
\ No newline at end of file
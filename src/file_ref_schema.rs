@@ -0,0 +1,27 @@
+use std::borrow::Cow;
+use schemars::{ JsonSchema, Schema, SchemaGenerator, json_schema };
+use crate::FileRef;
+
+
+
+impl JsonSchema for FileRef {
+	fn inline_schema() -> bool {
+		true
+	}
+
+	fn schema_name() -> Cow<'static, str> {
+		"FileRef".into()
+	}
+
+	fn schema_id() -> Cow<'static, str> {
+		concat!(module_path!(), "::FileRef").into()
+	}
+
+	fn json_schema(_generator:&mut SchemaGenerator) -> Schema {
+		json_schema!({
+			"type": "string",
+			"format": "path",
+			"minLength": 1
+		})
+	}
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod tests {
+	use schemars::{ JsonSchema, Schema, schema_for };
+	use crate::FileRef;
+
+
+
+	#[allow(dead_code)]
+	#[derive(JsonSchema)]
+	struct Config {
+		output:FileRef,
+		inputs:Vec<FileRef>
+	}
+
+
+
+	#[test]
+	fn test_schema_for_file_ref() {
+		let schema:Schema = schema_for!(FileRef);
+		assert_eq!(schema.get("type").unwrap(), "string");
+		assert_eq!(schema.get("format").unwrap(), "path");
+	}
+
+	#[test]
+	fn test_schema_for_config_with_file_refs() {
+		let schema:Schema = schema_for!(Config);
+		let properties:&serde_json::Value = schema.get("properties").unwrap();
+		assert_eq!(properties["output"]["format"], "path");
+		assert_eq!(properties["inputs"]["type"], "array");
+		assert_eq!(properties["inputs"]["items"]["format"], "path");
+	}
+}
\ No newline at end of file
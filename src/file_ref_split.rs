@@ -0,0 +1,116 @@
+use std::{ error::Error, fs::File, io::{ Read, Write } };
+use crate::{ FileOperation, FileRef, HashAlgorithm, SEPARATOR };
+
+
+
+const PART_EXTENSION_PREFIX:&str = ".part";
+const MIN_PART_NUMBER_WIDTH:usize = 3;
+const SPLIT_BUFFER_SIZE:usize = 64 * 1024;
+const SPLIT_HASH_ALGORITHM:HashAlgorithm = HashAlgorithm::Sha256;
+
+
+
+impl FileRef {
+
+	/* SPLITTING METHODS */
+
+	/// Split the file into parts of at most `chunk_size` bytes in the target dir, named 'name.part001', 'name.part002' and so on. A 'name.sha256' checksum file of the whole file is written next to the parts, so `join_chunks` can verify the result. Returns the parts in order.
+	pub fn split_into_chunks(&self, chunk_size:u64, target_dir:&FileRef) -> Result<Vec<FileRef>, Box<dyn Error>> {
+		if self.is_dir() {
+			return Err(self.error(FileOperation::Read, "Only able to split files."));
+		} else if !self.exists() {
+			return Err(self.error(FileOperation::Read, "File does not exist."));
+		} else if chunk_size == 0 {
+			return Err(self.error(FileOperation::Read, "Chunk size can not be zero."));
+		}
+		self.require_std_file_system(FileOperation::Read)?;
+		target_dir.require_std_file_system(FileOperation::Write)?;
+
+		// Determine part names.
+		let file_size:u64 = std::fs::metadata(self.os_path())?.len();
+		let part_count:u64 = file_size.div_ceil(chunk_size).max(1);
+		let number_width:usize = part_count.to_string().len().max(MIN_PART_NUMBER_WIDTH);
+		let target_dir:FileRef = target_dir.clone().trim_end_matches(SEPARATOR);
+		target_dir.guarantee_exists()?;
+
+		// Write parts.
+		let mut source:File = File::open(self.os_path())?;
+		let mut buffer:Vec<u8> = vec![0; SPLIT_BUFFER_SIZE];
+		let mut parts:Vec<FileRef> = Vec::with_capacity(part_count as usize);
+		for part_index in 0..part_count {
+			let part:FileRef = target_dir.clone() + SEPARATOR + &format!("{}{PART_EXTENSION_PREFIX}{:0number_width$}", self.name(), part_index + 1);
+			let mut part_file:File = File::create(part.os_path())?;
+			let mut remaining:u64 = chunk_size;
+			while remaining > 0 {
+				let read_size:usize = source.read(&mut buffer[..(remaining as usize).min(SPLIT_BUFFER_SIZE)])?;
+				if read_size == 0 {
+					break;
+				}
+				part_file.write_all(&buffer[..read_size])?;
+				remaining -= read_size as u64;
+			}
+			parts.push(part);
+		}
+
+		// Store checksum of the whole file.
+		let checksum_file:FileRef = (target_dir + SEPARATOR + self.name()).checksum_file(SPLIT_HASH_ALGORITHM);
+		checksum_file.write(&format!("{}  {}\n", self.hash(SPLIT_HASH_ALGORITHM)?, self.name()))?;
+		Ok(parts)
+	}
+
+	/// Join parts created by `split_into_chunks` into the target file, in the given order. The joined file is verified against the checksum file written by `split_into_chunks` next to the first part, and an error is returned if that checksum file is missing. Returns the size of the joined file.
+	pub fn join_chunks(parts:&[FileRef], target:&FileRef) -> Result<u64, Box<dyn Error>> {
+		FileRef::join_chunks_with(parts, target, true)
+	}
+
+	/// Join parts into the target file, in the given order, without verifying the result against a checksum file. Returns the size of the joined file.
+	pub fn join_chunks_unverified(parts:&[FileRef], target:&FileRef) -> Result<u64, Box<dyn Error>> {
+		FileRef::join_chunks_with(parts, target, false)
+	}
+
+	/// Join parts into the target file, verifying the result against the checksum of the original file if enabled.
+	fn join_chunks_with(parts:&[FileRef], target:&FileRef, verify:bool) -> Result<u64, Box<dyn Error>> {
+		use std::io::copy;
+
+		if parts.is_empty() {
+			return Err(target.error(FileOperation::Write, "No parts given to join."));
+		}
+		if let Some(missing_part) = parts.iter().find(|part| !part.exists()) {
+			return Err(target.error(FileOperation::Write, format!("Part \"{}\" does not exist.", missing_part.path())));
+		}
+		target.require_std_file_system(FileOperation::Write)?;
+		for part in parts {
+			part.require_std_file_system(FileOperation::Read)?;
+		}
+
+		// Find the checksum of the original file before writing anything.
+		let mut expected_hash:Option<String> = None;
+		if verify {
+			let original_file:Option<FileRef> = parts[0].path().rfind(PART_EXTENSION_PREFIX).map(|extension_index| FileRef::new(&parts[0].path()[..extension_index]));
+			let checksum_file:Option<FileRef> = original_file.map(|original_file| original_file.checksum_file(SPLIT_HASH_ALGORITHM)).filter(|checksum_file| checksum_file.exists());
+			match checksum_file {
+				Some(checksum_file) => expected_hash = Some(checksum_file.read()?.split_whitespace().next().unwrap_or_default().to_lowercase()),
+				None => return Err(target.error(FileOperation::Write, "No checksum file found next to the first part, use join_chunks_unverified to join without verifying."))
+			}
+		}
+
+		// Concatenate parts.
+		target.guarantee_parent_dir()?;
+		let mut target_file:File = File::create(target.os_path())?;
+		let mut joined_size:u64 = 0;
+		for part in parts {
+			joined_size += copy(&mut File::open(part.os_path())?, &mut target_file)?;
+		}
+		target_file.flush()?;
+		drop(target_file);
+
+		// Verify against the checksum of the original file.
+		if let Some(expected_hash) = expected_hash {
+			let actual_hash:String = target.hash(SPLIT_HASH_ALGORITHM)?;
+			if expected_hash != actual_hash {
+				return Err(target.error(FileOperation::Write, format!("Checksum {actual_hash} does not match the checksum {expected_hash} of the original file.")));
+			}
+		}
+		Ok(joined_size)
+	}
+}
\ No newline at end of file
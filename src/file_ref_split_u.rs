@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, TempDirRef };
+
+
+
+	#[test]
+	fn test_split_and_join() {
+		let temp_dir:TempDirRef = TempDirRef::new("split_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/video.bin";
+		let contents:String = (0..2500).map(|index| (b'a' + (index % 26) as u8) as char).collect();
+		source.write(&contents).unwrap();
+		let parts_dir:FileRef = temp_dir.path() + "/parts";
+
+		let parts:Vec<FileRef> = source.split_into_chunks(1000, &parts_dir).unwrap();
+		assert_eq!(parts.iter().map(|part| part.name()).collect::<Vec<&str>>(), vec!["video.bin.part001", "video.bin.part002", "video.bin.part003"]);
+		assert_eq!(parts[2].read().unwrap().len(), 500);
+		assert!((parts_dir.clone() + "/video.bin.sha256").exists());
+
+		let joined:FileRef = temp_dir.path() + "/joined/video.bin";
+		assert_eq!(FileRef::join_chunks(&parts, &joined).unwrap(), 2500);
+		assert_eq!(joined.read().unwrap(), contents);
+	}
+
+	#[test]
+	fn test_join_detects_corruption() {
+		let temp_dir:TempDirRef = TempDirRef::new("split_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/video.bin";
+		source.write("0123456789").unwrap();
+		let parts:Vec<FileRef> = source.split_into_chunks(4, &(temp_dir.path() + "/parts")).unwrap();
+		assert_eq!(parts.len(), 3);
+
+		parts[1].write("abcd").unwrap();
+		assert!(FileRef::join_chunks(&parts, &(temp_dir.path() + "/joined.bin")).is_err());
+		assert!(FileRef::join_chunks(&[parts[0].clone(), parts[2].clone()], &(temp_dir.path() + "/joined.bin")).is_err());
+	}
+
+	#[test]
+	fn test_join_requires_checksum() {
+		let temp_dir:TempDirRef = TempDirRef::new("split_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/video.bin";
+		source.write("0123456789").unwrap();
+		let parts:Vec<FileRef> = source.split_into_chunks(4, &(temp_dir.path() + "/parts")).unwrap();
+		(temp_dir.path() + "/parts/video.bin.sha256").delete().unwrap();
+
+		let joined:FileRef = temp_dir.path() + "/joined.bin";
+		assert!(FileRef::join_chunks(&parts, &joined).is_err());
+		assert!(!joined.exists());
+		assert_eq!(FileRef::join_chunks_unverified(&parts, &joined).unwrap(), 10);
+		assert_eq!(joined.read().unwrap(), "0123456789");
+	}
+
+	#[test]
+	fn test_split_empty_file() {
+		let temp_dir:TempDirRef = TempDirRef::new("split_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/empty.bin";
+		source.write("").unwrap();
+		let parts:Vec<FileRef> = source.split_into_chunks(4, &(temp_dir.path() + "/parts")).unwrap();
+		assert_eq!(parts.len(), 1);
+		assert!(source.split_into_chunks(0, &(temp_dir.path() + "/parts")).is_err());
+	}
+}
\ No newline at end of file
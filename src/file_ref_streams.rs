@@ -0,0 +1,71 @@
+use std::{ error::Error, io, path::Path };
+use crate::{ FileOperation, FileRef };
+
+
+
+const STREAM_SEPARATOR:&str = ":";
+
+
+
+impl FileRef {
+
+	/* ALTERNATE DATA STREAM METHODS */
+
+	/// Get a reference to a named alternate data stream of the file, addressed as `file.txt:name`. On NTFS the stream can be read and written like a regular file.
+	pub fn stream(&self, name:&str) -> FileRef {
+		self.clone() + &format!("{STREAM_SEPARATOR}{name}")
+	}
+
+	/// List the names of the alternate data streams of the file. The unnamed default stream is not included. Only supported on Windows.
+	pub fn streams(&self) -> Result<Vec<String>, Box<dyn Error>> {
+		if self.mounted_file_system().is_some() {
+			Err(self.error(FileOperation::Read, io::Error::new(io::ErrorKind::Unsupported, "Alternate data streams are only supported on the filesystem of the OS.")))
+		} else if !self.exists() {
+			Err(self.error(FileOperation::Read, "File does not exist."))
+		} else {
+			list_streams(&self.os_path()).map_err(|error| self.error(FileOperation::Read, error))
+		}
+	}
+}
+
+
+
+/// List the alternate data streams of a file using the stream enumeration API of Windows.
+#[cfg(windows)]
+fn list_streams(path:&Path) -> io::Result<Vec<String>> {
+	use std::{ ffi::c_void, iter::once, os::windows::ffi::OsStrExt };
+	use windows_sys::Win32::{ Foundation::{ ERROR_HANDLE_EOF, HANDLE, INVALID_HANDLE_VALUE }, Storage::FileSystem::{ FindClose, FindFirstStreamW, FindNextStreamW, FindStreamInfoStandard, WIN32_FIND_STREAM_DATA } };
+
+	let wide_path:Vec<u16> = path.as_os_str().encode_wide().chain(once(0)).collect();
+	let mut data:WIN32_FIND_STREAM_DATA = unsafe { std::mem::zeroed() };
+	let handle:HANDLE = unsafe { FindFirstStreamW(wide_path.as_ptr(), FindStreamInfoStandard, &mut data as *mut WIN32_FIND_STREAM_DATA as *mut c_void, 0) };
+	if handle == INVALID_HANDLE_VALUE {
+		let error:io::Error = io::Error::last_os_error();
+		return if error.raw_os_error() == Some(ERROR_HANDLE_EOF as i32) { Ok(Vec::new()) } else { Err(error) };
+	}
+
+	let mut names:Vec<String> = Vec::new();
+	loop {
+
+		// Streams are named ":name:$DATA", the default stream is named "::$DATA".
+		let name_length:usize = data.cStreamName.iter().position(|character| *character == 0).unwrap_or(data.cStreamName.len());
+		let full_name:String = String::from_utf16_lossy(&data.cStreamName[..name_length]);
+		let name:&str = full_name.strip_prefix(STREAM_SEPARATOR).unwrap_or(&full_name);
+		let name:&str = name.strip_suffix(":$DATA").unwrap_or(name);
+		if !name.is_empty() {
+			names.push(name.to_string());
+		}
+
+		if unsafe { FindNextStreamW(handle, &mut data as *mut WIN32_FIND_STREAM_DATA as *mut c_void) } == 0 {
+			let error:io::Error = io::Error::last_os_error();
+			unsafe { FindClose(handle) };
+			return if error.raw_os_error() == Some(ERROR_HANDLE_EOF as i32) { Ok(names) } else { Err(error) };
+		}
+	}
+}
+
+/// Alternate data streams only exist on Windows.
+#[cfg(not(windows))]
+fn list_streams(_path:&Path) -> io::Result<Vec<String>> {
+	Err(io::Error::new(io::ErrorKind::Unsupported, "Alternate data streams are only supported on Windows."))
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, TempDirRef };
+
+
+
+	#[test]
+	fn test_stream_path() {
+		let file:FileRef = FileRef::new("C:/data/file.txt");
+		let stream:FileRef = file.stream("Zone.Identifier");
+		assert_eq!(stream.path(), "C:/data/file.txt:Zone.Identifier");
+		assert!(stream.is_file());
+	}
+
+	#[test]
+	#[cfg(not(windows))]
+	fn test_streams_unsupported() {
+		let temp_dir:TempDirRef = TempDirRef::new("streams_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/file.txt";
+		file.write("contents").unwrap();
+		assert!(file.streams().is_err());
+	}
+
+	#[test]
+	#[cfg(windows)]
+	fn test_streams_list() {
+		let temp_dir:TempDirRef = TempDirRef::new("streams_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/file.txt";
+		file.write("contents").unwrap();
+		assert!(file.streams().unwrap().is_empty());
+
+		file.stream("tag").write("hidden").unwrap();
+		assert_eq!(file.stream("tag").read().unwrap(), "hidden");
+		assert_eq!(file.read().unwrap(), "contents");
+		assert_eq!(file.streams().unwrap(), vec!["tag".to_string()]);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+use std::{ error::Error, io::{ Error as IoError, ErrorKind }, path::PathBuf };
+use crate::{ FileOperation, FileRef, FileRefError };
+
+
+
+impl FileRef {
+
+	/* SYMLINK METHODS */
+
+	/// Check if the path is a symlink itself, whether or not its target exists. Mounted filesystems have no symlinks.
+	pub fn is_symlink(&self) -> bool {
+		self.mounted_file_system().is_none() && std::fs::symlink_metadata(self.os_path()).is_ok_and(|metadata| metadata.file_type().is_symlink())
+	}
+
+	/// Get the target the symlink points to, as stored in the link. Relative targets are relative to the dir of the link.
+	pub fn read_link(&self) -> Result<FileRef, Box<dyn Error>> {
+		if self.mounted_file_system().is_some() {
+			return Err(self.error(FileOperation::Resolve, "Symlinks are only supported on the filesystem of the OS."));
+		}
+		let target:PathBuf = std::fs::read_link(self.os_path()).map_err(|error| self.error(FileOperation::Resolve, error))?;
+		Ok(FileRef::new(&target.display().to_string()))
+	}
+
+	/// Turn this path into a symlink pointing to the given target, creating the parent dir if needed. Relative targets are stored as they are, so they resolve against the dir of the link. On Windows, the target must exist to know whether to create a file or dir symlink, missing targets get a file symlink.
+	pub fn symlink_to(&self, target:&FileRef) -> Result<(), Box<dyn Error>> {
+		self.with_operation_hooks(FileOperation::Symlink, Some(target), || {
+			if self.mounted_file_system().is_some() || target.mounted_file_system().is_some() {
+				return Err(link_error(FileOperation::Symlink, self, target, "Symlinks are only supported on the filesystem of the OS."));
+			}
+			if std::fs::symlink_metadata(self.os_path()).is_ok() {
+				return Err(link_error(FileOperation::Symlink, self, target, "File already exists."));
+			}
+			self.guarantee_parent_dir()?;
+
+			#[cfg(unix)]
+			let result:std::io::Result<()> = std::os::unix::fs::symlink(target.path(), self.os_path());
+			#[cfg(windows)]
+			let result:std::io::Result<()> = {
+				let resolved_target:FileRef = if target.is_absolute_path() { target.clone() } else { self.parent_dir().and_then(|dir| dir.join_ref(target)).unwrap_or_else(|_| target.clone()) };
+				if resolved_target.is_dir_fs() {
+					std::os::windows::fs::symlink_dir(target.path(), self.os_path())
+				} else {
+					std::os::windows::fs::symlink_file(target.path(), self.os_path())
+				}
+			};
+			#[cfg(not(any(unix, windows)))]
+			let result:std::io::Result<()> = Err(IoError::from(ErrorKind::Unsupported));
+
+			result.map_err(|error| link_error(FileOperation::Symlink, self, target, error))
+		})
+	}
+
+
+
+	/* HARDLINK METHODS */
+
+	/// Turn this path into a hard link to the given existing file, creating the parent dir if needed. Both paths then share the same contents. Fails with a `CrossesDevices` error if the paths are on different filesystems.
+	pub fn hardlink_to(&self, target:&FileRef) -> Result<(), Box<dyn Error>> {
+		self.with_operation_hooks(FileOperation::Link, Some(target), || {
+			if self.mounted_file_system().is_some() || target.mounted_file_system().is_some() {
+				return Err(link_error(FileOperation::Link, self, target, "Hard links are only supported on the filesystem of the OS."));
+			}
+			if std::fs::symlink_metadata(self.os_path()).is_ok() {
+				return Err(link_error(FileOperation::Link, self, target, "File already exists."));
+			}
+			self.guarantee_parent_dir()?;
+			std::fs::hard_link(target.os_path(), self.os_path()).map_err(|error| match error.kind() {
+				ErrorKind::CrossesDevices => link_error(FileOperation::Link, self, target, IoError::new(ErrorKind::CrossesDevices, "Hard links can not cross filesystems, copy the file instead.")),
+				_ => link_error(FileOperation::Link, self, target, error)
+			})
+		})
+	}
+}
+
+
+
+/// Create the error of a failed symlink or hard link operation.
+fn link_error<T>(operation:FileOperation, link:&FileRef, target:&FileRef, source:T) -> Box<dyn Error> where T:Into<Box<dyn Error>> {
+	Box::new(FileRefError::new(operation, link, source).with_target(target))
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, MemoryFs, TempDirRef };
+
+
+
+	#[test]
+	fn test_symlink_to() {
+		let temp_dir:TempDirRef = TempDirRef::new("symlink_test_").unwrap();
+		let file:FileRef = temp_dir.path().join("file.txt").unwrap();
+		file.write("contents").unwrap();
+		let link:FileRef = temp_dir.path().join("links/link.txt").unwrap();
+		link.symlink_to(&file).unwrap();
+
+		assert!(link.is_symlink());
+		assert!(!file.is_symlink());
+		assert_eq!(link.read_link().unwrap(), file);
+		assert_eq!(link.read().unwrap(), "contents");
+		assert!(link.symlink_to(&file).is_err());
+		assert!(file.read_link().is_err());
+
+		link.delete().unwrap();
+		assert!(!link.is_symlink());
+		assert!(file.exists());
+	}
+
+	#[test]
+	fn test_symlink_to_dir() {
+		let temp_dir:TempDirRef = TempDirRef::new("symlink_dir_test_").unwrap();
+		let dir:FileRef = temp_dir.path().join("dir").unwrap();
+		std::fs::create_dir(dir.path()).unwrap();
+		temp_dir.path().join("dir/file.txt").unwrap().write("contents").unwrap();
+
+		let link:FileRef = temp_dir.path().join("link").unwrap();
+		link.symlink_to(&FileRef::new("dir")).unwrap();
+		assert_eq!(link.read_link().unwrap(), FileRef::new("dir"));
+		assert!(link.is_dir_fs());
+		assert_eq!(link.join("file.txt").unwrap().read().unwrap(), "contents");
+
+		link.delete().unwrap();
+		assert!(!link.is_symlink());
+		assert_eq!(temp_dir.path().join("dir/file.txt").unwrap().read().unwrap(), "contents");
+	}
+
+	#[test]
+	fn test_dangling_symlink() {
+		let temp_dir:TempDirRef = TempDirRef::new("symlink_dangling_test_").unwrap();
+		let link:FileRef = temp_dir.path().join("link.txt").unwrap();
+		link.symlink_to(&temp_dir.path().join("missing.txt").unwrap()).unwrap();
+
+		assert!(!link.exists());
+		assert!(link.is_symlink());
+		link.delete().unwrap();
+		assert!(!link.is_symlink());
+	}
+
+	#[test]
+	fn test_symlink_mounted() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let file:FileRef = memory_fs.file_ref("file.txt");
+		file.write("contents").unwrap();
+
+		assert!(!file.is_symlink());
+		assert!(file.read_link().is_err());
+		assert!(memory_fs.file_ref("link.txt").symlink_to(&file).is_err());
+	}
+
+	#[test]
+	fn test_hardlink_to() {
+		let temp_dir:TempDirRef = TempDirRef::new("hardlink_test_").unwrap();
+		let file:FileRef = temp_dir.path().join("file.txt").unwrap();
+		file.write("contents").unwrap();
+		let link:FileRef = temp_dir.path().join("backup/file.txt").unwrap();
+		link.hardlink_to(&file).unwrap();
+
+		assert!(!link.is_symlink());
+		assert_eq!(link.read().unwrap(), "contents");
+		file.write("changed").unwrap();
+		assert_eq!(link.read().unwrap(), "changed");
+		assert!(link.hardlink_to(&file).is_err());
+		assert!(temp_dir.path().join("missing_link.txt").unwrap().hardlink_to(&temp_dir.path().join("missing.txt").unwrap()).is_err());
+
+		file.delete().unwrap();
+		assert_eq!(link.read().unwrap(), "changed");
+
+		let memory_fs:MemoryFs = MemoryFs::new();
+		memory_fs.file_ref("file.txt").write("contents").unwrap();
+		assert!(memory_fs.file_ref("link.txt").hardlink_to(&memory_fs.file_ref("file.txt")).is_err());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,318 @@
+use std::{ collections::{ BTreeSet, HashSet }, error::Error, fs::File, time::SystemTime };
+use crate::{ Cancelled, CopyOptions, DirEntries, DirRef, DirSnapshot, FileComparison, FileOperation, FileRef, FileRefError, NoProgress, Progress, ProgressTracker, SnapshotEntry, SEPARATOR, files_differ };
+
+
+
+#[derive(Clone, Debug, Default)]
+pub struct SyncOptions {
+	comparison:FileComparison,
+	delete_extraneous:bool,
+	dry_run:bool
+}
+impl SyncOptions {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create new default sync options. Files are compared by size and modification time and nothing is deleted from the target.
+	pub fn new() -> SyncOptions {
+		SyncOptions::default()
+	}
+
+	/// Return self with a setting to compare files by their contents instead of their size and modification time.
+	pub fn compare_contents(mut self) -> Self {
+		self.comparison = FileComparison::Contents;
+		self
+	}
+
+	/// Return self with a setting to delete files and dirs from the target that do not exist in the source.
+	pub fn delete_extraneous(mut self) -> Self {
+		self.delete_extraneous = true;
+		self
+	}
+
+	/// Return self with a setting to only report the actions that would be taken, without modifying the target.
+	pub fn dry_run(mut self) -> Self {
+		self.dry_run = true;
+		self
+	}
+}
+
+
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SyncReport {
+	pub created:Vec<FileRef>,
+	pub updated:Vec<FileRef>,
+	pub deleted:Vec<FileRef>,
+	pub unchanged:usize,
+	pub bytes_copied:u64
+}
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConflictResolution {
+	KeepLeft,
+	KeepRight,
+	KeepBoth
+}
+
+
+
+pub enum ConflictPolicy {
+	NewerWins,
+	PreferLeft,
+	PreferRight,
+	RenameBoth,
+	Callback(Box<dyn Fn(&SyncConflict) -> ConflictResolution>)
+}
+impl ConflictPolicy {
+
+	/// Decide how to resolve a conflict. With NewerWins, a modified file wins over a deleted one.
+	fn resolve(&self, conflict:&SyncConflict) -> ConflictResolution {
+		match self {
+			ConflictPolicy::NewerWins => match (&conflict.left, &conflict.right) {
+				(Some(left), Some(right)) => if right.modified > left.modified { ConflictResolution::KeepRight } else { ConflictResolution::KeepLeft },
+				(None, Some(_)) => ConflictResolution::KeepRight,
+				_ => ConflictResolution::KeepLeft
+			},
+			ConflictPolicy::PreferLeft => ConflictResolution::KeepLeft,
+			ConflictPolicy::PreferRight => ConflictResolution::KeepRight,
+			ConflictPolicy::RenameBoth => ConflictResolution::KeepBoth,
+			ConflictPolicy::Callback(callback) => callback(conflict)
+		}
+	}
+}
+
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyncConflict {
+	pub path:FileRef,
+	pub left:Option<SnapshotEntry>,
+	pub right:Option<SnapshotEntry>,
+	pub resolution:Option<ConflictResolution>
+}
+
+
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TwoWaySyncReport {
+	pub copied_to_left:Vec<FileRef>,
+	pub copied_to_right:Vec<FileRef>,
+	pub deleted_from_left:Vec<FileRef>,
+	pub deleted_from_right:Vec<FileRef>,
+	pub conflicts:Vec<SyncConflict>,
+	pub baseline:DirSnapshot
+}
+
+
+
+impl FileRef {
+
+	/* SYNC METHODS */
+
+	/// Make the target dir mirror this dir. Copies files that are missing or changed in the target and, if enabled in the options, deletes entries in the target that do not exist in this dir. Returns a report of all actions taken.
+	pub fn sync_to(&self, target:&FileRef, options:SyncOptions) -> Result<SyncReport, Box<dyn Error>> {
+		self.sync_to_with_progress(target, options, &NoProgress)
+	}
+
+	/// Make the target dir mirror this dir, reporting the bytes of the source files processed so far to the progress. Unchanged files count as processed once compared. If the progress is cancelled, a `Cancelled` error with the report of the actions taken so far is returned.
+	pub fn sync_to_with_progress(&self, target:&FileRef, options:SyncOptions, progress:&dyn Progress) -> Result<SyncReport, Box<dyn Error>> {
+		self.traced("sync", Some(target), |report:&SyncReport| Some(report.bytes_copied), || self.mirror_to(target, options, progress))
+	}
+
+	/// Make the target dir mirror this dir. Implementation of `sync_to_with_progress`. Entries are told apart by their metadata, so extensionless files and dirs with a dot in their name are synced correctly.
+	fn mirror_to(&self, target:&FileRef, options:SyncOptions, progress:&dyn Progress) -> Result<SyncReport, Box<dyn Error>> {
+		if !self.exists() {
+			return Err(FileRefError::new(FileOperation::Copy, self, "Dir does not exist.").with_target(target).into());
+		} else if !self.is_dir_fs() {
+			return Err(FileRefError::new(FileOperation::Copy, self, "Only able to sync dirs.").with_target(target).into());
+		}
+		self.require_std_file_system(FileOperation::Copy)?;
+		target.require_std_file_system(FileOperation::Copy)?;
+		let source_dir:FileRef = self.clone().absolute().trim_end_matches(SEPARATOR);
+		let target_dir:FileRef = target.clone().absolute().trim_end_matches(SEPARATOR);
+		let mut report:SyncReport = SyncReport::default();
+
+		// Create dirs and copy files that are missing or changed.
+		if !options.dry_run {
+			DirRef::create(&target_dir)?;
+		}
+		let mut source_entries:DirEntries = DirEntries::collect(&source_dir)?;
+		source_entries.files.sort_by(|(left, _), (right, _)| left.path().cmp(right.path()));
+		let mut tracker:ProgressTracker = ProgressTracker::new(progress, source_entries.files.iter().map(|(_, metadata)| metadata.len()).sum());
+		for source_entry in &source_entries.dirs {
+			let target_entry:FileRef = target_dir.clone() + SEPARATOR + source_entry.relative_to_dir(&source_dir).path();
+			if !target_entry.exists() && !options.dry_run {
+				DirRef::create(&target_entry)?;
+			}
+		}
+		for (source_entry, metadata) in &source_entries.files {
+			if tracker.is_cancelled() {
+				return Err(Box::new(Cancelled::new(report)));
+			}
+			let target_entry:FileRef = target_dir.clone() + SEPARATOR + source_entry.relative_to_dir(&source_dir).path();
+			tracker.start_file(source_entry);
+			let is_new:bool = !target_entry.exists();
+			if !is_new && !files_differ(source_entry, &target_entry, options.comparison)? {
+				report.unchanged += 1;
+				tracker.advance(metadata.len());
+				continue;
+			}
+			if !options.dry_run {
+				report.bytes_copied += sync_copy_file(source_entry, &target_entry)?;
+			} else {
+				report.bytes_copied += metadata.len();
+			}
+			tracker.advance(metadata.len());
+			if is_new { report.created.push(target_entry) } else { report.updated.push(target_entry) }
+		}
+
+		// Delete entries that do not exist in the source. Parents are sorted before their contents, so deleted dirs are removed as a whole.
+		if options.delete_extraneous && target_dir.exists() {
+			let relative_path = |entry:&FileRef, dir:&FileRef| -> String { entry.relative_to_dir(dir).path().trim_end_matches(SEPARATOR).to_string() };
+			let source_relative_paths:HashSet<String> = source_entries.dirs.iter().chain(source_entries.files.iter().map(|(file, _)| file)).map(|entry| relative_path(entry, &source_dir)).collect();
+			let target_entries:DirEntries = DirEntries::collect(&target_dir)?;
+			let mut target_paths:Vec<(FileRef, bool)> = target_entries.dirs.into_iter().map(|dir| (dir, true)).chain(target_entries.files.into_iter().map(|(file, _)| (file, false))).collect();
+			target_paths.sort_by(|(left, _), (right, _)| left.path().cmp(right.path()));
+			let mut deleted_dir_prefixes:Vec<String> = Vec::new();
+			for (target_entry, is_dir) in target_paths {
+				if tracker.is_cancelled() {
+					return Err(Box::new(Cancelled::new(report)));
+				}
+				let in_deleted_dir:bool = deleted_dir_prefixes.iter().any(|prefix| target_entry.path().starts_with(prefix.as_str()));
+				if in_deleted_dir || source_relative_paths.contains(&relative_path(&target_entry, &target_dir)) {
+					continue;
+				}
+				if is_dir {
+					deleted_dir_prefixes.push(target_entry.path().trim_end_matches(SEPARATOR).to_owned() + SEPARATOR);
+				}
+				if !options.dry_run {
+					target_entry.delete()?;
+				}
+				report.deleted.push(target_entry);
+			}
+		}
+
+		tracker.finish();
+		Ok(report)
+	}
+
+	/// Synchronize this dir and the other dir in both directions. Changes are detected by comparing both dirs to the baseline snapshot, which is the snapshot returned in the report of the previous two-way sync, or an empty snapshot for the first sync. Files changed on both sides are resolved using the conflict policy. The returned report contains the new baseline snapshot.
+	pub fn sync_two_way(&self, other:&FileRef, baseline:&DirSnapshot, policy:ConflictPolicy) -> Result<TwoWaySyncReport, Box<dyn Error>> {
+		self.traced("sync", Some(other), |_| None, || self.reconcile_with(other, baseline, policy))
+	}
+
+	/// Synchronize this dir and the other dir in both directions. Implementation of `sync_two_way`.
+	fn reconcile_with(&self, other:&FileRef, baseline:&DirSnapshot, policy:ConflictPolicy) -> Result<TwoWaySyncReport, Box<dyn Error>> {
+		if [self, other].iter().any(|dir| dir.exists() && !dir.is_dir_fs()) {
+			return Err(FileRefError::new(FileOperation::Copy, self, "Only able to sync dirs.").with_target(other).into());
+		}
+		self.require_std_file_system(FileOperation::Copy)?;
+		other.require_std_file_system(FileOperation::Copy)?;
+		let left_dir:FileRef = self.clone().absolute().trim_end_matches(SEPARATOR);
+		let right_dir:FileRef = other.clone().absolute().trim_end_matches(SEPARATOR);
+		DirRef::create(&left_dir)?;
+		DirRef::create(&right_dir)?;
+		let left_snapshot:DirSnapshot = left_dir.snapshot()?;
+		let right_snapshot:DirSnapshot = right_dir.snapshot()?;
+		let mut report:TwoWaySyncReport = TwoWaySyncReport::default();
+
+		// Find all files known to either side or the baseline.
+		let relative_paths:BTreeSet<FileRef> = left_snapshot.entries().chain(right_snapshot.entries()).chain(baseline.entries()).map(|(relative_path, _)| relative_path).collect();
+		for relative_path in relative_paths {
+			let left_file:FileRef = left_dir.clone() + SEPARATOR + relative_path.path();
+			let right_file:FileRef = right_dir.clone() + SEPARATOR + relative_path.path();
+			let left:Option<SnapshotEntry> = left_snapshot.get(&relative_path).copied();
+			let right:Option<SnapshotEntry> = right_snapshot.get(&relative_path).copied();
+			let base:Option<SnapshotEntry> = baseline.get(&relative_path).copied();
+
+			// Propagate one-sided changes.
+			match (left != base, right != base) {
+				(false, false) => {},
+				(true, false) => sync_propagate(&left_file, &right_file, &mut report.copied_to_right, &mut report.deleted_from_right)?,
+				(false, true) => sync_propagate(&right_file, &left_file, &mut report.copied_to_left, &mut report.deleted_from_left)?,
+				(true, true) => {
+
+					// Both sides changed to the same state.
+					let identical:bool = match (&left, &right) {
+						(None, None) => true,
+						(Some(_), Some(_)) => !files_differ(&left_file, &right_file, FileComparison::Contents)?,
+						_ => false
+					};
+					if identical {
+						continue;
+					}
+
+					// Resolve conflict.
+					let mut conflict:SyncConflict = SyncConflict { path: relative_path.clone(), left, right, resolution: None };
+					let resolution:ConflictResolution = policy.resolve(&conflict);
+					match resolution {
+						ConflictResolution::KeepLeft => sync_propagate(&left_file, &right_file, &mut report.copied_to_right, &mut report.deleted_from_right)?,
+						ConflictResolution::KeepRight => sync_propagate(&right_file, &left_file, &mut report.copied_to_left, &mut report.deleted_from_left)?,
+						ConflictResolution::KeepBoth => {
+							for (side_name, side_file) in [("left", &left_file), ("right", &right_file)] {
+								if side_file.exists() {
+									let renamed_path:FileRef = sync_conflict_path(&relative_path, side_name);
+									let renamed_left:FileRef = left_dir.clone() + SEPARATOR + renamed_path.path();
+									let renamed_right:FileRef = right_dir.clone() + SEPARATOR + renamed_path.path();
+									sync_copy_file(side_file, &renamed_left)?;
+									sync_copy_file(side_file, &renamed_right)?;
+									report.copied_to_left.push(renamed_left);
+									report.copied_to_right.push(renamed_right);
+								}
+							}
+							for side_file in [&left_file, &right_file] {
+								if side_file.exists() {
+									side_file.delete()?;
+								}
+							}
+						}
+					}
+					conflict.resolution = Some(resolution);
+					report.conflicts.push(conflict);
+				}
+			}
+		}
+
+		// Both sides are equal now, so either can be used as the new baseline.
+		report.baseline = left_dir.snapshot()?;
+		Ok(report)
+	}
+}
+
+
+
+/// Copy a file, giving the target the same modification time as the source so later comparisons see them as equal. Returns the number of bytes copied.
+fn sync_copy_file(source:&FileRef, target:&FileRef) -> Result<u64, Box<dyn Error>> {
+	DirRef::create(&target.parent_dir()?)?;
+	let bytes_copied:u64 = source.copy_to_with_options(target, CopyOptions::new())?;
+	let modified:SystemTime = std::fs::metadata(source.os_path())?.modified()?;
+	File::options().write(true).open(target.os_path())?.set_modified(modified)?;
+	Ok(bytes_copied)
+}
+
+/// Make the target file equal to the source file, copying it if the source exists or deleting the target if it does not.
+fn sync_propagate(source:&FileRef, target:&FileRef, copied:&mut Vec<FileRef>, deleted:&mut Vec<FileRef>) -> Result<(), Box<dyn Error>> {
+	if source.exists() {
+		sync_copy_file(source, target)?;
+		copied.push(target.clone());
+	} else if target.exists() {
+		target.delete()?;
+		deleted.push(target.clone());
+	}
+	Ok(())
+}
+
+/// Get the relative path a conflicting file is renamed to, for example 'dir/file.left.txt'.
+fn sync_conflict_path(relative_path:&FileRef, side_name:&str) -> FileRef {
+	let renamed:String = match relative_path.extension() {
+		Some(extension) => format!("{}.{side_name}.{extension}", relative_path.file_name_no_extension()),
+		None => format!("{}.{side_name}", relative_path.name())
+	};
+	match relative_path.path().rfind(SEPARATOR) {
+		Some(separator_index) => FileRef::new(&(relative_path.path()[..separator_index + 1].to_owned() + &renamed)),
+		None => FileRef::new(&renamed)
+	}
+}
\ No newline at end of file
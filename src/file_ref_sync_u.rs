@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ ConflictPolicy, ConflictResolution, DirSnapshot, FileRef, SyncOptions, SyncReport, TempDirRef, TwoWaySyncReport };
+
+
+
+	fn create_source(temp_dir:&TempDirRef) -> FileRef {
+		let source_dir:FileRef = temp_dir.path() + "/source";
+		(source_dir.clone() + "/file1.txt").write("one").unwrap();
+		(source_dir.clone() + "/sub_dir/file2.txt").write("two").unwrap();
+		(source_dir.clone() + "/empty_dir").create().unwrap();
+		source_dir
+	}
+
+	#[test]
+	fn test_sync_new_target() {
+		let temp_dir:TempDirRef = TempDirRef::new("sync_test_").unwrap();
+		let source_dir:FileRef = create_source(&temp_dir);
+		let target_dir:FileRef = temp_dir.path() + "/target";
+
+		let report:SyncReport = source_dir.sync_to(&target_dir, SyncOptions::new()).unwrap();
+		assert_eq!(report.created.len(), 2);
+		assert_eq!(report.bytes_copied, 6);
+		assert_eq!((target_dir.clone() + "/sub_dir/file2.txt").read().unwrap(), "two");
+		assert!((target_dir.clone() + "/empty_dir").exists());
+	}
+
+	#[test]
+	fn test_sync_unchanged_and_updated() {
+		let temp_dir:TempDirRef = TempDirRef::new("sync_test_").unwrap();
+		let source_dir:FileRef = create_source(&temp_dir);
+		let target_dir:FileRef = temp_dir.path() + "/target";
+		source_dir.sync_to(&target_dir, SyncOptions::new()).unwrap();
+
+		let report:SyncReport = source_dir.sync_to(&target_dir, SyncOptions::new()).unwrap();
+		assert!(report.created.is_empty() && report.updated.is_empty());
+		assert_eq!(report.unchanged, 2);
+
+		(source_dir.clone() + "/file1.txt").write("uno").unwrap();
+		let report:SyncReport = source_dir.sync_to(&target_dir, SyncOptions::new().compare_contents()).unwrap();
+		assert_eq!(report.updated, vec![target_dir.clone().absolute() + "/file1.txt"]);
+		assert_eq!((target_dir.clone() + "/file1.txt").read().unwrap(), "uno");
+	}
+
+	#[test]
+	fn test_sync_delete_extraneous() {
+		let temp_dir:TempDirRef = TempDirRef::new("sync_test_").unwrap();
+		let source_dir:FileRef = create_source(&temp_dir);
+		let target_dir:FileRef = temp_dir.path() + "/target";
+		(target_dir.clone() + "/extra.txt").write("extra").unwrap();
+		(target_dir.clone() + "/extra_dir/file.txt").write("extra").unwrap();
+		std::fs::create_dir((target_dir.clone() + "/extra.d").os_path()).unwrap();
+		(target_dir.clone() + "/extra.d/file.txt").write("extra").unwrap();
+
+		let report:SyncReport = source_dir.sync_to(&target_dir, SyncOptions::new()).unwrap();
+		assert!(report.deleted.is_empty());
+		assert!((target_dir.clone() + "/extra.txt").exists());
+
+		let report:SyncReport = source_dir.sync_to(&target_dir, SyncOptions::new().delete_extraneous()).unwrap();
+		assert_eq!(report.deleted.len(), 3);
+		assert!(!(target_dir.clone() + "/extra.txt").exists());
+		assert!(!(target_dir.clone() + "/extra_dir").exists());
+		assert!(!(target_dir.clone() + "/extra.d").exists());
+		assert!((target_dir.clone() + "/file1.txt").exists());
+	}
+
+	#[test]
+	fn test_sync_extensionless_files_and_dotted_dirs() {
+		let temp_dir:TempDirRef = TempDirRef::new("sync_test_").unwrap();
+		let source_dir:FileRef = create_source(&temp_dir);
+		let target_dir:FileRef = temp_dir.path() + "/target";
+		std::fs::write((source_dir.clone() + "/Makefile").os_path(), "all:").unwrap();
+		std::fs::create_dir((source_dir.clone() + "/lib.v2").os_path()).unwrap();
+		std::fs::write((source_dir.clone() + "/lib.v2/LICENSE").os_path(), "MIT").unwrap();
+
+		let report:SyncReport = source_dir.sync_to(&target_dir, SyncOptions::new()).unwrap();
+		assert_eq!(report.created.len(), 4);
+		assert_eq!(std::fs::read_to_string((target_dir.clone() + "/Makefile").os_path()).unwrap(), "all:");
+		assert_eq!(std::fs::read_to_string((target_dir.clone() + "/lib.v2/LICENSE").os_path()).unwrap(), "MIT");
+
+		std::fs::remove_file((source_dir.clone() + "/Makefile").os_path()).unwrap();
+		let report:SyncReport = source_dir.sync_to(&target_dir, SyncOptions::new().delete_extraneous()).unwrap();
+		assert_eq!(report.deleted, vec![target_dir.clone().absolute() + "/Makefile"]);
+		assert_eq!(report.unchanged, 3);
+	}
+
+	#[test]
+	fn test_sync_dry_run() {
+		let temp_dir:TempDirRef = TempDirRef::new("sync_test_").unwrap();
+		let source_dir:FileRef = create_source(&temp_dir);
+		let target_dir:FileRef = temp_dir.path() + "/target";
+
+		let report:SyncReport = source_dir.sync_to(&target_dir, SyncOptions::new().delete_extraneous().dry_run()).unwrap();
+		assert_eq!(report.created.len(), 2);
+		assert!(!target_dir.exists());
+	}
+
+	fn create_two_way_dirs(temp_dir:&TempDirRef) -> (FileRef, FileRef, DirSnapshot) {
+		let left_dir:FileRef = temp_dir.path() + "/left";
+		let right_dir:FileRef = temp_dir.path() + "/right";
+		(left_dir.clone() + "/shared.txt").write("shared").unwrap();
+		(left_dir.clone() + "/removed.txt").write("removed").unwrap();
+		let report:TwoWaySyncReport = left_dir.sync_two_way(&right_dir, &DirSnapshot::new(), ConflictPolicy::PreferLeft).unwrap();
+		assert_eq!(report.copied_to_right.len(), 2);
+		(left_dir, right_dir, report.baseline)
+	}
+
+	#[test]
+	fn test_sync_two_way_one_sided_changes() {
+		let temp_dir:TempDirRef = TempDirRef::new("sync_test_").unwrap();
+		let (left_dir, right_dir, baseline) = create_two_way_dirs(&temp_dir);
+		(left_dir.clone() + "/new_left.txt").write("left").unwrap();
+		(right_dir.clone() + "/sub_dir/new_right.txt").write("right").unwrap();
+		(right_dir.clone() + "/removed.txt").delete().unwrap();
+
+		let report:TwoWaySyncReport = left_dir.sync_two_way(&right_dir, &baseline, ConflictPolicy::NewerWins).unwrap();
+		assert!(report.conflicts.is_empty());
+		assert_eq!(report.copied_to_left.len(), 1);
+		assert_eq!(report.copied_to_right.len(), 1);
+		assert_eq!(report.deleted_from_left.len(), 1);
+		assert_eq!((right_dir.clone() + "/new_left.txt").read().unwrap(), "left");
+		assert_eq!((left_dir.clone() + "/sub_dir/new_right.txt").read().unwrap(), "right");
+		assert!(!(left_dir.clone() + "/removed.txt").exists());
+		assert_eq!(report.baseline, right_dir.snapshot().unwrap());
+	}
+
+	#[test]
+	fn test_sync_two_way_extensionless_files_and_dotted_dirs() {
+		let temp_dir:TempDirRef = TempDirRef::new("sync_test_").unwrap();
+		let (left_dir, right_dir, baseline) = create_two_way_dirs(&temp_dir);
+		std::fs::write((left_dir.clone() + "/Makefile").os_path(), "all:").unwrap();
+		std::fs::create_dir((right_dir.clone() + "/lib.v2").os_path()).unwrap();
+		std::fs::write((right_dir.clone() + "/lib.v2/LICENSE").os_path(), "MIT").unwrap();
+
+		let report:TwoWaySyncReport = left_dir.sync_two_way(&right_dir, &baseline, ConflictPolicy::NewerWins).unwrap();
+		assert!(report.conflicts.is_empty());
+		assert_eq!(report.copied_to_right, vec![right_dir.clone().absolute() + "/Makefile"]);
+		assert_eq!(report.copied_to_left, vec![left_dir.clone().absolute() + "/lib.v2/LICENSE"]);
+		assert_eq!(std::fs::read_to_string((left_dir.clone() + "/lib.v2/LICENSE").os_path()).unwrap(), "MIT");
+		assert_eq!(report.baseline.len(), 4);
+		assert!(left_dir.sync_two_way(&(left_dir.clone() + "/Makefile"), &report.baseline, ConflictPolicy::NewerWins).is_err());
+	}
+
+	#[test]
+	fn test_sync_two_way_conflict_policies() {
+		let temp_dir:TempDirRef = TempDirRef::new("sync_test_").unwrap();
+		let (left_dir, right_dir, baseline) = create_two_way_dirs(&temp_dir);
+		(left_dir.clone() + "/shared.txt").write("left version").unwrap();
+		(right_dir.clone() + "/shared.txt").write("right version").unwrap();
+
+		let report:TwoWaySyncReport = left_dir.sync_two_way(&right_dir, &baseline, ConflictPolicy::PreferRight).unwrap();
+		assert_eq!(report.conflicts.len(), 1);
+		assert_eq!(report.conflicts[0].path, FileRef::new("shared.txt"));
+		assert_eq!(report.conflicts[0].resolution, Some(ConflictResolution::KeepRight));
+		assert_eq!((left_dir.clone() + "/shared.txt").read().unwrap(), "right version");
+
+		(left_dir.clone() + "/shared.txt").write("left again").unwrap();
+		(right_dir.clone() + "/shared.txt").write("right again").unwrap();
+		let policy:ConflictPolicy = ConflictPolicy::Callback(Box::new(|_| ConflictResolution::KeepLeft));
+		left_dir.sync_two_way(&right_dir, &report.baseline, policy).unwrap();
+		assert_eq!((right_dir.clone() + "/shared.txt").read().unwrap(), "left again");
+	}
+
+	#[test]
+	fn test_sync_two_way_rename_both() {
+		let temp_dir:TempDirRef = TempDirRef::new("sync_test_").unwrap();
+		let (left_dir, right_dir, baseline) = create_two_way_dirs(&temp_dir);
+		(left_dir.clone() + "/shared.txt").write("left version").unwrap();
+		(right_dir.clone() + "/shared.txt").write("right version").unwrap();
+
+		let report:TwoWaySyncReport = left_dir.sync_two_way(&right_dir, &baseline, ConflictPolicy::RenameBoth).unwrap();
+		assert_eq!(report.conflicts.len(), 1);
+		for dir in [&left_dir, &right_dir] {
+			assert!(!(dir.clone() + "/shared.txt").exists());
+			assert_eq!((dir.clone() + "/shared.left.txt").read().unwrap(), "left version");
+			assert_eq!((dir.clone() + "/shared.right.txt").read().unwrap(), "right version");
+		}
+		assert_eq!(report.baseline.len(), 3);
+	}
+
+	#[test]
+	fn test_sync_two_way_identical_changes() {
+		let temp_dir:TempDirRef = TempDirRef::new("sync_test_").unwrap();
+		let (left_dir, right_dir, baseline) = create_two_way_dirs(&temp_dir);
+		(left_dir.clone() + "/shared.txt").write("same change").unwrap();
+		(right_dir.clone() + "/shared.txt").write("same change").unwrap();
+
+		let report:TwoWaySyncReport = left_dir.sync_two_way(&right_dir, &baseline, ConflictPolicy::PreferLeft).unwrap();
+		assert!(report.conflicts.is_empty());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use std::{ error::Error, fs::File, io::{ Read, Write } };
+use flate2::{ Compression, read::GzDecoder, write::GzEncoder };
+use tar::{ Archive, Builder, Entry, HeaderMode };
+use crate::{ DirRef, FileOperation, FileRef, FileRefError, SEPARATOR };
+
+
+
+const GZIP_EXTENSIONS:&[&str] = &["gz", "tgz"];
+
+
+
+impl FileRef {
+
+	/* TAR METHODS */
+
+	/// Check if the file is gzip compressed, based on its extension.
+	fn is_gzip(&self) -> bool {
+		self.extension().map(|extension| GZIP_EXTENSIONS.contains(&extension.to_lowercase().as_str())).unwrap_or(false)
+	}
+
+	/// Write all contents of this dir to a tar archive at the target location. If the target has a '.gz' or '.tgz' extension, the archive is gzip compressed. Permissions are preserved and symlinks are stored as links rather than followed. The target can not be inside this dir, as the archive would end up in itself.
+	pub fn tar_dir_to(&self, target:&FileRef) -> Result<(), Box<dyn Error>> {
+		if !self.exists() {
+			return Err(self.error(FileOperation::Read, "Dir does not exist."));
+		} else if !self.is_dir_fs() {
+			return Err(self.error(FileOperation::Read, "Only able to tar dirs."));
+		}
+		self.require_std_file_system(FileOperation::Read)?;
+		target.require_std_file_system(FileOperation::Write)?;
+		let source_dir:FileRef = self.clone().absolute().normalize();
+		if target.clone().absolute().normalize().starts_with(&(source_dir.path().to_owned() + SEPARATOR)) {
+			return Err(FileRefError::new(FileOperation::Write, self, "Unable to write an archive into the dir being archived.").with_target(target).into());
+		}
+
+		target.guarantee_parent_dir()?;
+		let file:File = File::create(target.os_path())?;
+		let writer:Box<dyn Write> = if target.is_gzip() { Box::new(GzEncoder::new(file, Compression::default())) } else { Box::new(file) };
+		let mut builder:Builder<Box<dyn Write>> = Builder::new(writer);
+		builder.mode(HeaderMode::Complete);
+		builder.follow_symlinks(false);
+		builder.append_dir_all(".", self.os_path())?;
+		builder.into_inner()?.flush()?;
+		Ok(())
+	}
+
+	/// Extract the tar archive into the given dir. Archives with a '.gz' or '.tgz' extension are decompressed with gzip. Permissions and symlinks are restored, entries that would escape the target dir are skipped. Returns the extracted files.
+	pub fn untar_to(&self, target_dir:&FileRef) -> Result<Vec<FileRef>, Box<dyn Error>> {
+		if !self.exists() {
+			return Err(self.error(FileOperation::Read, "File does not exist."));
+		} else if self.is_dir_fs() {
+			return Err(self.error(FileOperation::Read, "Only able to untar files."));
+		}
+		self.require_std_file_system(FileOperation::Read)?;
+		target_dir.require_std_file_system(FileOperation::Write)?;
+
+		DirRef::create(target_dir)?;
+		let file:File = File::open(self.os_path())?;
+		let reader:Box<dyn Read> = if self.is_gzip() { Box::new(GzDecoder::new(file)) } else { Box::new(file) };
+		let mut archive:Archive<Box<dyn Read>> = Archive::new(reader);
+		archive.set_preserve_permissions(true);
+		let mut extracted:Vec<FileRef> = Vec::new();
+		for entry in archive.entries()? {
+			let mut entry:Entry<Box<dyn Read>> = entry?;
+			let relative_path:FileRef = FileRef::new(&entry.path()?.display().to_string()).trim_end_matches(SEPARATOR);
+			let is_file:bool = entry.header().entry_type().is_file();
+			if entry.unpack_in(target_dir.os_path())? && is_file {
+				extracted.push(target_dir.clone() + SEPARATOR + relative_path.path());
+			}
+		}
+		Ok(extracted)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, TempDirRef };
+
+
+
+	fn create_test_dir(temp_dir:&TempDirRef) -> FileRef {
+		let source_dir:FileRef = temp_dir.path() + "/source";
+		(source_dir.clone() + "/file1.txt").write("one").unwrap();
+		(source_dir.clone() + "/sub_dir/file2.txt").write("two").unwrap();
+		source_dir
+	}
+
+	#[test]
+	fn test_tar_roundtrip() {
+		let temp_dir:TempDirRef = TempDirRef::new("tar_test_").unwrap();
+		let source_dir:FileRef = create_test_dir(&temp_dir);
+		let tar_file:FileRef = temp_dir.path() + "/archive.tar";
+		let target_dir:FileRef = temp_dir.path() + "/extracted";
+
+		source_dir.tar_dir_to(&tar_file).unwrap();
+		let mut extracted:Vec<FileRef> = tar_file.untar_to(&target_dir).unwrap();
+		extracted.sort();
+		assert_eq!(extracted, vec![target_dir.clone() + "/file1.txt", target_dir.clone() + "/sub_dir/file2.txt"]);
+		assert_eq!((target_dir.clone() + "/sub_dir/file2.txt").read().unwrap(), "two");
+	}
+
+	#[test]
+	fn test_tar_gz_roundtrip() {
+		let temp_dir:TempDirRef = TempDirRef::new("tar_test_").unwrap();
+		let source_dir:FileRef = create_test_dir(&temp_dir);
+		let tar_file:FileRef = temp_dir.path() + "/archive.tar.gz";
+		let target_dir:FileRef = temp_dir.path() + "/extracted";
+
+		source_dir.tar_dir_to(&tar_file).unwrap();
+		assert_eq!(tar_file.read_range(0, 2).unwrap(), vec![0x1f, 0x8b]);
+		tar_file.untar_to(&target_dir).unwrap();
+		assert_eq!((target_dir.clone() + "/file1.txt").read().unwrap(), "one");
+	}
+
+	#[test]
+	fn test_tar_rejects_target_inside_source() {
+		let temp_dir:TempDirRef = TempDirRef::new("tar_test_").unwrap();
+		let source_dir:FileRef = create_test_dir(&temp_dir);
+		let tar_file:FileRef = source_dir.clone() + "/sub_dir/archive.tar";
+		assert!(source_dir.tar_dir_to(&tar_file).is_err());
+		assert!(!tar_file.exists());
+		assert!(source_dir.tar_dir_to(&(temp_dir.path() + "/source_archive.tar")).is_ok());
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn test_tar_preserves_permissions_and_symlinks() {
+		use std::os::unix::fs::{ PermissionsExt, symlink };
+
+		let temp_dir:TempDirRef = TempDirRef::new("tar_test_").unwrap();
+		let source_dir:FileRef = create_test_dir(&temp_dir);
+		std::fs::set_permissions((source_dir.clone() + "/file1.txt").path(), std::fs::Permissions::from_mode(0o750)).unwrap();
+		symlink("file1.txt", (source_dir.clone() + "/link.txt").path()).unwrap();
+		let tar_file:FileRef = temp_dir.path() + "/archive.tgz";
+		let target_dir:FileRef = temp_dir.path() + "/extracted";
+
+		source_dir.tar_dir_to(&tar_file).unwrap();
+		tar_file.untar_to(&target_dir).unwrap();
+		let mode:u32 = std::fs::metadata((target_dir.clone() + "/file1.txt").path()).unwrap().permissions().mode();
+		assert_eq!(mode & 0o777, 0o750);
+		let link_target = std::fs::read_link((target_dir.clone() + "/link.txt").path()).unwrap();
+		assert_eq!(link_target.display().to_string(), "file1.txt");
+	}
+}
\ No newline at end of file
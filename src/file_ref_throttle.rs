@@ -0,0 +1,180 @@
+use std::{ error::Error, fs::{ File, OpenOptions }, io::{ self, Read, Write }, sync::{ Arc, Mutex, MutexGuard }, thread::sleep, time::{ Duration, Instant } };
+use crate::{ FileOperation, FileRef, FileRefError };
+
+
+
+const THROTTLED_CHUNK_SIZE:u64 = 64 * 1024;
+
+
+
+struct ThrottleState {
+	available:f64,
+	last_refill:Instant
+}
+
+
+
+#[derive(Clone)]
+pub struct IoThrottle {
+	bytes_per_second:u64,
+	state:Arc<Mutex<ThrottleState>>
+}
+impl IoThrottle {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new throttle that limits IO to the given amount of bytes per second. Clones share the same limit, so one throttle can limit multiple concurrent operations together.
+	pub fn new(bytes_per_second:u64) -> IoThrottle {
+		IoThrottle {
+			bytes_per_second: bytes_per_second.max(1),
+			state: Arc::new(Mutex::new(ThrottleState { available: 0.0, last_refill: Instant::now() }))
+		}
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Get the limit in bytes per second.
+	pub fn bytes_per_second(&self) -> u64 {
+		self.bytes_per_second
+	}
+
+	/// Register that the given amount of bytes was transferred, sleeping as long as needed to stay within the limit. At most one second of unused bandwidth is saved up for bursts.
+	pub fn consume(&self, bytes:u64) {
+		let rate:f64 = self.bytes_per_second as f64;
+		let wait:f64 = {
+			let mut state:MutexGuard<ThrottleState> = self.state.lock().unwrap();
+			let now:Instant = Instant::now();
+			state.available = (state.available + now.duration_since(state.last_refill).as_secs_f64() * rate).min(rate) - bytes as f64;
+			state.last_refill = now;
+			-state.available / rate
+		};
+		if wait > 0.0 {
+			sleep(Duration::from_secs_f64(wait));
+		}
+	}
+
+	/// Wrap a reader so reading from it is throttled.
+	pub fn reader<T:Read>(&self, inner:T) -> ThrottledReader<T> {
+		ThrottledReader { inner, throttle: self.clone() }
+	}
+
+	/// Wrap a writer so writing to it is throttled.
+	pub fn writer<T:Write>(&self, inner:T) -> ThrottledWriter<T> {
+		ThrottledWriter { inner, throttle: self.clone() }
+	}
+}
+
+
+
+pub struct ThrottledReader<T:Read> {
+	inner:T,
+	throttle:IoThrottle
+}
+impl<T:Read> ThrottledReader<T> {
+
+	/// Get the wrapped reader back.
+	pub fn into_inner(self) -> T {
+		self.inner
+	}
+}
+impl<T:Read> Read for ThrottledReader<T> {
+	fn read(&mut self, buffer:&mut [u8]) -> io::Result<usize> {
+		let limit:usize = buffer.len().min(THROTTLED_CHUNK_SIZE as usize);
+		let bytes_read:usize = self.inner.read(&mut buffer[..limit])?;
+		self.throttle.consume(bytes_read as u64);
+		Ok(bytes_read)
+	}
+}
+
+
+
+pub struct ThrottledWriter<T:Write> {
+	inner:T,
+	throttle:IoThrottle
+}
+impl<T:Write> ThrottledWriter<T> {
+
+	/// Get the wrapped writer back.
+	pub fn into_inner(self) -> T {
+		self.inner
+	}
+}
+impl<T:Write> Write for ThrottledWriter<T> {
+	fn write(&mut self, data:&[u8]) -> io::Result<usize> {
+		let limit:usize = data.len().min(THROTTLED_CHUNK_SIZE as usize);
+		let bytes_written:usize = self.inner.write(&data[..limit])?;
+		self.throttle.consume(bytes_written as u64);
+		Ok(bytes_written)
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.inner.flush()
+	}
+}
+
+
+
+impl FileRef {
+
+	/* THROTTLED IO METHODS */
+
+	/// Open the file for streaming reads, limited by the throttle.
+	pub fn open_read_throttled(&self, throttle:&IoThrottle) -> Result<ThrottledReader<File>, Box<dyn Error>> {
+		self.require_std_file_system(FileOperation::Read)?;
+		let file:File = File::open(self.os_path()).map_err(|error| self.error(FileOperation::Read, error))?;
+		Ok(throttle.reader(file))
+	}
+
+	/// Create or truncate the file for streaming writes, limited by the throttle. Creates the parent dirs if they do not exist.
+	pub fn open_write_throttled(&self, throttle:&IoThrottle) -> Result<ThrottledWriter<File>, Box<dyn Error>> {
+		self.require_std_file_system(FileOperation::Write)?;
+		self.guarantee_parent_dir()?;
+		let file:File = OpenOptions::new().write(true).create(true).truncate(true).open(self.os_path()).map_err(|error| self.error(FileOperation::Write, error))?;
+		Ok(throttle.writer(file))
+	}
+
+	/// Copy the file to another location, limited by the throttle. Files on the filesystem of the OS are streamed from one open reader to one open writer, files on mounted filesystems are copied in chunks. Returns the number of bytes written.
+	pub fn copy_to_throttled(&self, target:&FileRef, throttle:&IoThrottle) -> Result<u64, Box<dyn Error>> {
+		if self.is_dir() {
+			return Err(self.error(FileOperation::Copy, "Only able to copy files."));
+		} else if !self.exists() {
+			return Err(self.error(FileOperation::Copy, "File does not exist."));
+		}
+		if self.mounted_file_system().is_some() || target.mounted_file_system().is_some() {
+			return self.copy_to_throttled_in_chunks(target, throttle);
+		}
+		self.with_operation_hooks(FileOperation::Copy, Some(target), || {
+			let mut reader:File = File::open(self.os_path()).map_err(|error| self.error(FileOperation::Copy, error))?;
+			let mut writer:ThrottledWriter<File> = target.open_write_throttled(throttle)?;
+			let copy_error = |error:io::Error| -> Box<dyn Error> { FileRefError::new(FileOperation::Copy, self, error).with_target(target).into() };
+			let size:u64 = io::copy(&mut reader, &mut writer).map_err(copy_error)?;
+			writer.flush().map_err(copy_error)?;
+			Ok(size)
+		})
+	}
+
+	/// Copy the file in chunks through the filesystems the files are on, limited by the throttle. Used when either file is on a mounted filesystem, which does not support streaming.
+	fn copy_to_throttled_in_chunks(&self, target:&FileRef, throttle:&IoThrottle) -> Result<u64, Box<dyn Error>> {
+		let size:u64 = self.file_system().size(self.path()).map_err(|error| self.error(FileOperation::Copy, error))?;
+		target.write_bytes(&[])?;
+		let mut offset:u64 = 0;
+		while offset < size {
+			let end:u64 = (offset + THROTTLED_CHUNK_SIZE).min(size);
+			let chunk:Vec<u8> = self.read_range(offset, end)?;
+			target.append_bytes(&chunk)?;
+			throttle.consume(chunk.len() as u64);
+			offset = end;
+		}
+		Ok(size)
+	}
+
+	/// Fail if the file is on a mounted filesystem, for operations that need direct access to the filesystem of the OS, like streaming.
+	pub(crate) fn require_std_file_system(&self, operation:FileOperation) -> Result<(), Box<dyn Error>> {
+		match self.mounted_file_system() {
+			Some(_) => Err(self.error(operation, "Only supported on the filesystem of the OS, not on mounted filesystems.")),
+			None => Ok(())
+		}
+	}
+}
\ No newline at end of file
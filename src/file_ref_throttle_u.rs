@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+	use std::{ fs::File, io::{ Read, Write }, time::{ Duration, Instant } };
+	use crate::{ FileRef, IoThrottle, MemoryFs, TempDirRef, ThrottledWriter };
+
+
+
+	#[test]
+	fn test_throttle_limits_rate() {
+		let throttle:IoThrottle = IoThrottle::new(1_000_000);
+		let start:Instant = Instant::now();
+		for _ in 0..4 {
+			throttle.consume(50_000);
+		}
+		assert!(start.elapsed() >= Duration::from_millis(180));
+	}
+
+	#[test]
+	fn test_throttled_copy() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let source:FileRef = memory_fs.file_ref("source.bin");
+		let target:FileRef = memory_fs.file_ref("backup/target.bin");
+		let data:Vec<u8> = (0..200_000).map(|index| (index % 251) as u8).collect();
+		source.write_bytes(&data).unwrap();
+
+		let start:Instant = Instant::now();
+		assert_eq!(source.copy_to_throttled(&target, &IoThrottle::new(1_000_000)).unwrap(), data.len() as u64);
+		assert!(start.elapsed() >= Duration::from_millis(180));
+		assert_eq!(target.read_bytes().unwrap(), data);
+	}
+
+	#[test]
+	fn test_throttled_copy_streams_on_os_file_system() {
+		let temp_dir:TempDirRef = TempDirRef::new("throttle_copy_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/source.bin";
+		let target:FileRef = temp_dir.path() + "/backup/target.bin";
+		let data:Vec<u8> = (0..200_000).map(|index| (index % 251) as u8).collect();
+		source.write_bytes(&data).unwrap();
+		target.write("old contents that are longer than nothing").unwrap();
+
+		let start:Instant = Instant::now();
+		assert_eq!(source.copy_to_throttled(&target, &IoThrottle::new(1_000_000)).unwrap(), data.len() as u64);
+		assert!(start.elapsed() >= Duration::from_millis(180));
+		assert_eq!(target.read_bytes().unwrap(), data);
+	}
+
+	#[test]
+	fn test_throttled_streams() {
+		let temp_dir:TempDirRef = TempDirRef::new("throttle_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/stream.txt";
+		let throttle:IoThrottle = IoThrottle::new(10_000_000);
+
+		let mut writer:ThrottledWriter<File> = file.open_write_throttled(&throttle).unwrap();
+		writer.write_all(b"throttled contents").unwrap();
+		drop(writer);
+
+		let mut contents:String = String::new();
+		file.open_read_throttled(&throttle).unwrap().read_to_string(&mut contents).unwrap();
+		assert_eq!(contents, "throttled contents");
+	}
+
+	#[test]
+	fn test_streams_not_supported_on_mounted_file_systems() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		assert!(memory_fs.file_ref("file.txt").open_read_throttled(&IoThrottle::new(1)).is_err());
+	}
+}
\ No newline at end of file
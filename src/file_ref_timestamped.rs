@@ -0,0 +1,87 @@
+use std::time::{ Duration, SystemTime, UNIX_EPOCH };
+use crate::{ FileRef, SEPARATOR, now };
+
+
+
+const SECONDS_PER_DAY:u64 = 24 * 60 * 60;
+const TIMESTAMP_LAYOUT:&[u8] = b"0000-00-00T00-00-00";
+
+
+
+impl FileRef {
+
+	/* TIMESTAMPED NAME METHODS */
+
+	/// Get a file in this dir named after the current UTC time, as given by the clock of the thread, like 'backup-2024-06-01T12-30-05.tar.gz'. If that file already exists, a counter is added, like 'backup-2024-06-01T12-30-05-1.tar.gz'. An empty extension creates a name without extension.
+	pub fn timestamped(&self, prefix:&str, extension:&str) -> FileRef {
+		self.timestamped_at(prefix, extension, now())
+	}
+
+	/// Get a file in this dir named after the given UTC time, like 'backup-2024-06-01T12-30-05.tar.gz'. If that file already exists, a counter is added, like 'backup-2024-06-01T12-30-05-1.tar.gz'. An empty extension creates a name without extension.
+	pub fn timestamped_at(&self, prefix:&str, extension:&str, time:SystemTime) -> FileRef {
+		let base_name:String = if prefix.is_empty() { format_name_timestamp(time) } else { format!("{prefix}-{}", format_name_timestamp(time)) };
+		let extension:String = if extension.is_empty() { String::new() } else { format!(".{}", extension.trim_start_matches('.')) };
+		let dir:FileRef = self.clone().trim_end_matches(SEPARATOR);
+		(0..)
+			.map(|counter:usize| if counter == 0 { format!("{base_name}{extension}") } else { format!("{base_name}-{counter}{extension}") })
+			.map(|name| dir.clone() + "/" + &name)
+			.find(|file| !file.exists())
+			.unwrap()
+	}
+
+	/// Parse the timestamp from the name of a file created with `timestamped`. Returns None if the name contains no valid timestamp.
+	pub fn name_timestamp(&self) -> Option<SystemTime> {
+		let name:&[u8] = self.name().as_bytes();
+		name.windows(TIMESTAMP_LAYOUT.len()).find_map(parse_name_timestamp)
+	}
+}
+
+
+
+/// Format a time as a UTC timestamp that is safe to use in file names, like '2024-06-01T12-30-05'.
+fn format_name_timestamp(time:SystemTime) -> String {
+	let seconds:u64 = time.duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or_default();
+	let (year, month, day):(i64, u32, u32) = civil_from_days((seconds / SECONDS_PER_DAY) as i64);
+	let seconds_of_day:u64 = seconds % SECONDS_PER_DAY;
+	format!("{year:04}-{month:02}-{day:02}T{:02}-{:02}-{:02}", seconds_of_day / 3600, seconds_of_day / 60 % 60, seconds_of_day % 60)
+}
+
+/// Parse a timestamp formatted by `format_name_timestamp`. Returns None if the text is not one.
+fn parse_name_timestamp(text:&[u8]) -> Option<SystemTime> {
+	let matches_layout:bool = text.iter().zip(TIMESTAMP_LAYOUT).all(|(character, layout)| if *layout == b'0' { character.is_ascii_digit() } else { character == layout });
+	if !matches_layout {
+		return None;
+	}
+	let number = |start:usize, end:usize| -> u64 { text[start..end].iter().fold(0, |value, digit| value * 10 + (digit - b'0') as u64) };
+	let (year, month, day, hour, minute, second):(u64, u64, u64, u64, u64, u64) = (number(0, 4), number(5, 7), number(8, 10), number(11, 13), number(14, 16), number(17, 19));
+	if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 59 {
+		return None;
+	}
+	let days:i64 = days_from_civil(year as i64, month as u32, day as u32);
+	let seconds:u64 = u64::try_from(days).ok()? * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second;
+	Some(UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+/// Convert days since the unix epoch to a year, month and day.
+pub(crate) fn civil_from_days(days:i64) -> (i64, u32, u32) {
+	let shifted_days:i64 = days + 719468;
+	let era:i64 = shifted_days.div_euclid(146097);
+	let day_of_era:i64 = shifted_days - era * 146097;
+	let year_of_era:i64 = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+	let day_of_year:i64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let shifted_month:i64 = (5 * day_of_year + 2) / 153;
+	let day:u32 = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+	let month:u32 = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+	(year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }, month, day)
+}
+
+/// Convert a year, month and day to days since the unix epoch.
+pub(crate) fn days_from_civil(year:i64, month:u32, day:u32) -> i64 {
+	let year:i64 = if month <= 2 { year - 1 } else { year };
+	let era:i64 = year.div_euclid(400);
+	let year_of_era:i64 = year - era * 400;
+	let shifted_month:i64 = if month > 2 { month as i64 - 3 } else { month as i64 + 9 };
+	let day_of_year:i64 = (153 * shifted_month + 2) / 5 + day as i64 - 1;
+	let day_of_era:i64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	era * 146097 + day_of_era - 719468
+}
\ No newline at end of file
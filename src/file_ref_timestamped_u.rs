@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+	use std::time::{ Duration, SystemTime, UNIX_EPOCH };
+	use crate::{ FileRef, RetentionPolicy, TempDirRef, file_ref_timestamped::{ civil_from_days, days_from_civil } };
+
+
+
+	#[test]
+	fn test_timestamped_name() {
+		let temp_dir:TempDirRef = TempDirRef::new("timestamped_test_").unwrap();
+		let time:SystemTime = UNIX_EPOCH + Duration::from_secs(1717245005);
+
+		let file:FileRef = temp_dir.path().timestamped_at("backup", "tar.gz", time);
+		assert_eq!(file.name(), "backup-2024-06-01T12-30-05.tar.gz");
+		assert_eq!(file.name_timestamp(), Some(time));
+		assert_eq!(temp_dir.path().timestamped_at("", "", time).name(), "2024-06-01T12-30-05");
+
+		file.write("backup").unwrap();
+		let second:FileRef = temp_dir.path().timestamped_at("backup", "tar.gz", time);
+		assert_eq!(second.name(), "backup-2024-06-01T12-30-05-1.tar.gz");
+		assert_eq!(second.name_timestamp(), Some(time));
+	}
+
+	#[test]
+	fn test_name_timestamp_invalid() {
+		assert_eq!(FileRef::new("dir/backup.tar.gz").name_timestamp(), None);
+		assert_eq!(FileRef::new("dir/backup-2024-13-01T12-30-05.tar").name_timestamp(), None);
+		assert_eq!(FileRef::new("2024-06-01T12-30-05/backup.tar").name_timestamp(), None);
+	}
+
+	#[test]
+	fn test_civil_days() {
+		assert_eq!(civil_from_days(0), (1970, 1, 1));
+		assert_eq!(civil_from_days(19875), (2024, 6, 1));
+		assert_eq!(civil_from_days(-1), (1969, 12, 31));
+		for days in [-800000, -1, 0, 59, 11016, 19875, 2932896] {
+			let (year, month, day):(i64, u32, u32) = civil_from_days(days);
+			assert_eq!(days_from_civil(year, month, day), days);
+		}
+	}
+
+	#[test]
+	fn test_name_timestamp_retention() {
+		let temp_dir:TempDirRef = TempDirRef::new("timestamped_retention_test_").unwrap();
+		for day in 1..=3 {
+			temp_dir.path().timestamped_at("log", "txt", UNIX_EPOCH + Duration::from_secs(day * 86400)).write("log").unwrap();
+		}
+
+		let policy:RetentionPolicy = RetentionPolicy::new().keep_last(1).timestamp(|file| file.name_timestamp());
+		policy.apply(&temp_dir.path()).unwrap();
+		assert!((temp_dir.path() + "/log-1970-01-04T00-00-00.txt").exists());
+		assert!(!(temp_dir.path() + "/log-1970-01-02T00-00-00.txt").exists());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::error::Error;
+use crate::{ FileRef, io_metrics::record_io_operation };
+
+
+
+impl FileRef {
+
+	/* TRACING METHODS */
+
+	/// Execute an operation and record it in the IO metrics. The amount is the number of bytes processed, or the number of entries listed for scans. With the 'tracing' feature, the operation runs inside a 'file_ref' span with the operation, path and target, and an event with the amount, the duration and the result is emitted when it finishes. Successful operations are traced at debug level, failures at warn level.
+	#[cfg_attr(not(feature="tracing"), allow(unused_variables))]
+	pub(crate) fn traced<T, U, V>(&self, operation:&'static str, target:Option<&FileRef>, amount:U, action:V) -> Result<T, Box<dyn Error>> where U:FnOnce(&T) -> Option<u64>, V:FnOnce() -> Result<T, Box<dyn Error>> {
+		#[cfg(feature="tracing")]
+		let span:tracing::Span = tracing::debug_span!("file_ref", operation, path = self.path(), target = target.map(|target| target.path()));
+		#[cfg(feature="tracing")]
+		let _entered:tracing::span::Entered = span.enter();
+		#[cfg(feature="tracing")]
+		let start:std::time::Instant = std::time::Instant::now();
+
+		let result:Result<T, Box<dyn Error>> = action();
+		let amount:Option<u64> = result.as_ref().ok().and_then(amount);
+		record_io_operation(operation, amount, result.as_ref().err().map(|error| error.as_ref()));
+
+		#[cfg(feature="tracing")]
+		{
+			let duration_us:u64 = start.elapsed().as_micros() as u64;
+			match &result {
+				Ok(_) if operation == "scan" => tracing::debug!(entries = amount, duration_us, "{operation} completed"),
+				Ok(_) => tracing::debug!(bytes = amount, duration_us, "{operation} completed"),
+				Err(error) => tracing::warn!(duration_us, error = %error, "{operation} failed")
+			}
+		}
+		result
+	}
+}
\ No newline at end of file
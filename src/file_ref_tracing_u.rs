@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+	use std::{ fmt::Debug, sync::{ Arc, Mutex, MutexGuard } };
+	use tracing::{ Event, Metadata, Subscriber, field::{ Field, Visit }, span::{ Attributes, Id, Record } };
+	use crate::{ FileRef, SyncOptions, TempDirRef };
+
+
+
+	/// Collects the fields of all spans and events as 'name=value' strings.
+	#[derive(Clone, Default)]
+	struct RecordingSubscriber {
+		spans:Arc<Mutex<Vec<Vec<String>>>>,
+		events:Arc<Mutex<Vec<Vec<String>>>>
+	}
+	impl Subscriber for RecordingSubscriber {
+		fn enabled(&self, _metadata:&Metadata<'_>) -> bool {
+			true
+		}
+
+		fn new_span(&self, span:&Attributes<'_>) -> Id {
+			let mut fields:FieldRecorder = FieldRecorder::default();
+			span.record(&mut fields);
+			let mut spans:MutexGuard<Vec<Vec<String>>> = self.spans.lock().unwrap();
+			spans.push(fields.0);
+			Id::from_u64(spans.len() as u64)
+		}
+
+		fn record(&self, _span:&Id, _values:&Record<'_>) {}
+
+		fn record_follows_from(&self, _span:&Id, _follows:&Id) {}
+
+		fn event(&self, event:&Event<'_>) {
+			let mut fields:FieldRecorder = FieldRecorder::default();
+			event.record(&mut fields);
+			self.events.lock().unwrap().push(fields.0);
+		}
+
+		fn enter(&self, _span:&Id) {}
+
+		fn exit(&self, _span:&Id) {}
+	}
+
+	#[derive(Default)]
+	struct FieldRecorder(Vec<String>);
+	impl Visit for FieldRecorder {
+		fn record_debug(&mut self, field:&Field, value:&dyn Debug) {
+			self.0.push(format!("{}={:?}", field.name(), value));
+		}
+	}
+
+
+
+	#[test]
+	fn test_traced_operations() {
+		let temp_dir:TempDirRef = TempDirRef::new("tracing_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/source/file.txt";
+		let subscriber:RecordingSubscriber = RecordingSubscriber::default();
+		tracing::subscriber::with_default(subscriber.clone(), || {
+			file.write("contents").unwrap();
+			file.read().unwrap();
+			(temp_dir.path() + "/source").sync_to(&(temp_dir.path() + "/target"), SyncOptions::default()).unwrap();
+			assert!((temp_dir.path() + "/missing.txt").read().is_err());
+		});
+
+		let spans:Vec<Vec<String>> = subscriber.spans.lock().unwrap().clone();
+		let operations:Vec<&String> = spans.iter().map(|fields| &fields[0]).collect();
+		assert_eq!(operations[..2], ["operation=\"write\"", "operation=\"read\""]);
+		assert!(operations.contains(&&"operation=\"scan\"".to_string()));
+		assert!(operations.contains(&&"operation=\"sync\"".to_string()));
+		assert!(spans[0].contains(&format!("path={:?}", file.path())));
+
+		let events:Vec<Vec<String>> = subscriber.events.lock().unwrap().clone();
+		assert!(events[0].contains(&"bytes=8".to_string()));
+		assert!(events[0].iter().any(|field| field.starts_with("duration_us=")));
+		assert!(events.last().unwrap().iter().any(|field| field.starts_with("error=")));
+	}
+}
\ No newline at end of file
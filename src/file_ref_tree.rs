@@ -0,0 +1,95 @@
+use std::fmt::Write;
+use crate::{ FileRef, ResultFilter };
+
+
+
+const SIZE_UNITS:[&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+
+
+/// Options for rendering a dir as a tree.
+pub struct TreeOptions {
+	max_depth:Option<usize>,
+	show_sizes:bool,
+	filter:ResultFilter
+}
+impl Default for TreeOptions {
+	fn default() -> TreeOptions {
+		TreeOptions { max_depth: None, show_sizes: false, filter: Box::new(|_| true) }
+	}
+}
+impl TreeOptions {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create new default tree options. All entries are rendered at any depth, without sizes.
+	pub fn new() -> TreeOptions {
+		TreeOptions::default()
+	}
+
+	/// Return self with a maximum depth. Dirs deeper than the limit are shown, but their contents are not.
+	pub fn max_depth(mut self, max_depth:usize) -> Self {
+		self.max_depth = Some(max_depth);
+		self
+	}
+
+	/// Return self with a setting to show the size of each file.
+	pub fn show_sizes(mut self) -> Self {
+		self.show_sizes = true;
+		self
+	}
+
+	/// Return self with a filter. Only entries the filter returns true for are shown, dirs the filter rejects are skipped with all their contents.
+	pub fn filter<T>(mut self, filter:T) -> Self where T:Fn(&FileRef) -> bool + 'static {
+		self.filter = Box::new(filter);
+		self
+	}
+}
+
+
+
+impl FileRef {
+
+	/* TREE METHODS */
+
+	/// Render the dir and its contents like the 'tree' command, with entries sorted by name. Every line ends in a newline.
+	pub fn tree_string(&self, options:TreeOptions) -> String {
+		let mut output:String = format!("{}\n", self.path());
+		self.write_tree_entries(&options, "", 1, &mut output);
+		output
+	}
+
+	/// Write a line for each entry in the dir, recursing into sub-dirs up to the maximum depth. Entries are told apart by their actual type, and symlinks to dirs are not followed.
+	fn write_tree_entries(&self, options:&TreeOptions, prefix:&str, depth:usize, output:&mut String) {
+		let mut entries:Vec<FileRef> = self.scanner().include_files().include_dirs().collect();
+		entries.retain(|entry| (options.filter)(entry));
+		entries.sort_by(|left, right| left.name().cmp(right.name()));
+		for (index, entry) in entries.iter().enumerate() {
+			let is_last:bool = index + 1 == entries.len();
+			let is_dir:bool = entry.is_dir_fs() && !entry.is_symlink();
+			let _ = write!(output, "{prefix}{}{}", if is_last { "└── " } else { "├── " }, entry.name());
+			if options.show_sizes && !is_dir {
+				if let Ok(size) = entry.file_system().size(entry.path()) {
+					let _ = write!(output, " ({})", format_size(size));
+				}
+			}
+			output.push('\n');
+			if is_dir && options.max_depth.is_none_or(|max_depth| depth < max_depth) {
+				entry.write_tree_entries(options, &format!("{prefix}{}", if is_last { "    " } else { "│   " }), depth + 1, output);
+			}
+		}
+	}
+}
+
+
+
+/// Format a size in bytes for humans, like '512 B' or '1.5 KiB'.
+fn format_size(size:u64) -> String {
+	let mut value:f64 = size as f64;
+	let mut unit_index:usize = 0;
+	while value >= 1024.0 && unit_index + 1 < SIZE_UNITS.len() {
+		value /= 1024.0;
+		unit_index += 1;
+	}
+	if unit_index == 0 { format!("{size} B") } else { format!("{value:.1} {}", SIZE_UNITS[unit_index]) }
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, TempDirRef, TreeOptions };
+
+
+
+	fn create_tree() -> TempDirRef {
+		let temp_dir:TempDirRef = TempDirRef::new("tree_test_").unwrap();
+		for (path, contents) in [("b.txt", "12345"), ("a/x.txt", ""), ("a/deep/y.txt", "y"), ("c.log", "log")] {
+			(temp_dir.path() + "/" + path).write(contents).unwrap();
+		}
+		temp_dir
+	}
+
+
+
+	#[test]
+	fn test_tree_string() {
+		let temp_dir:TempDirRef = create_tree();
+		let expected:String = format!("{}\n├── a\n│   ├── deep\n│   │   └── y.txt\n│   └── x.txt\n├── b.txt\n└── c.log\n", temp_dir.path().path());
+		assert_eq!(temp_dir.path().tree_string(TreeOptions::new()), expected);
+	}
+
+	#[test]
+	fn test_tree_string_options() {
+		let temp_dir:TempDirRef = create_tree();
+		let root:FileRef = temp_dir.path();
+
+		let expected:String = format!("{}\n├── a\n│   ├── deep\n│   └── x.txt\n├── b.txt\n└── c.log\n", root.path());
+		assert_eq!(root.tree_string(TreeOptions::new().max_depth(2)), expected);
+
+		let expected:String = format!("{}\n├── a\n│   └── x.txt (0 B)\n└── b.txt (5 B)\n", root.path());
+		assert_eq!(root.tree_string(TreeOptions::new().show_sizes().filter(|entry| entry.extension() != Some("log") && entry.name() != "deep")), expected);
+	}
+
+	#[test]
+	fn test_tree_string_extensionless_files_and_dotted_dirs() {
+		let temp_dir:TempDirRef = TempDirRef::new("tree_test_").unwrap();
+		std::fs::create_dir((temp_dir.path() + "/lib.v2").os_path()).unwrap();
+		std::fs::write((temp_dir.path() + "/Makefile").os_path(), "all:").unwrap();
+		std::fs::write((temp_dir.path() + "/lib.v2/LICENSE").os_path(), "MIT").unwrap();
+		let expected:String = format!("{}\n├── Makefile (4 B)\n└── lib.v2\n    └── LICENSE (3 B)\n", temp_dir.path().path());
+		assert_eq!(temp_dir.path().tree_string(TreeOptions::new().show_sizes()), expected);
+	}
+}
\ No newline at end of file
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
 	use unit_test_support::TempFile;
-	use crate::FileRef;
+	use crate::{ FileRef, MemoryFs, TempDirRef };
 	
 
 
@@ -26,6 +26,28 @@ mod tests {
 		let fs_path:FileRef = FileRef::new("dir/file.txt");
 		assert_eq!(fs_path.path(), "dir/file.txt");
 	}
+
+	#[test]
+	fn test_inline_path() {
+		let short_path:FileRef = FileRef::new("dir\\sub/../file.txt");
+		assert!(matches!(short_path, FileRef::Inline(_)));
+		assert_eq!(short_path.path(), "dir/file.txt");
+
+		let long_path:FileRef = FileRef::new("some/very/long/dir/structure/with/a/file.txt");
+		assert!(matches!(long_path, FileRef::Owned(_)));
+		assert_eq!(long_path.path(), "some/very/long/dir/structure/with/a/file.txt");
+
+		// Ordering only depends on the path, not on how it is stored.
+		assert!(FileRef::new_const("top.txt") > long_path);
+		assert!(short_path < long_path);
+	}
+
+	#[test]
+	#[cfg(target_pointer_width="64")]
+	fn test_file_ref_size() {
+		assert_eq!(std::mem::size_of::<FileRef>(), 24);
+		assert_eq!(std::mem::size_of::<FileRef>(), std::mem::size_of::<String>());
+	}
 	
 	#[test]
 	fn test_messy_path() {
@@ -41,6 +63,39 @@ mod tests {
 		assert!(fs_path.path().contains(":"), "Did not correctly create absolute path");
 	}
 
+	#[test]
+	fn test_is_absolute_path() {
+		assert!(FileRef::new("/dir/file.txt").is_absolute_path());
+		assert!(FileRef::new("C:/dir/file.txt").is_absolute_path());
+		assert!(!FileRef::new("dir/file.txt").is_absolute_path());
+		assert!(!FileRef::new("./dir/file.txt").is_absolute_path());
+	}
+
+	#[test]
+	fn test_to_absolute() {
+		let working_dir:FileRef = FileRef::working_dir();
+		assert_eq!(FileRef::new("dir/../file.txt").to_absolute(), working_dir.join("file.txt").unwrap());
+		assert_eq!(FileRef::new("/dir/file.txt").to_absolute().path(), "/dir/file.txt");
+	}
+
+	#[test]
+	fn test_canonicalize() {
+		let temp_dir:TempDirRef = TempDirRef::new("canonicalize_test_").unwrap();
+		let file:FileRef = temp_dir.path().join("dir/file.txt").unwrap();
+		file.write("contents").unwrap();
+		let canonical:FileRef = file.canonicalize().unwrap();
+		assert!(canonical.is_absolute_path());
+		assert_eq!(temp_dir.path().join("dir/sub/../file.txt").unwrap().canonicalize().unwrap(), canonical);
+		#[cfg(unix)]
+		{
+			std::os::unix::fs::symlink(file.path(), temp_dir.path().join("link.txt").unwrap().path()).unwrap();
+			assert_eq!(temp_dir.path().join("link.txt").unwrap().canonicalize().unwrap(), canonical);
+		}
+
+		let error:Box<dyn std::error::Error> = temp_dir.path().join("missing.txt").unwrap().canonicalize().unwrap_err();
+		assert!(error.downcast_ref::<crate::FileRefError>().unwrap().is_not_found());
+	}
+
 	#[test]
 	fn test_path_to_relative() {
 		let path:String = std::env::current_dir().unwrap().display().to_string() + "/dir/file.txt";
@@ -72,6 +127,113 @@ mod tests {
 	fn test_last_node() {
 		let fs_path:FileRef = FileRef::new("dir/subdir/file.txt");
 		assert_eq!(fs_path.last_node(), "file.txt");
+		assert_eq!(FileRef::new("file.txt").last_node(), "file.txt");
+	}
+
+	#[test]
+	fn test_depth() {
+		assert_eq!(FileRef::new("dir/subdir/file.txt").depth(), 3);
+		assert_eq!(FileRef::new("file.txt").depth(), 1);
+		assert_eq!(FileRef::new("/dir").depth(), 2);
+	}
+
+	#[test]
+	fn test_collect_segments() {
+		let fs_path:FileRef = ["dir", "sub dir", "file.txt"].into_iter().collect();
+		assert_eq!(fs_path.path(), "dir/sub dir/file.txt");
+
+		let sanitized:FileRef = fs_path.path_nodes().iter().map(|node| node.replace(' ', "_")).collect();
+		assert_eq!(sanitized.path(), "dir/sub_dir/file.txt");
+
+		let absolute:FileRef = FileRef::new("/home/user").path_nodes().into_iter().collect();
+		assert_eq!(absolute.path(), "/home/user");
+	}
+
+	#[test]
+	fn test_segments() {
+		let fs_path:FileRef = FileRef::new("static/assets/img/logo.png");
+		assert_eq!(fs_path.segment(0), Some("static"));
+		assert_eq!(fs_path.segment(3), Some("logo.png"));
+		assert_eq!(fs_path.segment(4), None);
+		assert_eq!(FileRef::new("/dir").segment(0), Some(""));
+
+		assert_eq!(fs_path.segments_range(1..3).path(), "assets/img");
+		assert_eq!(fs_path.segments_range(1..).path(), "assets/img/logo.png");
+		assert_eq!(fs_path.segments_range(..=1).path(), "static/assets");
+		assert_eq!(fs_path.segments_range(2..10).path(), "img/logo.png");
+		assert_eq!(fs_path.segments_range(4..).path(), "");
+
+		let (root, rest):(&str, FileRef) = fs_path.split_first_segment().unwrap();
+		assert_eq!(root, "static");
+		assert_eq!(rest.path(), "assets/img/logo.png");
+		assert!(FileRef::new("logo.png").split_first_segment().is_none());
+	}
+
+	#[test]
+	fn test_segments_range_bounds() {
+		use std::ops::Bound;
+
+		let fs_path:FileRef = FileRef::new("static/assets/logo.png");
+		assert_eq!(fs_path.segments_range(..=usize::MAX).path(), "static/assets/logo.png");
+		assert_eq!(fs_path.segments_range(1..=usize::MAX).path(), "assets/logo.png");
+		assert_eq!(fs_path.segments_range((Bound::Excluded(0), Bound::Unbounded)).path(), "assets/logo.png");
+		assert_eq!(fs_path.segments_range((Bound::Excluded(usize::MAX), Bound::Unbounded)).path(), "");
+		assert_eq!(fs_path.segments_range((Bound::Excluded(usize::MAX), Bound::Included(usize::MAX))).path(), "");
+	}
+
+	#[test]
+	fn test_join() {
+		let dir:FileRef = FileRef::new("/home/user/");
+		assert_eq!(dir.join("file.txt").unwrap().path(), "/home/user/file.txt");
+		assert_eq!(dir.join("docs//notes.txt").unwrap().path(), "/home/user/docs/notes.txt");
+		assert_eq!(dir.join("../other").unwrap().path(), "/home/other");
+		assert_eq!(dir.join("").unwrap().path(), "/home/user/");
+		assert_eq!(FileRef::new("").join("file.txt").unwrap().path(), "file.txt");
+		assert_eq!(FileRef::new("/").join("file.txt").unwrap().path(), "/file.txt");
+		assert_eq!(FileRef::new("dir").join_ref(&FileRef::new("sub\\file.txt")).unwrap().path(), "dir/sub/file.txt");
+	}
+
+	#[test]
+	fn test_join_absolute() {
+		let error:Box<dyn std::error::Error> = FileRef::new("/home/user").join("/etc/passwd").unwrap_err();
+		assert!(error.to_string().contains("Only able to join relative paths"));
+		assert!(FileRef::new("dir").join_ref(&FileRef::new("C:/Windows")).is_err());
+	}
+
+	#[test]
+	fn test_relative_to() {
+		let root:FileRef = FileRef::new("/home/user/project");
+		assert_eq!(FileRef::new("/home/user/project/src/lib.rs").relative_to(&root).unwrap().path(), "src/lib.rs");
+		assert_eq!(FileRef::new("/home/user/notes.txt").relative_to(&root).unwrap().path(), "../notes.txt");
+		assert_eq!(FileRef::new("/etc/hosts").relative_to(&(root.clone() + "/")).unwrap().path(), "../../../etc/hosts");
+		assert_eq!(root.relative_to(&root).unwrap().path(), ".");
+		assert_eq!(FileRef::new("a/b/c.txt").relative_to(&FileRef::new("a/d")).unwrap().path(), "../b/c.txt");
+		assert_eq!(FileRef::new("../shared/c.txt").relative_to(&FileRef::new("../shared")).unwrap().path(), "c.txt");
+		assert_eq!(FileRef::new("C:/data/file.txt").relative_to(&FileRef::new("c:/data")).unwrap().path(), "file.txt");
+
+		assert!(FileRef::new("src/lib.rs").relative_to(&root).is_err());
+		assert!(FileRef::new("C:/data/file.txt").relative_to(&FileRef::new("D:/data")).is_err());
+		assert!(FileRef::new("file.txt").relative_to(&FileRef::new("..")).is_err());
+	}
+
+	#[test]
+	fn test_normalize() {
+		assert_eq!(FileRef::new("dir/./sub/../file.txt").normalize().path(), "dir/file.txt");
+		assert_eq!(FileRef::new("dir\\\\sub/").normalize().path(), "dir/sub");
+		assert_eq!(FileRef::new("../../dir/..").normalize().path(), "../..");
+		assert_eq!(FileRef::new("dir/..").normalize().path(), ".");
+		assert_eq!(FileRef::new("/..").normalize().path(), "/");
+		assert_eq!(FileRef::new("/dir/../../file.txt").normalize().path(), "/file.txt");
+		assert_eq!(FileRef::new("C:/../dir/").normalize().path(), "C:/dir");
+		assert_eq!(FileRef::new("dir//sub/.").normalize(), FileRef::new("dir/sub/file.txt/..").normalize());
+	}
+
+	#[test]
+	fn test_parent_dir_segments() {
+		assert_eq!(FileRef::new("/dir/file.txt").parent_dir().unwrap().path(), "/dir");
+		assert_eq!(FileRef::new("/file.txt").parent_dir().unwrap().path(), "");
+		assert_eq!(FileRef::new("../..").parent_dir().unwrap().path(), "../../..");
+		assert_eq!(FileRef::new("dir/file.tar.gz").extension(), Some("gz"));
 	}
 
 	#[test]
@@ -161,6 +323,53 @@ mod tests {
 		assert_eq!(repeated.path(), "file_file_file_");
 	}
 
+	#[test]
+	fn test_current_dir() {
+		assert_eq!(FileRef::current_dir().unwrap(), FileRef::working_dir());
+	}
+
+	#[test]
+	fn test_current_exe() {
+		let exe:FileRef = FileRef::current_exe().unwrap();
+		assert!(std::path::Path::new(exe.path()).is_file());
+		assert_eq!(FileRef::exe_dir().unwrap(), exe.parent_dir().unwrap());
+	}
+
+	#[test]
+	#[cfg(unix)]
+	fn test_which() {
+		assert!(FileRef::which("sh").is_some_and(|shell| shell.name() == "sh"));
+		assert!(FileRef::which("definitely_not_an_installed_program").is_none());
+	}
+
+	#[test]
+	#[cfg(unix)]
+	fn test_which_direct_path() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let temp_file:TempFile = TempFile::new(Some("sh"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+		temp_file_ref.write("#!/bin/sh").unwrap();
+		assert!(FileRef::which(temp_file.path()).is_none());
+
+		std::fs::set_permissions(temp_file.path(), std::fs::Permissions::from_mode(0o755)).unwrap();
+		assert_eq!(FileRef::which(temp_file.path()), Some(temp_file_ref));
+	}
+
+	#[test]
+	fn test_is_dir_fs() {
+		let temp_dir:TempDirRef = TempDirRef::new("is_dir_fs_test_").unwrap();
+		let dotted_dir:FileRef = temp_dir.path().join("backup.2024").unwrap();
+		let extensionless_file:FileRef = temp_dir.path().join("Makefile").unwrap();
+		std::fs::create_dir(dotted_dir.path()).unwrap();
+		std::fs::write(extensionless_file.path(), "all:").unwrap();
+
+		assert!(dotted_dir.is_file() && dotted_dir.is_dir_fs() && !dotted_dir.is_file_fs());
+		assert!(extensionless_file.is_dir() && extensionless_file.is_file_fs() && !extensionless_file.is_dir_fs());
+		assert!(!temp_dir.path().join("missing").unwrap().is_dir_fs());
+		assert!(!temp_dir.path().join("missing.txt").unwrap().is_file_fs());
+	}
+
 
 
 	/* FILE MODIFICATION TESTS */
@@ -188,6 +397,22 @@ mod tests {
 		assert_eq!(content, read_content);
 	}
 
+	#[test]
+	fn test_read_or_default() {
+		let temp_file:TempFile = TempFile::new(Some("txt"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+		assert_eq!(temp_file_ref.read_or("fallback").unwrap(), "fallback");
+		assert_eq!(temp_file_ref.read_or_default().unwrap(), "");
+		assert_eq!(temp_file_ref.read_or_else(|| "generated".to_string()).unwrap(), "generated");
+
+		temp_file_ref.write("stored").unwrap();
+		assert_eq!(temp_file_ref.read_or("fallback").unwrap(), "stored");
+
+		// Errors other than a missing file are not replaced by the default.
+		temp_file_ref.write_bytes(&[0xFF, 0xFE]).unwrap();
+		assert!(temp_file_ref.read_or_default().is_err());
+	}
+
 	#[test]
 	fn test_file_write_bytes_and_read_bytes() {
 		let temp_file:TempFile = TempFile::new(Some("txt"));
@@ -280,4 +505,157 @@ mod tests {
 
 		target_file_ref.delete().unwrap();
 	}
+
+	#[test]
+	fn test_copy_and_move_into() {
+		let temp_dir:TempDirRef = TempDirRef::new("copy_into_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/report.txt";
+		source.write("report").unwrap();
+
+		let copy:FileRef = source.copy_into(&(temp_dir.path() + "/archive/2024.v1")).unwrap();
+		assert_eq!(copy.path(), (temp_dir.path() + "/archive/2024.v1/report.txt").path());
+		assert_eq!(copy.read().unwrap(), "report");
+		assert!(source.exists());
+
+		let moved:FileRef = source.move_into(&(temp_dir.path() + "/outbox")).unwrap();
+		assert_eq!(moved.read().unwrap(), "report");
+		assert!(!source.exists());
+		assert!(source.move_into(&(temp_dir.path() + "/outbox")).is_err());
+	}
+
+	#[test]
+	#[cfg(unix)]
+	fn test_copy_to_keeps_permissions() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let temp_dir:TempDirRef = TempDirRef::new("copy_permissions_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/run.sh";
+		source.write("#!/bin/sh").unwrap();
+		std::fs::set_permissions(source.path(), std::fs::Permissions::from_mode(0o755)).unwrap();
+
+		let target:FileRef = temp_dir.path() + "/bin/run.sh";
+		source.copy_to(&target).unwrap();
+		assert_eq!(std::fs::metadata(target.path()).unwrap().permissions().mode() & 0o777, 0o755);
+	}
+
+	#[test]
+	fn test_move_to() {
+		let temp_dir:TempDirRef = TempDirRef::new("move_to_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/report.txt";
+		source.write("report").unwrap();
+
+		let target:FileRef = temp_dir.path() + "/archive/2024/report.txt";
+		source.move_to(&target).unwrap();
+		assert_eq!(target.read().unwrap(), "report");
+		assert!(!source.exists());
+		assert!(source.move_to(&target).is_err());
+
+		let dir:FileRef = temp_dir.path() + "/archive";
+		let moved_dir:FileRef = temp_dir.path() + "/old/archive";
+		dir.move_to(&moved_dir).unwrap();
+		assert_eq!((moved_dir.clone() + "/2024/report.txt").read().unwrap(), "report");
+		assert!(!dir.exists());
+
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let mounted:FileRef = memory_fs.file_ref("notes.txt");
+		mounted.write("notes").unwrap();
+		let local:FileRef = temp_dir.path() + "/local/notes.txt";
+		mounted.move_to(&local).unwrap();
+		assert_eq!(local.read().unwrap(), "notes");
+		assert!(!mounted.exists());
+
+		memory_fs.file_ref("docs/readme.md").write("readme").unwrap();
+		memory_fs.file_ref("docs").move_to(&(temp_dir.path() + "/docs")).unwrap();
+		assert_eq!((temp_dir.path() + "/docs/readme.md").read().unwrap(), "readme");
+		assert!(!memory_fs.file_ref("docs").exists());
+	}
+
+	#[test]
+	fn test_rename_to() {
+		let temp_dir:TempDirRef = TempDirRef::new("rename_to_test_").unwrap();
+		let source:FileRef = temp_dir.path() + "/draft.txt";
+		source.write("draft").unwrap();
+
+		let renamed:FileRef = source.rename_to("final.txt").unwrap();
+		assert_eq!(renamed.path(), (temp_dir.path() + "/final.txt").path());
+		assert_eq!(renamed.read().unwrap(), "draft");
+		assert!(!source.exists());
+		assert!(renamed.rename_to("dir/final.txt").is_err());
+		assert!(renamed.rename_to("").is_err());
+	}
+
+	#[test]
+	fn test_write_atomic() {
+		let temp_dir:TempDirRef = TempDirRef::new("write_atomic_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/config/settings.toml";
+		file.write_atomic(b"key = 1").unwrap();
+		assert_eq!(file.read().unwrap(), "key = 1");
+
+		#[cfg(unix)]
+		file.set_mode(0o600).unwrap();
+		file.write_atomic(b"key = 2").unwrap();
+		assert_eq!(file.read().unwrap(), "key = 2");
+		#[cfg(unix)]
+		assert_eq!(file.mode().unwrap(), 0o600);
+		assert_eq!(std::fs::read_dir((temp_dir.path() + "/config").path()).unwrap().count(), 1);
+		assert!((temp_dir.path() + "/config").write_atomic(b"").is_err());
+
+		let memory_fs:MemoryFs = MemoryFs::new();
+		memory_fs.file_ref("settings.toml").write_atomic(b"key = 1").unwrap();
+		assert_eq!(memory_fs.file_ref("settings.toml").read().unwrap(), "key = 1");
+	}
+
+	#[test]
+	fn test_preallocate() {
+		let temp_file:TempFile = TempFile::new(Some("bin"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+
+		temp_file_ref.preallocate(4096).unwrap();
+		assert_eq!(std::fs::metadata(temp_file_ref.path()).unwrap().len(), 4096);
+
+		// Never shrinks the file.
+		temp_file_ref.write_bytes(&[1; 8192]).unwrap();
+		temp_file_ref.preallocate(100).unwrap();
+		assert_eq!(std::fs::metadata(temp_file_ref.path()).unwrap().len(), 8192);
+		assert!(temp_file_ref.read_bytes().unwrap().iter().all(|byte| *byte == 1));
+		temp_file_ref.preallocate(0).unwrap();
+		assert_eq!(std::fs::metadata(temp_file_ref.path()).unwrap().len(), 8192);
+
+		// Preallocating nothing still creates the file.
+		let empty_file:TempFile = TempFile::new(Some("bin"));
+		let empty_file_ref:FileRef = FileRef::new(empty_file.path());
+		empty_file_ref.preallocate(0).unwrap();
+		assert_eq!(std::fs::metadata(empty_file_ref.path()).unwrap().len(), 0);
+	}
+
+
+
+	/* FILE LOCKING TESTS */
+
+	#[test]
+	fn test_lock_exclusive() {
+		let temp_file:TempFile = TempFile::new(Some("lock"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+
+		let lock = temp_file_ref.lock_exclusive().unwrap();
+		assert!(temp_file_ref.exists());
+		assert!(temp_file_ref.try_lock().unwrap().is_none());
+
+		drop(lock);
+		assert!(temp_file_ref.try_lock().unwrap().is_some());
+	}
+
+	#[test]
+	fn test_lock_shared() {
+		let temp_file:TempFile = TempFile::new(Some("lock"));
+		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
+
+		let first_lock = temp_file_ref.lock_shared().unwrap();
+		let second_lock = temp_file_ref.lock_shared().unwrap();
+		assert!(temp_file_ref.try_lock().unwrap().is_none());
+
+		drop(first_lock);
+		drop(second_lock);
+		assert!(temp_file_ref.try_lock().unwrap().is_some());
+	}
 }
\ No newline at end of file
@@ -0,0 +1,45 @@
+use std::{ error::Error, path::PathBuf };
+use url::Url;
+use crate::{ FileOperation, FileRef };
+
+
+
+const FILE_SCHEME:&str = "file";
+
+
+
+impl TryFrom<Url> for FileRef {
+	type Error = Box<dyn Error>;
+
+	fn try_from(url:Url) -> Result<FileRef, Box<dyn Error>> {
+		FileRef::try_from(&url)
+	}
+}
+impl TryFrom<&Url> for FileRef {
+	type Error = Box<dyn Error>;
+
+	fn try_from(url:&Url) -> Result<FileRef, Box<dyn Error>> {
+		if url.scheme() != FILE_SCHEME {
+			return Err(format!("Could not convert url \"{url}\" to a file. Only urls with the file scheme are supported.").into());
+		}
+		let path:PathBuf = url.to_file_path().map_err(|_| format!("Could not convert url \"{url}\" to a file. The url does not point to a path on this system."))?;
+		let path:&str = path.to_str().ok_or_else(|| format!("Could not convert url \"{url}\" to a file. The path is not valid UTF-8."))?;
+		Ok(FileRef::new(path))
+	}
+}
+impl TryFrom<&FileRef> for Url {
+	type Error = Box<dyn Error>;
+
+	fn try_from(file:&FileRef) -> Result<Url, Box<dyn Error>> {
+		file.require_std_file_system(FileOperation::Resolve)?;
+		let file:FileRef = file.clone().absolute();
+		Url::from_file_path(file.os_path()).map_err(|_| file.error(FileOperation::Resolve, "Only absolute paths on the local filesystem can be converted to urls."))
+	}
+}
+impl TryFrom<FileRef> for Url {
+	type Error = Box<dyn Error>;
+
+	fn try_from(file:FileRef) -> Result<Url, Box<dyn Error>> {
+		Url::try_from(&file)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+	use url::Url;
+	use crate::FileRef;
+
+
+
+	#[test]
+	fn test_url_to_file_ref() {
+		#[cfg(unix)]
+		{
+			let file:FileRef = FileRef::try_from(Url::parse("file:///home/user/my%20file%23.txt").unwrap()).unwrap();
+			assert_eq!(file.path(), "/home/user/my file#.txt");
+
+			let file:FileRef = FileRef::try_from(Url::parse("file://localhost/home/user/file.txt").unwrap()).unwrap();
+			assert_eq!(file.path(), "/home/user/file.txt");
+		}
+		#[cfg(windows)]
+		{
+			let file:FileRef = FileRef::try_from(Url::parse("file://localhost/C:/Users/my%20file%23.txt").unwrap()).unwrap();
+			assert_eq!(file.path(), "C:/Users/my file#.txt");
+
+			let file:FileRef = FileRef::try_from(Url::parse("file:///c|/dir/file.txt").unwrap()).unwrap();
+			assert_eq!(file.path(), "c:/dir/file.txt");
+		}
+	}
+
+	#[test]
+	fn test_url_to_file_ref_rejects_other_urls() {
+		assert!(FileRef::try_from(Url::parse("https://example.com/file.txt").unwrap()).is_err());
+		#[cfg(unix)]
+		assert!(FileRef::try_from(Url::parse("file://server/share/file.txt").unwrap()).is_err());
+	}
+
+	#[test]
+	fn test_file_ref_to_url() {
+		#[cfg(unix)]
+		{
+			let url:Url = Url::try_from(&FileRef::new("/home/user/my file#1 100%.txt")).unwrap();
+			assert_eq!(url.as_str(), "file:///home/user/my%20file%231%20100%25.txt");
+			assert_eq!(FileRef::try_from(url).unwrap().path(), "/home/user/my file#1 100%.txt");
+		}
+		#[cfg(windows)]
+		{
+			let url:Url = Url::try_from(FileRef::new("C:\\Users\\file.txt")).unwrap();
+			assert_eq!(url.as_str(), "file:///C:/Users/file.txt");
+			assert_eq!(FileRef::try_from(url).unwrap().path(), "C:/Users/file.txt");
+		}
+
+		assert!(Url::try_from(&FileRef::new("remote:/dir/file.txt")).is_err());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use std::{ error::Error, fs::OpenOptions, io::ErrorKind };
+use uuid::Uuid;
+use crate::{ DirRef, FileOperation, FileRef };
+
+
+
+const UUID_NAME_ATTEMPTS:usize = 8;
+
+
+
+impl FileRef {
+
+	/* UUID NAME METHODS */
+
+	/// Create a new empty file with a random UUID name in the given dir, creating the dir if it does not exist. The file is created exclusively, so concurrent callers never receive the same file. An empty extension creates a name without extension.
+	pub fn new_uuid_in(dir:&FileRef, extension:&str) -> Result<FileRef, Box<dyn Error>> {
+		let dir:DirRef = DirRef::create(dir)?;
+		let extension:String = if extension.is_empty() { String::new() } else { format!(".{}", extension.trim_start_matches('.')) };
+		for _ in 0..UUID_NAME_ATTEMPTS {
+			let file:FileRef = dir.child(&format!("{}{extension}", Uuid::new_v4()));
+			if file.create_exclusive()? {
+				return Ok(file);
+			}
+		}
+		Err(dir.file_ref().error(FileOperation::Create, "Could not find an unused UUID name."))
+	}
+
+	/// Create the file if it does not exist yet. Returns false if it already existed. Only atomic on the filesystem of the OS.
+	fn create_exclusive(&self) -> Result<bool, Box<dyn Error>> {
+		match self.mounted_file_system() {
+			Some(file_system) => {
+				if file_system.exists(self.path()) {
+					return Ok(false);
+				}
+				file_system.create(self.path(), false).map_err(|error| self.io_failure(FileOperation::Create, error))?;
+				Ok(true)
+			},
+			None => match OpenOptions::new().write(true).create_new(true).open(self.os_path()) {
+				Ok(_) => Ok(true),
+				Err(error) if error.kind() == ErrorKind::AlreadyExists => Ok(false),
+				Err(error) => Err(self.io_failure(FileOperation::Create, Box::new(error)))
+			}
+		}
+	}
+}
\ No newline at end of file
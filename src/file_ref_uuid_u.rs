@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+	use std::collections::HashSet;
+	use crate::{ FileRef, TempDirRef };
+
+
+
+	#[test]
+	fn test_new_uuid_in() {
+		let temp_dir:TempDirRef = TempDirRef::new("uuid_test_").unwrap();
+		let staging:FileRef = temp_dir.path() + "/staging";
+
+		let file:FileRef = FileRef::new_uuid_in(&staging, "part").unwrap();
+		assert!(file.exists());
+		assert_eq!(file.extension(), Some("part"));
+		assert_eq!(file.name().len(), 36 + ".part".len());
+		assert_eq!(file.read().unwrap(), "");
+
+		let names:HashSet<String> = (0..20).map(|_| FileRef::new_uuid_in(&staging, "").unwrap().name().to_string()).collect();
+		assert_eq!(names.len(), 20);
+		assert!(names.iter().all(|name| !name.contains('.')));
+	}
+}
\ No newline at end of file
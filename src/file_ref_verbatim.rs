@@ -0,0 +1,53 @@
+use std::{ borrow::Cow, path::PathBuf };
+use crate::{ FileRef, SEPARATOR };
+
+
+
+const VERBATIM_PREFIX:&str = "\\\\?\\";
+const VERBATIM_UNC_PREFIX:&str = "\\\\?\\UNC\\";
+#[cfg(windows)]
+const LONG_PATH_LENGTH:usize = 248; // MAX_PATH minus the room Windows keeps for an 8.3 file name when creating dirs.
+
+
+
+impl FileRef {
+
+	/* VERBATIM PATH METHODS */
+
+	/// Get the path in the verbatim form Windows uses to lift the MAX_PATH limit, like '\\?\C:\dir\file.txt'. Relative paths are made absolute first. Paths without a drive, like unix paths, are returned unchanged.
+	pub fn to_verbatim(&self) -> String {
+		let absolute:FileRef = self.clone().absolute();
+		let path:&str = absolute.path();
+		let bytes:&[u8] = path.as_bytes();
+		if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+			format!("{VERBATIM_PREFIX}{}", path.replace(SEPARATOR, "\\"))
+		} else {
+			path.to_string()
+		}
+	}
+
+	/// Get the path to hand to the OS. On Windows, paths too long for MAX_PATH are given in verbatim form.
+	pub(crate) fn os_path(&self) -> PathBuf {
+		os_path(self.path())
+	}
+}
+
+
+
+/// Get the path to hand to the OS. On Windows, paths too long for MAX_PATH are given in verbatim form.
+pub(crate) fn os_path(path:&str) -> PathBuf {
+	#[cfg(windows)]
+	if path.len() >= LONG_PATH_LENGTH {
+		return PathBuf::from(FileRef::new(path).to_verbatim());
+	}
+	PathBuf::from(path)
+}
+
+/// Remove the verbatim prefix from a path returned by the OS, like '\\?\C:\dir' or '\\?\UNC\server\share'.
+pub(crate) fn strip_verbatim_prefix(path:&str) -> Cow<'_, str> {
+	if let Some(unc_path) = path.strip_prefix(VERBATIM_UNC_PREFIX) {
+		Cow::Owned(format!("\\\\{unc_path}"))
+	} else {
+		path.strip_prefix(VERBATIM_PREFIX).map(Cow::Borrowed).unwrap_or(Cow::Borrowed(path))
+	}
+}
\ No newline at end of file
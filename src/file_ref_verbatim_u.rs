@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, file_ref_verbatim::strip_verbatim_prefix };
+
+
+
+	#[test]
+	fn test_to_verbatim() {
+		assert_eq!(FileRef::new("C:/dir/file.txt").to_verbatim(), "\\\\?\\C:\\dir\\file.txt");
+		assert_eq!(FileRef::new("/home/user/file.txt").to_verbatim(), "/home/user/file.txt");
+	}
+
+	#[test]
+	fn test_verbatim_prefix_stripped() {
+		assert_eq!(strip_verbatim_prefix("\\\\?\\C:\\dir\\file.txt"), "C:\\dir\\file.txt");
+		assert_eq!(strip_verbatim_prefix("\\\\?\\UNC\\server\\share"), "\\\\server\\share");
+		assert_eq!(strip_verbatim_prefix("C:\\dir"), "C:\\dir");
+		assert_eq!(FileRef::new("\\\\?\\C:\\dir\\file.txt").path(), "C:/dir/file.txt");
+		assert_eq!(FileRef::new(&FileRef::new("C:/dir/file.txt").to_verbatim()), FileRef::new("C:/dir/file.txt"));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use std::{ error::Error, fmt::{ self, Display, Formatter } };
+use crate::{ FileOperation, FileRef, HashAlgorithm, default_if_not_found };
+
+
+
+/// The version of the contents of a file, used to detect if a file was changed by someone else between reading and writing it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FileVersion {
+	Missing,
+	Contents { size:u64, digest:String }
+}
+impl FileVersion {
+
+	/// Get the version of the given file contents.
+	pub fn of(data:&[u8]) -> FileVersion {
+		FileVersion::Contents { size: data.len() as u64, digest: HashAlgorithm::Sha256.hash_bytes(data) }
+	}
+}
+
+
+
+/// The error returned when a file was changed since the version that was expected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionConflict {
+	pub expected:FileVersion,
+	pub actual:FileVersion
+}
+impl Display for VersionConflict {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		match self.actual {
+			FileVersion::Missing => write!(f, "File was deleted since it was read."),
+			FileVersion::Contents { .. } if self.expected == FileVersion::Missing => write!(f, "File was created since it was checked."),
+			FileVersion::Contents { .. } => write!(f, "File was changed since it was read.")
+		}
+	}
+}
+impl Error for VersionConflict {}
+
+
+
+impl FileRef {
+
+	/* VERSIONED METHODS */
+
+	/// Get the current version of the file. Returns `FileVersion::Missing` if the file does not exist.
+	pub fn version(&self) -> Result<FileVersion, Box<dyn Error>> {
+		default_if_not_found(self.read_bytes().map(|data| FileVersion::of(&data)), || FileVersion::Missing)
+	}
+
+	/// Read the contents of the file as a string, together with the version of the contents.
+	pub fn read_versioned(&self) -> Result<(String, FileVersion), Box<dyn Error>> {
+		let (data, version):(Vec<u8>, FileVersion) = self.read_bytes_versioned()?;
+		Ok((String::from_utf8(data).map_err(|error| self.error(FileOperation::Read, error))?, version))
+	}
+
+	/// Read the contents of the file as bytes, together with the version of the contents.
+	pub fn read_bytes_versioned(&self) -> Result<(Vec<u8>, FileVersion), Box<dyn Error>> {
+		let data:Vec<u8> = self.read_bytes()?;
+		let version:FileVersion = FileVersion::of(&data);
+		Ok((data, version))
+	}
+
+	/// Replace the contents of the file, but only if it still has the given version. Fails with a `VersionConflict` if the file was changed in the meantime. The check and the atomic write happen while holding the '.lock' sidecar of the file, so concurrent writers using this method cannot overwrite each other. Returns the version of the new contents.
+	pub fn write_if_unchanged(&self, version:&FileVersion, data:&[u8]) -> Result<FileVersion, Box<dyn Error>> {
+		self.with_sidecar_lock(|| {
+			let actual:FileVersion = self.version()?;
+			if &actual != version {
+				return Err(self.error(FileOperation::Write, VersionConflict { expected: version.clone(), actual }));
+			}
+			self.write_atomic(data)?;
+			Ok(FileVersion::of(data))
+		})?
+	}
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+	use std::error::Error;
+	use crate::{ FileRef, FileRefError, FileVersion, TempDirRef, VersionConflict };
+
+
+
+	fn conflict<'a>(error:&'a (dyn Error + 'static)) -> &'a VersionConflict {
+		error.downcast_ref::<FileRefError>().unwrap().source().unwrap().downcast_ref::<VersionConflict>().unwrap()
+	}
+
+
+
+	#[test]
+	fn test_write_if_unchanged() {
+		let temp_dir:TempDirRef = TempDirRef::new("versioned_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/state.txt";
+		file.write("first").unwrap();
+
+		let (contents, version):(String, FileVersion) = file.read_versioned().unwrap();
+		assert_eq!(contents, "first");
+		let new_version:FileVersion = file.write_if_unchanged(&version, b"second").unwrap();
+		assert_eq!(file.read().unwrap(), "second");
+		assert_eq!(file.version().unwrap(), new_version);
+
+		// Writing with the old version fails, even though the size did not change.
+		let error:Box<dyn Error> = file.write_if_unchanged(&version, b"third").unwrap_err();
+		assert_eq!(conflict(error.as_ref()).actual, new_version);
+		assert!(error.to_string().ends_with("File was changed since it was read."));
+		assert_eq!(file.read().unwrap(), "second");
+	}
+
+	#[test]
+	fn test_write_if_unchanged_missing_file() {
+		let temp_dir:TempDirRef = TempDirRef::new("versioned_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/state.txt";
+		assert_eq!(file.version().unwrap(), FileVersion::Missing);
+
+		file.write_if_unchanged(&FileVersion::Missing, b"created").unwrap();
+		let error:Box<dyn Error> = file.write_if_unchanged(&FileVersion::Missing, b"again").unwrap_err();
+		assert_eq!(conflict(error.as_ref()).expected, FileVersion::Missing);
+		assert_eq!(file.read().unwrap(), "created");
+	}
+}
\ No newline at end of file
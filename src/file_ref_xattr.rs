@@ -0,0 +1,77 @@
+use std::{ error::Error, ffi::OsString };
+use crate::{ FileOperation, FileRef };
+
+
+
+impl FileRef {
+
+	/* EXTENDED ATTRIBUTE METHODS */
+
+	/// Get the value of an extended attribute of the file/dir. Returns None if the attribute is not set.
+	pub fn xattr_get(&self, name:&str) -> Result<Option<Vec<u8>>, Box<dyn Error>> {
+		self.require_xattr_support(FileOperation::Read)?;
+		platform::get(self.os_path(), name).map_err(|error| self.error(FileOperation::Read, error))
+	}
+
+	/// Set an extended attribute of the file/dir, replacing any existing value.
+	pub fn xattr_set(&self, name:&str, value:&[u8]) -> Result<(), Box<dyn Error>> {
+		self.require_xattr_support(FileOperation::Write)?;
+		platform::set(self.os_path(), name, value).map_err(|error| self.error(FileOperation::Write, error))
+	}
+
+	/// List the names of all extended attributes of the file/dir.
+	pub fn xattr_list(&self) -> Result<Vec<String>, Box<dyn Error>> {
+		self.require_xattr_support(FileOperation::Read)?;
+		let names:Vec<OsString> = platform::list(self.os_path()).map_err(|error| self.error(FileOperation::Read, error))?.collect();
+		Ok(names.into_iter().map(|name| name.to_string_lossy().to_string()).collect())
+	}
+
+	/// Remove an extended attribute of the file/dir.
+	pub fn xattr_remove(&self, name:&str) -> Result<(), Box<dyn Error>> {
+		self.require_xattr_support(FileOperation::Write)?;
+		platform::remove(self.os_path(), name).map_err(|error| self.error(FileOperation::Write, error))
+	}
+
+	/// Return an error if extended attributes can not be used on the file/dir.
+	fn require_xattr_support(&self, operation:FileOperation) -> Result<(), Box<dyn Error>> {
+		if self.mounted_file_system().is_some() {
+			Err(self.error(operation, std::io::Error::new(std::io::ErrorKind::Unsupported, "Extended attributes are only supported on the filesystem of the OS.")))
+		} else if !self.exists() {
+			Err(self.error(operation, "File does not exist."))
+		} else {
+			Ok(())
+		}
+	}
+}
+
+
+
+#[cfg(unix)]
+mod platform {
+	pub(super) use xattr::{ get, set, list, remove };
+}
+
+#[cfg(not(unix))]
+mod platform {
+	use std::{ ffi::OsString, io, path::Path, vec::IntoIter };
+
+	fn unsupported() -> io::Error {
+		io::Error::new(io::ErrorKind::Unsupported, "Extended attributes are not supported on this platform.")
+	}
+
+	pub(super) fn get(_path:impl AsRef<Path>, _name:&str) -> io::Result<Option<Vec<u8>>> {
+		Err(unsupported())
+	}
+
+	pub(super) fn set(_path:impl AsRef<Path>, _name:&str, _value:&[u8]) -> io::Result<()> {
+		Err(unsupported())
+	}
+
+	pub(super) fn list(_path:impl AsRef<Path>) -> io::Result<IntoIter<OsString>> {
+		Err(unsupported())
+	}
+
+	pub(super) fn remove(_path:impl AsRef<Path>, _name:&str) -> io::Result<()> {
+		Err(unsupported())
+	}
+}
\ No newline at end of file
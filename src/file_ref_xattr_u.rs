@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+	use std::{ error::Error, io::ErrorKind };
+	use crate::{ FileRef, FileRefError, MemoryFs, TempDirRef };
+
+
+
+	fn is_unsupported(error:&(dyn Error + 'static)) -> bool {
+		error.downcast_ref::<FileRefError>().and_then(|error| error.io_error_kind()) == Some(ErrorKind::Unsupported)
+	}
+
+
+
+	#[test]
+	#[cfg(unix)]
+	fn test_xattr_set_get_list_remove() {
+		let temp_dir:TempDirRef = TempDirRef::new("xattr_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/tagged.txt";
+		file.write("contents").unwrap();
+
+		// Not every filesystem used for temp dirs supports user attributes.
+		if let Err(error) = file.xattr_set("user.file_ref.tag", b"important") {
+			assert!(is_unsupported(error.as_ref()), "{error}");
+			return;
+		}
+		assert_eq!(file.xattr_get("user.file_ref.tag").unwrap(), Some(b"important".to_vec()));
+		assert!(file.xattr_list().unwrap().contains(&"user.file_ref.tag".to_string()));
+		file.xattr_remove("user.file_ref.tag").unwrap();
+		assert_eq!(file.xattr_get("user.file_ref.tag").unwrap(), None);
+	}
+
+	#[test]
+	fn test_xattr_unsupported_on_mounted_fs() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let file:FileRef = memory_fs.file_ref("file.txt");
+		file.write("contents").unwrap();
+		assert!(is_unsupported(file.xattr_list().unwrap_err().as_ref()));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,166 @@
+use std::{ error::Error, fs::File };
+use zip::{ CompressionMethod, ZipArchive, ZipWriter, read::ZipFile, write::SimpleFileOptions };
+use crate::{ Cancelled, CompressionLevel, DirEntries, FileOperation, FileRef, GlobPattern, NoProgress, Progress, ProgressTracker, SEPARATOR };
+
+
+
+impl FileRef {
+
+	/* ZIP READING METHODS */
+
+	/// Open the file as a zip archive.
+	fn open_zip(&self) -> Result<ZipArchive<File>, Box<dyn Error>> {
+		if self.is_dir() {
+			Err(self.error(FileOperation::Read, "Only able to open files as zip archives."))
+		} else if !self.exists() {
+			Err(self.error(FileOperation::Read, "File does not exist."))
+		} else {
+			self.require_std_file_system(FileOperation::Read)?;
+			ZipArchive::new(File::open(self.os_path()).map_err(|error| self.error(FileOperation::Read, error))?).map_err(|error| self.error(FileOperation::Read, error))
+		}
+	}
+
+	/// List the paths of all entries in the zip archive, relative to the root of the archive.
+	pub fn zip_entries(&self) -> Result<Vec<FileRef>, Box<dyn Error>> {
+		let mut archive:ZipArchive<File> = self.open_zip()?;
+		let mut entries:Vec<FileRef> = Vec::with_capacity(archive.len());
+		for entry_index in 0..archive.len() {
+			entries.push(FileRef::new(archive.by_index(entry_index)?.name()).trim_end_matches(SEPARATOR));
+		}
+		Ok(entries)
+	}
+
+	/// Extract all entries of the zip archive into the given dir. Returns the extracted files.
+	pub fn zip_extract_to(&self, target_dir:&FileRef) -> Result<Vec<FileRef>, Box<dyn Error>> {
+		self.zip_extract_filtered_to(target_dir, |_| true)
+	}
+
+	/// Extract the entries of the zip archive matching the given glob pattern into the given dir. Returns the extracted files.
+	pub fn zip_extract_glob_to(&self, target_dir:&FileRef, pattern:&str) -> Result<Vec<FileRef>, Box<dyn Error>> {
+		let pattern:GlobPattern = GlobPattern::new(pattern)?;
+		self.zip_extract_filtered_to(target_dir, |entry| pattern.matches(entry.path()))
+	}
+
+	/// Extract the entries of the zip archive for which the filter returns true into the given dir. The filter receives the path of the entry relative to the root of the archive. Returns the extracted files.
+	pub fn zip_extract_filtered_to<T>(&self, target_dir:&FileRef, filter:T) -> Result<Vec<FileRef>, Box<dyn Error>> where T:Fn(&FileRef) -> bool {
+		use std::{ fs::create_dir_all, io::copy };
+
+		let mut archive:ZipArchive<File> = self.open_zip()?;
+		target_dir.require_std_file_system(FileOperation::Write)?;
+		let mut extracted:Vec<FileRef> = Vec::new();
+		for entry_index in 0..archive.len() {
+			let mut entry:ZipFile = archive.by_index(entry_index)?;
+
+			// Refuse entries that would escape the target dir.
+			let relative_path:FileRef = match entry.enclosed_name() {
+				Some(path) => FileRef::new(&path.display().to_string()).trim_end_matches(SEPARATOR),
+				None => return Err(self.error(FileOperation::Read, format!("Entry \"{}\" has an unsafe path.", entry.name())))
+			};
+			if !filter(&relative_path) {
+				continue;
+			}
+
+			// Extract entry.
+			let target:FileRef = target_dir.clone() + SEPARATOR + relative_path.path();
+			if entry.is_dir() {
+				create_dir_all(target.os_path())?;
+			} else {
+				target.guarantee_parent_dir()?;
+				let mut target_file:File = File::create(target.os_path())?;
+				copy(&mut entry, &mut target_file)?;
+				extracted.push(target);
+			}
+		}
+		Ok(extracted)
+	}
+
+
+
+	/* ZIP WRITING METHODS */
+
+	/// Write all files and dirs in this dir to a zip archive at the target location. Returns the number of files written.
+	pub fn zip_dir_to(&self, target:&FileRef, level:CompressionLevel) -> Result<usize, Box<dyn Error>> {
+		self.zip_dir_filtered_to(target, level, |_| true)
+	}
+
+	/// Write all files and dirs in this dir to a zip archive at the target location, reporting the bytes of the files archived so far to the progress. If the progress is cancelled, the archive is closed with the files written so far and a `Cancelled` error with their number is returned. Returns the number of files written.
+	pub fn zip_dir_to_with_progress(&self, target:&FileRef, level:CompressionLevel, progress:&dyn Progress) -> Result<usize, Box<dyn Error>> {
+		self.zip_dir_with(target, level, |_| true, progress)
+	}
+
+	/// Write the files and dirs in this dir for which the filter returns true to a zip archive at the target location. The filter receives the path of the entry relative to this dir. When a dir is filtered out, all of its contents are left out as well. Returns the number of files written.
+	pub fn zip_dir_filtered_to<T>(&self, target:&FileRef, level:CompressionLevel, filter:T) -> Result<usize, Box<dyn Error>> where T:Fn(&FileRef) -> bool {
+		self.zip_dir_with(target, level, filter, &NoProgress)
+	}
+
+	/// Write the files and dirs in this dir for which the filter returns true to a zip archive, reporting the progress. Entries are told apart by their metadata, so extensionless files and dirs with a dot in their name are archived correctly.
+	fn zip_dir_with<T>(&self, target:&FileRef, level:CompressionLevel, filter:T, progress:&dyn Progress) -> Result<usize, Box<dyn Error>> where T:Fn(&FileRef) -> bool {
+		use std::io::copy;
+
+		if !self.exists() {
+			return Err(self.error(FileOperation::Read, "Dir does not exist."));
+		} else if !self.is_dir_fs() {
+			return Err(self.error(FileOperation::Read, "Only able to zip dirs."));
+		}
+		self.require_std_file_system(FileOperation::Read)?;
+		target.require_std_file_system(FileOperation::Write)?;
+
+		// Prepare archive.
+		let options:SimpleFileOptions = match level {
+			CompressionLevel::Store => SimpleFileOptions::default().compression_method(CompressionMethod::Stored),
+			CompressionLevel::Fast => SimpleFileOptions::default().compression_method(CompressionMethod::Deflated).compression_level(Some(1)),
+			CompressionLevel::Default => SimpleFileOptions::default().compression_method(CompressionMethod::Deflated),
+			CompressionLevel::Best => SimpleFileOptions::default().compression_method(CompressionMethod::Deflated).compression_level(Some(9))
+		};
+		let source_dir:FileRef = self.clone().absolute().trim_end_matches(SEPARATOR);
+		let target_absolute:FileRef = target.clone().absolute();
+		let source_entries:DirEntries = DirEntries::collect(&source_dir)?;
+		let bytes_total:u64 = source_entries.files.iter().map(|(_, metadata)| metadata.len()).sum();
+		let mut entries:Vec<(FileRef, bool)> = source_entries.dirs.into_iter().map(|dir| (dir, true)).chain(source_entries.files.into_iter().map(|(file, _)| (file, false))).collect();
+		entries.sort_by(|(left, _), (right, _)| left.path().cmp(right.path()));
+		target.guarantee_parent_dir()?;
+		let mut writer:ZipWriter<File> = ZipWriter::new(File::create(target.os_path())?);
+		let mut tracker:ProgressTracker = ProgressTracker::new(progress, bytes_total);
+
+		// Add entries to archive.
+		let mut excluded_dirs:Vec<String> = Vec::new();
+		let mut files_written:usize = 0;
+		for (entry, is_dir) in entries {
+			if progress.is_cancelled() {
+				writer.finish()?;
+				return Err(Box::new(Cancelled::new(files_written)));
+			}
+			if entry.path() == target_absolute.path() {
+				continue;
+			}
+			let relative_path:FileRef = entry.relative_to_dir(&source_dir);
+			if excluded_dirs.iter().any(|excluded_dir| relative_path.starts_with(excluded_dir)) {
+				continue;
+			}
+			if !filter(&relative_path) {
+				if is_dir {
+					excluded_dirs.push(relative_path.path().to_owned() + SEPARATOR);
+				}
+				continue;
+			}
+			if is_dir {
+				writer.add_directory(relative_path.path().to_owned() + SEPARATOR, options)?;
+			} else {
+				writer.start_file(relative_path.path(), options)?;
+				tracker.start_file(&entry);
+				if let Err(error) = copy(&mut tracker.reader(File::open(entry.os_path())?), &mut writer) {
+					if progress.is_cancelled() {
+						writer.abort_file()?;
+						writer.finish()?;
+						return Err(Box::new(Cancelled::new(files_written)));
+					}
+					return Err(error.into());
+				}
+				files_written += 1;
+			}
+		}
+		writer.finish()?;
+		tracker.finish();
+		Ok(files_written)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+	use std::{ fs::File, io::Write };
+	use zip::{ ZipWriter, write::SimpleFileOptions };
+	use crate::{ CompressionLevel, FileRef, TempDirRef };
+
+
+
+	fn create_test_zip(temp_dir:&TempDirRef) -> FileRef {
+		let zip_file:FileRef = temp_dir.path() + "/archive.zip";
+		let mut writer:ZipWriter<File> = ZipWriter::new(File::create(zip_file.path()).unwrap());
+		writer.add_directory("sub_dir/", SimpleFileOptions::default()).unwrap();
+		for (name, contents) in [("file1.txt", "one"), ("sub_dir/file2.txt", "two"), ("sub_dir/image.png", "three")] {
+			writer.start_file(name, SimpleFileOptions::default()).unwrap();
+			writer.write_all(contents.as_bytes()).unwrap();
+		}
+		writer.finish().unwrap();
+		zip_file
+	}
+
+	#[test]
+	fn test_zip_entries() {
+		let temp_dir:TempDirRef = TempDirRef::new("zip_test_").unwrap();
+		let zip_file:FileRef = create_test_zip(&temp_dir);
+		let entries:Vec<FileRef> = zip_file.zip_entries().unwrap();
+		assert_eq!(entries, vec![FileRef::new("sub_dir"), FileRef::new("file1.txt"), FileRef::new("sub_dir/file2.txt"), FileRef::new("sub_dir/image.png")]);
+	}
+
+	#[test]
+	fn test_zip_extract_to() {
+		let temp_dir:TempDirRef = TempDirRef::new("zip_test_").unwrap();
+		let zip_file:FileRef = create_test_zip(&temp_dir);
+		let target_dir:FileRef = temp_dir.path() + "/extracted";
+
+		let extracted:Vec<FileRef> = zip_file.zip_extract_to(&target_dir).unwrap();
+		assert_eq!(extracted.len(), 3);
+		assert_eq!((target_dir.clone() + "/file1.txt").read().unwrap(), "one");
+		assert_eq!((target_dir.clone() + "/sub_dir/file2.txt").read().unwrap(), "two");
+	}
+
+	#[test]
+	fn test_zip_extract_glob_to() {
+		let temp_dir:TempDirRef = TempDirRef::new("zip_test_").unwrap();
+		let zip_file:FileRef = create_test_zip(&temp_dir);
+		let target_dir:FileRef = temp_dir.path() + "/extracted";
+
+		let extracted:Vec<FileRef> = zip_file.zip_extract_glob_to(&target_dir, "**/*.txt").unwrap();
+		assert_eq!(extracted.len(), 2);
+		assert!(!(target_dir.clone() + "/sub_dir/image.png").exists());
+	}
+
+	#[test]
+	fn test_zip_missing_archive() {
+		let temp_dir:TempDirRef = TempDirRef::new("zip_test_").unwrap();
+		assert!((temp_dir.path() + "/missing.zip").zip_entries().is_err());
+	}
+
+	#[test]
+	fn test_zip_dir_to() {
+		let temp_dir:TempDirRef = TempDirRef::new("zip_test_").unwrap();
+		let source_dir:FileRef = temp_dir.path() + "/source";
+		(source_dir.clone() + "/file1.txt").write("one").unwrap();
+		(source_dir.clone() + "/sub_dir/file2.txt").write("two").unwrap();
+		(source_dir.clone() + "/empty_dir").create().unwrap();
+		let zip_file:FileRef = temp_dir.path() + "/archive.zip";
+
+		assert_eq!(source_dir.zip_dir_to(&zip_file, CompressionLevel::Best).unwrap(), 2);
+		let mut entries:Vec<FileRef> = zip_file.zip_entries().unwrap();
+		entries.sort();
+		assert_eq!(entries, vec![FileRef::new("empty_dir"), FileRef::new("file1.txt"), FileRef::new("sub_dir"), FileRef::new("sub_dir/file2.txt")]);
+
+		let target_dir:FileRef = temp_dir.path() + "/extracted";
+		zip_file.zip_extract_to(&target_dir).unwrap();
+		assert_eq!((target_dir.clone() + "/sub_dir/file2.txt").read().unwrap(), "two");
+	}
+
+	#[test]
+	fn test_zip_dir_extensionless_files_and_dotted_dirs() {
+		let temp_dir:TempDirRef = TempDirRef::new("zip_test_").unwrap();
+		let source_dir:FileRef = temp_dir.path() + "/source";
+		std::fs::create_dir_all((source_dir.clone() + "/lib.v2").os_path()).unwrap();
+		std::fs::write((source_dir.clone() + "/Makefile").os_path(), "all:").unwrap();
+		std::fs::write((source_dir.clone() + "/lib.v2/LICENSE").os_path(), "MIT").unwrap();
+		let zip_file:FileRef = temp_dir.path() + "/archive.zip";
+
+		assert_eq!(source_dir.zip_dir_to(&zip_file, CompressionLevel::Default).unwrap(), 2);
+		assert_eq!(zip_file.zip_entries().unwrap(), vec![FileRef::new("Makefile"), FileRef::new("lib.v2"), FileRef::new("lib.v2/LICENSE")]);
+
+		let target_dir:FileRef = temp_dir.path() + "/extracted";
+		zip_file.zip_extract_to(&target_dir).unwrap();
+		assert_eq!(std::fs::read_to_string((target_dir.clone() + "/Makefile").os_path()).unwrap(), "all:");
+		assert_eq!(std::fs::read_to_string((target_dir.clone() + "/lib.v2/LICENSE").os_path()).unwrap(), "MIT");
+	}
+
+	#[test]
+	fn test_zip_dir_filtered_to() {
+		let temp_dir:TempDirRef = TempDirRef::new("zip_test_").unwrap();
+		let source_dir:FileRef = temp_dir.path() + "/source";
+		(source_dir.clone() + "/file1.txt").write("one").unwrap();
+		(source_dir.clone() + "/image.png").write("image").unwrap();
+		(source_dir.clone() + "/target/file2.txt").write("two").unwrap();
+		let zip_file:FileRef = temp_dir.path() + "/archive.zip";
+
+		let written:usize = source_dir.zip_dir_filtered_to(&zip_file, CompressionLevel::Store, |entry| entry.path() != "target" && !entry.ends_with(".png")).unwrap();
+		assert_eq!(written, 1);
+		assert_eq!(zip_file.zip_entries().unwrap(), vec![FileRef::new("file1.txt")]);
+	}
+}
\ No newline at end of file
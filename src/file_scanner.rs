@@ -1,4 +1,4 @@
-use crate::{ FileRef, SEPARATOR };
+use crate::{ CancellationToken, FileRef, SEPARATOR };
 
 
 
@@ -21,6 +21,7 @@ pub struct FileScanner {
 	results_filter:ResultFilter,
 	recurse_filter:ResultFilter,
 	use_cache:bool,
+	cancellation:Option<CancellationToken>,
 
 	cursor:FileScannerCursor
 }
@@ -30,7 +31,7 @@ impl FileScanner {
 
 	/// Create a new filter.
 	pub fn new(root_dir:&FileRef) -> FileScanner {
-		let root_dir:FileRef = root_dir.clone().absolute().trim_end_matches(SEPARATOR);
+		let root_dir:FileRef = root_dir.clone().absolute().trim_dir_separator();
 		FileScanner {
 			root_dir: root_dir.clone(),
 			include_self: false,
@@ -39,6 +40,7 @@ impl FileScanner {
 			results_filter: Box::new(|_| true),
 			recurse_filter: Box::new(|_| false),
 			use_cache: false,
+			cancellation: None,
 
 			cursor: FileScannerCursor {
 				parsed_self: false,
@@ -91,6 +93,12 @@ impl FileScanner {
 		self
 	}
 
+	/// Return self with a token that stops the scan when cancelled. The entries found before cancellation are still returned.
+	pub fn cancel_on(mut self, token:&CancellationToken) -> Self {
+		self.cancellation = Some(token.clone());
+		self
+	}
+
 
 
 
@@ -120,7 +128,7 @@ impl FileScanner {
 
 		// If not found in current dir, try to recurse into sub-dir.
 		if let Some(current_dir) = self.cursor.current_dir.clone() {
-			if let Some(sub_dir) = self.entries_in_dir(&current_dir).to_owned().iter().filter(|entry| entry.is_dir() && (self.recurse_filter)(*entry)).next() {
+			if let Some(sub_dir) = self.entries_in_dir(&current_dir).to_owned().iter().find(|entry| entry.is_dir() && (self.recurse_filter)(entry)) {
 				if let Some(entry) = self.find_in_new_dir(sub_dir.clone()) {
 					return Some(entry);
 				}
@@ -129,27 +137,22 @@ impl FileScanner {
 
 		// If not found in any sub-dirs, keep moving to parent dirs while keeping inside the source dir.
 		if let Some(mut current_dir) = self.cursor.current_dir.clone() {
-			if let Ok(mut parent_dir) = current_dir.parent_dir() {
-				while parent_dir.contains(&self.root_dir.path()) {
-
-					// Find new sub-dirs in the parent dir, below the current dir.
-					let parent_dir_sub_dirs:Vec<FileRef> = parent_dir.list_dirs();
-					if let Some(own_index_in_parent_dir) = parent_dir_sub_dirs.iter().position(|dir| dir == &current_dir) {
-						if let Some(sub_dir) = parent_dir_sub_dirs[own_index_in_parent_dir + 1..].iter().filter(|dir| (self.recurse_filter)(&dir)).next() {
-							if let Some(entry) = self.find_in_new_dir(sub_dir.clone()) {
-								return Some(entry);
-							}
+			while let Some(parent_dir) = self.parent_in_scan(&current_dir) {
+
+				// Find new sub-dirs in the parent dir, below the current dir.
+				let parent_dir_sub_dirs:Vec<FileRef> = parent_dir.list_dirs();
+				if let Some(own_index_in_parent_dir) = parent_dir_sub_dirs.iter().position(|dir| dir == &current_dir) {
+					if let Some(sub_dir) = parent_dir_sub_dirs[own_index_in_parent_dir + 1..].iter().find(|dir| (self.recurse_filter)(dir)) {
+						if let Some(entry) = self.find_in_new_dir(sub_dir.clone()) {
+							return Some(entry);
 						}
-					} else {
-						eprintln!("FileRef scanner Could not recurse back to parent of '{current_dir}'. Dir does not seem to exist anymore.");
-					}
-
-					// Move to parent dir.
-					if let Ok(parent_parent_dir) = parent_dir.parent_dir() {
-						current_dir = parent_dir.clone();
-						parent_dir = parent_parent_dir;
 					}
+				} else {
+					eprintln!("FileRef scanner Could not recurse back to parent of '{current_dir}'. Dir does not seem to exist anymore.");
 				}
+
+				// Move to parent dir.
+				current_dir = parent_dir;
 			}
 		}
 		
@@ -157,6 +160,16 @@ impl FileScanner {
 		None
 	}
 
+	/// Get the parent of a dir found by the scan, or None for the root dir of the scan. The root of a filesystem is kept as a separator, so scans starting there do not climb into an empty path.
+	fn parent_in_scan(&self, dir:&FileRef) -> Option<FileRef> {
+		if dir.path().trim_end_matches(SEPARATOR) == self.root_dir.path().trim_end_matches(SEPARATOR) {
+			return None;
+		}
+		let parent_dir:FileRef = dir.parent_dir().ok()?;
+		let parent_dir:FileRef = if parent_dir.path().is_empty() && dir.path().starts_with(SEPARATOR) { FileRef::new(SEPARATOR) } else { parent_dir };
+		Some(parent_dir).filter(|parent_dir| parent_dir.contains(self.root_dir.path()))
+	}
+
 	/// Move the cursor to a new place and find the next file there.
 	fn find_in_new_dir(&mut self, new_dir:FileRef) -> Option<FileRef> {
 		self.cursor.current_dir = Some(new_dir);
@@ -196,8 +209,6 @@ impl FileScanner {
 
 	/// List the entries in a specific dir.
 	fn entries_in_dir(&mut self, dir:&FileRef) -> &Vec<FileRef> {
-		use std::fs::read_dir;
-
 		// Find in cache.
 		if self.use_cache {
 			if let Some(cache_index) = self.cursor.entries_cache.iter().position(|(cache_dir, _)| cache_dir == dir) {
@@ -206,7 +217,7 @@ impl FileScanner {
 		}
 
 		// List entries in actual folder and store in cache. If cache is disabled, keep one result in the cache to be able to return a borrowed reference.
-		let entries:Vec<FileRef> = read_dir(dir.path()).map(|results| results.flatten().map(|dir_entry| FileRef::new(dir_entry.path().to_str().unwrap())).collect::<Vec<FileRef>>()).unwrap_or_default();
+		let entries:Vec<FileRef> = dir.traced("scan", None, |paths:&Vec<String>| Some(paths.len() as u64), || dir.file_system().list(dir.path())).map(|paths| paths.iter().map(|path| FileRef::new(path)).collect::<Vec<FileRef>>()).unwrap_or_default();
 		if self.use_cache {
 			self.cursor.entries_cache.push((dir.clone(), entries));
 			&self.cursor.entries_cache.last().unwrap().1
@@ -224,6 +235,9 @@ impl Iterator for FileScanner {
 	type Item = FileRef;
 
 	fn next(&mut self) -> Option<Self::Item> {
+		if self.cancellation.as_ref().map(|token| token.is_cancelled()).unwrap_or(false) {
+			return None;
+		}
 		self.find_next_at_cursor()
 	}
 }
\ No newline at end of file
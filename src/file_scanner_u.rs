@@ -55,7 +55,7 @@ mod tests {
 		let temp_file_ref:FileRef = FileRef::new(temp_file.path());
 		let scanner:FileScanner = FileScanner::new(&temp_file_ref).include_files().include_dirs();
 		let results:Vec<FileRef> = scanner.collect();
-		assert!(results.len() > 0);
+		assert!(!results.is_empty());
 		assert!(results.iter().any(|e| e.is_dir()));
 		assert!(results.iter().any(|e| !e.is_dir()));
 	}
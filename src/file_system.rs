@@ -0,0 +1,248 @@
+use std::{ error::Error, sync::{ Arc, RwLock, RwLockReadGuard, RwLockWriteGuard } };
+use crate::{ FileOperation, FileRef, SEPARATOR, file_ref_verbatim::{ os_path, strip_verbatim_prefix }, platform::{ fallback_file_system, process_id, unix_nanos } };
+
+
+
+static FILE_SYSTEM_MOUNTS:RwLock<Vec<(String, Arc<dyn FileSystem>)>> = RwLock::new(Vec::new());
+
+
+
+/// The backend FileRefs use for their IO. All paths are the full paths of the FileRefs, including the root the filesystem is mounted at.
+pub trait FileSystem:Send + Sync {
+
+	/// Check if the file or dir exists.
+	fn exists(&self, path:&str) -> bool;
+
+	/// Check if the file or dir can be accessed.
+	fn is_accessible(&self, path:&str) -> bool;
+
+	/// Check if the path is an existing dir. By default, existing paths that can be listed are dirs.
+	fn is_dir(&self, path:&str) -> bool {
+		self.exists(path) && self.list(path).is_ok()
+	}
+
+	/// Create an empty file or dir. The parent dir must exist.
+	fn create(&self, path:&str, is_dir:bool) -> Result<(), Box<dyn Error>>;
+
+	/// Read the contents of a file.
+	fn read(&self, path:&str) -> Result<Vec<u8>, Box<dyn Error>>;
+
+	/// Get the size of a file in bytes.
+	fn size(&self, path:&str) -> Result<u64, Box<dyn Error>> {
+		Ok(self.read(path)?.len() as u64)
+	}
+
+	/// Read a range of bytes from a file.
+	fn read_range(&self, path:&str, start:u64, end:u64) -> Result<Vec<u8>, Box<dyn Error>> {
+		self.read(path)?.get(start as usize..end as usize).map(|range| range.to_vec()).ok_or_else(|| FileRef::new(path).error(FileOperation::Read, format!("Range {start}..{end} is out of bounds.")))
+	}
+
+	/// Write data to an existing file at the given offset, or replace all contents if no offset is given.
+	fn write_at(&self, path:&str, offset:Option<u64>, data:&[u8]) -> Result<(), Box<dyn Error>>;
+
+	/// Append data to an existing file.
+	fn append(&self, path:&str, data:&[u8]) -> Result<(), Box<dyn Error>> {
+		let size:u64 = self.read(path)?.len() as u64;
+		self.write_at(path, Some(size), data)
+	}
+
+	/// Cut a file off at the given length. Files shorter than the length are left as they are.
+	fn truncate(&self, path:&str, length:u64) -> Result<(), Box<dyn Error>> {
+		let data:Vec<u8> = self.read(path)?;
+		if (data.len() as u64) > length {
+			self.write_at(path, None, &data[..length as usize])?;
+		}
+		Ok(())
+	}
+
+	/// Copy a file within this filesystem. The parent dir of the target must exist. Returns the number of bytes copied.
+	fn copy(&self, source:&str, target:&str) -> Result<u64, Box<dyn Error>> {
+		let data:Vec<u8> = self.read(source)?;
+		if !self.exists(target) {
+			self.create(target, false)?;
+		}
+		self.write_at(target, None, &data)?;
+		Ok(data.len() as u64)
+	}
+
+	/// Remove a file, or a dir and all of its contents.
+	fn remove(&self, path:&str) -> Result<(), Box<dyn Error>>;
+
+	/// List the paths of the direct children of a dir.
+	fn list(&self, path:&str) -> Result<Vec<String>, Box<dyn Error>>;
+}
+
+
+
+/// Mount a filesystem at the given root, like 'remote:' or '/mnt/overlay'. All FileRefs with paths in the root use the filesystem. Replaces any filesystem previously mounted at the same root.
+pub fn mount_file_system(root:&FileRef, file_system:Arc<dyn FileSystem>) {
+	let root:String = root.path().trim_end_matches(SEPARATOR).to_owned();
+	let mut mounts:RwLockWriteGuard<Vec<(String, Arc<dyn FileSystem>)>> = FILE_SYSTEM_MOUNTS.write().unwrap();
+	mounts.retain(|(mounted_root, _)| mounted_root != &root);
+	mounts.push((root, file_system));
+}
+
+/// Unmount the filesystem mounted at the given root. FileRefs in the root will use the filesystem of the OS again.
+pub fn unmount_file_system(root:&FileRef) {
+	let root:&str = root.path().trim_end_matches(SEPARATOR);
+	if let Ok(mut mounts) = FILE_SYSTEM_MOUNTS.write() {
+		mounts.retain(|(mounted_root, _)| mounted_root != root);
+	}
+}
+
+
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StdFileSystem;
+impl FileSystem for StdFileSystem {
+	fn exists(&self, path:&str) -> bool {
+		std::fs::metadata(os_path(path)).is_ok()
+	}
+
+	fn is_accessible(&self, path:&str) -> bool {
+		match std::fs::metadata(os_path(path)) {
+			Ok(metadata) if metadata.is_dir() => std::fs::read_dir(os_path(path)).is_ok(),
+			Ok(_) => std::fs::File::open(os_path(path)).is_ok(),
+			Err(_) => false
+		}
+	}
+
+	fn is_dir(&self, path:&str) -> bool {
+		std::fs::metadata(os_path(path)).is_ok_and(|metadata| metadata.is_dir())
+	}
+
+	fn create(&self, path:&str, is_dir:bool) -> Result<(), Box<dyn Error>> {
+		use std::fs::{ File, create_dir };
+
+		if is_dir {
+			create_dir(os_path(path)).map_err(|error| error.into())
+		} else {
+			File::create(os_path(path))?;
+			Ok(())
+		}
+	}
+
+	fn read(&self, path:&str) -> Result<Vec<u8>, Box<dyn Error>> {
+		use std::{ fs::File, io::Read };
+
+		let mut file:File = File::open(os_path(path))?;
+		let mut content:Vec<u8> = Vec::new();
+		file.read_to_end(&mut content)?;
+		Ok(content)
+	}
+
+	fn size(&self, path:&str) -> Result<u64, Box<dyn Error>> {
+		Ok(std::fs::metadata(os_path(path))?.len())
+	}
+
+	fn read_range(&self, path:&str, start:u64, end:u64) -> Result<Vec<u8>, Box<dyn Error>> {
+		use std::{ fs::File, io::{ Read, Seek, SeekFrom } };
+
+		let mut file:File = File::open(os_path(path))?;
+		let mut buffer:Vec<u8> = vec![0; (end - start) as usize];
+		file.seek(SeekFrom::Start(start))?;
+		file.read_exact(&mut buffer)?;
+		Ok(buffer)
+	}
+
+	fn write_at(&self, path:&str, offset:Option<u64>, data:&[u8]) -> Result<(), Box<dyn Error>> {
+		use std::{ fs::{ File, OpenOptions }, io::{ Write, Seek, SeekFrom } };
+
+		let mut file:File = match offset {
+			Some(offset) => {
+				let mut file:File = OpenOptions::new().write(true).open(os_path(path))?;
+				file.seek(SeekFrom::Start(offset))?;
+				file
+			},
+			None => OpenOptions::new().write(true).truncate(true).open(os_path(path))?
+		};
+		file.write_all(data).map_err(|error| error.into())
+	}
+
+	fn append(&self, path:&str, data:&[u8]) -> Result<(), Box<dyn Error>> {
+		use std::{ fs::{ File, OpenOptions }, io::Write };
+
+		let mut file:File = OpenOptions::new().append(true).open(os_path(path))?;
+		file.write_all(data).map_err(|error| error.into())
+	}
+
+	fn truncate(&self, path:&str, length:u64) -> Result<(), Box<dyn Error>> {
+		use std::fs::{ File, OpenOptions };
+
+		let file:File = OpenOptions::new().write(true).open(os_path(path))?;
+		if file.metadata()?.len() > length {
+			file.set_len(length)?;
+		}
+		Ok(())
+	}
+
+	fn copy(&self, source:&str, target:&str) -> Result<u64, Box<dyn Error>> {
+		std::fs::copy(os_path(source), os_path(target)).map_err(|error| error.into())
+	}
+
+	fn remove(&self, path:&str) -> Result<(), Box<dyn Error>> {
+		use std::fs::{ remove_dir, remove_dir_all, remove_file, symlink_metadata };
+
+		let metadata:Option<std::fs::Metadata> = symlink_metadata(os_path(path)).ok();
+		if metadata.as_ref().is_some_and(|metadata| metadata.file_type().is_symlink()) {
+			// Only remove the link itself. On Windows, links to dirs are removed like empty dirs.
+			return remove_file(os_path(path)).or_else(|error| if cfg!(windows) { remove_dir(os_path(path)) } else { Err(error) }).map_err(|error| error.into());
+		}
+		if metadata.map(|metadata| metadata.is_dir()).unwrap_or_else(|| FileRef::new(path).is_dir()) {
+			remove_dir_all(os_path(path)).map_err(|error| error.into())
+		} else {
+			remove_file(os_path(path)).map_err(|error| error.into())
+		}
+	}
+
+	fn list(&self, path:&str) -> Result<Vec<String>, Box<dyn Error>> {
+		// Entries with names that are not valid UTF-8 can not be represented as a FileRef, so they are skipped.
+		Ok(std::fs::read_dir(os_path(path))?.flatten().filter_map(|dir_entry| dir_entry.path().to_str().map(|entry_path| strip_verbatim_prefix(entry_path).into_owned())).collect())
+	}
+}
+
+
+
+impl FileRef {
+
+	/* FILE SYSTEM METHODS */
+
+	/// Get the filesystem this file uses for its IO. This is the filesystem mounted at the longest root containing the file, or the filesystem of the OS if none is.
+	pub fn file_system(&self) -> Arc<dyn FileSystem> {
+		self.mounted_file_system().unwrap_or_else(|| Arc::new(StdFileSystem))
+	}
+
+	/// Check if the volume containing the path treats names that only differ in case as different files. Probes by creating a temporary file in the closest existing dir, so that dir must be writable.
+	pub fn fs_is_case_sensitive(&self) -> Result<bool, Box<dyn Error>> {
+		let file_system:Arc<dyn FileSystem> = self.file_system();
+		let dir:FileRef = std::iter::successors(Some(self.clone().absolute()), |path| path.parent_dir().ok().filter(|parent| parent != path))
+			.find(|path| file_system.exists(path.path()) && file_system.list(path.path()).is_ok())
+			.ok_or_else(|| self.error(FileOperation::Scan, "No existing dir found to probe."))?;
+		let probe_name:String = format!(".case_probe_{}_{}.tmp", process_id(), unix_nanos());
+		let probe:FileRef = dir.clone().trim_end_matches(SEPARATOR) + SEPARATOR + &probe_name;
+		let upper_case_probe:FileRef = dir.trim_end_matches(SEPARATOR) + SEPARATOR + &probe_name.to_uppercase();
+		file_system.create(probe.path(), false).map_err(|error| probe.error(FileOperation::Create, error))?;
+		let is_case_sensitive:bool = !file_system.exists(upper_case_probe.path());
+		file_system.remove(probe.path()).map_err(|error| probe.error(FileOperation::Delete, error))?;
+		Ok(is_case_sensitive)
+	}
+
+	/// Get the filesystem mounted at the longest root containing the file, if any. On targets without a filesystem of the OS, files outside of all mounts use an in-memory filesystem, which counts as mounted.
+	pub(crate) fn mounted_file_system(&self) -> Option<Arc<dyn FileSystem>> {
+		let mounts:RwLockReadGuard<Vec<(String, Arc<dyn FileSystem>)>> = FILE_SYSTEM_MOUNTS.read().unwrap();
+		mounts.iter()
+			.filter(|(root, _)| self.path().strip_prefix(root.as_str()).map(|remainder| remainder.is_empty() || remainder.starts_with(SEPARATOR)).unwrap_or(false))
+			.max_by_key(|(root, _)| root.len())
+			.map(|(_, file_system)| file_system.clone())
+			.or_else(fallback_file_system)
+	}
+
+	/// Check if both files use the same filesystem, so operations between them can be handed to that filesystem directly.
+	pub(crate) fn shares_file_system(&self, other:&FileRef) -> bool {
+		match (self.mounted_file_system(), other.mounted_file_system()) {
+			(None, None) => true,
+			(Some(own_file_system), Some(other_file_system)) => Arc::ptr_eq(&own_file_system, &other_file_system),
+			_ => false
+		}
+	}
+}
\ No newline at end of file
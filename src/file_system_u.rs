@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+	use std::{ error::Error, sync::{ Arc, atomic::{ AtomicU32, Ordering } } };
+	use crate::{ FileRef, FileSystem, MemoryFs, StdFileSystem, TempDirRef, mount_file_system, unmount_file_system };
+
+
+
+	/// A filesystem that forwards reads to the OS and rejects all modifications.
+	struct ReadOnlyFileSystem;
+	impl FileSystem for ReadOnlyFileSystem {
+		fn exists(&self, path:&str) -> bool { StdFileSystem.exists(path) }
+		fn is_accessible(&self, path:&str) -> bool { StdFileSystem.is_accessible(path) }
+		fn create(&self, path:&str, _is_dir:bool) -> Result<(), Box<dyn Error>> { Err(format!("\"{path}\" is read-only.").into()) }
+		fn read(&self, path:&str) -> Result<Vec<u8>, Box<dyn Error>> { StdFileSystem.read(path) }
+		fn write_at(&self, path:&str, _offset:Option<u64>, _data:&[u8]) -> Result<(), Box<dyn Error>> { Err(format!("\"{path}\" is read-only.").into()) }
+		fn remove(&self, path:&str) -> Result<(), Box<dyn Error>> { Err(format!("\"{path}\" is read-only.").into()) }
+		fn list(&self, path:&str) -> Result<Vec<String>, Box<dyn Error>> { StdFileSystem.list(path) }
+	}
+
+	/// A filesystem that forwards to the OS and counts the existence checks.
+	#[derive(Default)]
+	struct CountingFileSystem {
+		exists_calls:AtomicU32
+	}
+	impl FileSystem for CountingFileSystem {
+		fn exists(&self, path:&str) -> bool { self.exists_calls.fetch_add(1, Ordering::SeqCst); StdFileSystem.exists(path) }
+		fn is_accessible(&self, path:&str) -> bool { StdFileSystem.is_accessible(path) }
+		fn create(&self, path:&str, is_dir:bool) -> Result<(), Box<dyn Error>> { StdFileSystem.create(path, is_dir) }
+		fn read(&self, path:&str) -> Result<Vec<u8>, Box<dyn Error>> { StdFileSystem.read(path) }
+		fn write_at(&self, path:&str, offset:Option<u64>, data:&[u8]) -> Result<(), Box<dyn Error>> { StdFileSystem.write_at(path, offset, data) }
+		fn remove(&self, path:&str) -> Result<(), Box<dyn Error>> { StdFileSystem.remove(path) }
+		fn list(&self, path:&str) -> Result<Vec<String>, Box<dyn Error>> { StdFileSystem.list(path) }
+	}
+
+
+
+	#[test]
+	fn test_mounted_file_system_is_used() {
+		let temp_dir:TempDirRef = TempDirRef::new("file_system_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/read_only/file.txt";
+		file.write("contents").unwrap();
+
+		mount_file_system(&(temp_dir.path() + "/read_only"), Arc::new(ReadOnlyFileSystem));
+		assert_eq!(file.read().unwrap(), "contents");
+		assert!(file.write("changed").is_err());
+		assert!(file.delete().is_err());
+		assert_eq!((temp_dir.path() + "/read_only").list_files(), vec![file.clone()]);
+		assert!((temp_dir.path() + "/other.txt").write("writable").is_ok());
+
+		unmount_file_system(&(temp_dir.path() + "/read_only"));
+		file.write("changed").unwrap();
+		assert_eq!(file.read().unwrap(), "changed");
+	}
+
+	#[test]
+	fn test_mount_root_must_match_whole_nodes() {
+		let temp_dir:TempDirRef = TempDirRef::new("file_system_test_").unwrap();
+		mount_file_system(&(temp_dir.path() + "/data"), Arc::new(ReadOnlyFileSystem));
+		assert!((temp_dir.path() + "/data/file.txt").write("contents").is_err());
+		assert!((temp_dir.path() + "/data_other/file.txt").write("contents").is_ok());
+		unmount_file_system(&(temp_dir.path() + "/data"));
+	}
+
+	#[test]
+	fn test_copy_between_file_systems() {
+		let temp_dir:TempDirRef = TempDirRef::new("file_system_test_").unwrap();
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let disk_file:FileRef = temp_dir.path() + "/file.txt";
+		disk_file.write("contents").unwrap();
+
+		assert_eq!(disk_file.copy_to(&memory_fs.file_ref("copy/file.txt")).unwrap(), 8);
+		assert_eq!(memory_fs.file_ref("copy/file.txt").read().unwrap(), "contents");
+		assert_eq!(memory_fs.file_ref("copy/file.txt").copy_to(&(temp_dir.path() + "/back.txt")).unwrap(), 8);
+		assert_eq!((temp_dir.path() + "/back.txt").read().unwrap(), "contents");
+	}
+	#[test]
+	fn test_write_does_not_check_existence() {
+		let temp_dir:TempDirRef = TempDirRef::new("file_system_test_").unwrap();
+		let counting_dir:FileRef = temp_dir.path() + "/counted";
+		counting_dir.create().unwrap();
+		let file_system:Arc<CountingFileSystem> = Arc::new(CountingFileSystem::default());
+		mount_file_system(&counting_dir, file_system.clone());
+
+		// The write is attempted right away, only the parent dir is checked when creating the file.
+		(counting_dir.clone() + "/file.txt").write("contents").unwrap();
+		assert_eq!(file_system.exists_calls.load(Ordering::SeqCst), 1);
+		(counting_dir.clone() + "/file.txt").write("changed").unwrap();
+		assert_eq!(file_system.exists_calls.load(Ordering::SeqCst), 1);
+		unmount_file_system(&counting_dir);
+	}
+
+	#[test]
+	fn test_fs_is_case_sensitive() {
+		let temp_dir:TempDirRef = TempDirRef::new("case_probe_test_").unwrap();
+		let missing:FileRef = temp_dir.path() + "/missing/file.txt";
+		assert_eq!(missing.fs_is_case_sensitive().unwrap(), !cfg!(any(windows, target_os="macos")));
+		assert_eq!(std::fs::read_dir(temp_dir.path().path()).unwrap().count(), 0);
+
+		let memory_fs:MemoryFs = MemoryFs::new();
+		memory_fs.file_ref("data/file.txt").write("contents").unwrap();
+		assert!(memory_fs.file_ref("data/file.txt").fs_is_case_sensitive().unwrap());
+		assert_eq!(memory_fs.file_ref("data").list_files().len(), 1);
+	}
+
+	#[test]
+	fn test_is_accessible_uses_actual_type() {
+		let temp_dir:TempDirRef = TempDirRef::new("accessible_test_").unwrap();
+		let dir_with_extension:FileRef = temp_dir.path() + "/archive.d";
+		std::fs::create_dir(dir_with_extension.os_path()).unwrap();
+		let file_without_extension:FileRef = temp_dir.path() + "/README";
+		std::fs::write(file_without_extension.os_path(), "readme").unwrap();
+
+		assert!(StdFileSystem.is_accessible(dir_with_extension.path()));
+		assert!(StdFileSystem.is_accessible(file_without_extension.path()));
+		assert!(!StdFileSystem.is_accessible((temp_dir.path() + "/missing").path()));
+	}
+
+	#[test]
+	#[cfg(target_os="linux")]
+	fn test_list_skips_non_utf8_names() {
+		use std::{ ffi::OsStr, os::unix::ffi::OsStrExt };
+
+		let temp_dir:TempDirRef = TempDirRef::new("list_test_").unwrap();
+		(temp_dir.path() + "/file.txt").write("contents").unwrap();
+		std::fs::write(temp_dir.path().os_path().join(OsStr::from_bytes(b"invalid_\xFF.txt")), "invalid").unwrap();
+
+		assert_eq!(StdFileSystem.list(temp_dir.path().path()).unwrap(), vec![(temp_dir.path() + "/file.txt").path().to_owned()]);
+		assert_eq!(temp_dir.path().list_files(), vec![temp_dir.path() + "/file.txt"]);
+	}
+}
\ No newline at end of file
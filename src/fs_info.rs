@@ -0,0 +1,131 @@
+use std::{ error::Error, io, path::PathBuf };
+use crate::{ FileOperation, FileRef };
+
+
+
+/// Information about the filesystem a path is stored on, so operations can pick a strategy the filesystem supports.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FsInfo {
+	pub fs_type:String,
+	pub max_name_length:u64,
+	pub supports_symlinks:bool,
+	pub supports_reflink:bool,
+	pub supports_sparse:bool,
+	pub supports_xattrs:bool
+}
+impl FsInfo {
+
+	/// Create the info for a filesystem type, filling in the support flags known for that type.
+	#[cfg(not(windows))]
+	fn from_unix_type(fs_type:&str, max_name_length:u64) -> FsInfo {
+		let is_fat:bool = matches!(fs_type, "vfat" | "msdos" | "exfat");
+		FsInfo {
+			fs_type: fs_type.to_string(),
+			max_name_length,
+			supports_symlinks: !is_fat,
+			supports_reflink: matches!(fs_type, "btrfs" | "xfs" | "bcachefs" | "apfs"),
+			supports_sparse: !is_fat && fs_type != "hfs",
+			supports_xattrs: matches!(fs_type, "ext4" | "xfs" | "btrfs" | "bcachefs" | "f2fs" | "zfs" | "tmpfs" | "overlay" | "apfs" | "hfs")
+		}
+	}
+}
+
+
+
+impl FileRef {
+
+	/* FILESYSTEM INFO METHODS */
+
+	/// Get information about the filesystem the path is stored on. If the path does not exist, the closest existing dir above it is used. Filesystem types are named as the OS names them, like 'ext4', 'tmpfs', 'apfs' or 'NTFS'.
+	pub fn fs_info(&self) -> Result<FsInfo, Box<dyn Error>> {
+		if self.mounted_file_system().is_some() {
+			return Err(self.error(FileOperation::Scan, io::Error::new(io::ErrorKind::Unsupported, "Filesystem info is only available on the filesystem of the OS.")));
+		}
+		let existing:FileRef = std::iter::successors(Some(self.clone().absolute()), |path| path.parent_dir().ok().filter(|parent| parent != path))
+			.find(|path| std::fs::metadata(path.os_path()).is_ok())
+			.ok_or_else(|| self.error(FileOperation::Scan, "No existing path found to query."))?;
+		query_fs_info(existing.os_path()).map_err(|error| self.error(FileOperation::Scan, error))
+	}
+}
+
+
+
+/// Query the filesystem using statfs, which identifies the filesystem by its magic number on Linux.
+#[cfg(all(target_os="linux", feature="libc"))]
+fn query_fs_info(path:PathBuf) -> io::Result<FsInfo> {
+	const FS_TYPES:[(i64, &str); 20] = [
+		(0xEF53, "ext4"), (0x58465342, "xfs"), (0x9123683E, "btrfs"), (0xCA451A4E, "bcachefs"), (0xF2F52010, "f2fs"), (0x2FC12FC1, "zfs"),
+		(0x01021994, "tmpfs"), (0x858458F6, "ramfs"), (0x794C7630, "overlay"), (0x73717368, "squashfs"), (0x6969, "nfs"), (0xFF534D42, "cifs"),
+		(0xFE534D42, "smb2"), (0x65735546, "fuse"), (0x4D44, "vfat"), (0x2011BAB0, "exfat"), (0x5346544E, "ntfs"), (0x9FA0, "proc"),
+		(0x62656572, "sysfs"), (0x9660, "iso9660")
+	];
+
+	let statfs:libc::statfs = statfs(path)?;
+	let magic:i64 = statfs.f_type as i64;
+	let fs_type:String = FS_TYPES.iter().find(|(fs_magic, _)| *fs_magic == magic).map(|(_, name)| name.to_string()).unwrap_or_else(|| format!("{magic:#x}"));
+	Ok(FsInfo::from_unix_type(&fs_type, statfs.f_namelen as u64))
+}
+
+/// Query the filesystem using statfs, which names the filesystem type on macOS.
+#[cfg(all(target_os="macos", feature="libc"))]
+fn query_fs_info(path:PathBuf) -> io::Result<FsInfo> {
+	use std::{ ffi::{ CStr, CString }, os::unix::ffi::OsStrExt };
+
+	let statfs:libc::statfs = statfs(path.clone())?;
+	let fs_type:String = unsafe { CStr::from_ptr(statfs.f_fstypename.as_ptr()) }.to_string_lossy().into_owned();
+	let c_path:CString = CString::new(path.as_os_str().as_bytes()).map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+	let max_name_length:i64 = unsafe { libc::pathconf(c_path.as_ptr(), libc::_PC_NAME_MAX) } as i64;
+	Ok(FsInfo::from_unix_type(&fs_type, if max_name_length > 0 { max_name_length as u64 } else { 255 }))
+}
+
+/// Call statfs on a path.
+#[cfg(all(any(target_os="linux", target_os="macos"), feature="libc"))]
+fn statfs(path:PathBuf) -> io::Result<libc::statfs> {
+	use std::{ ffi::CString, os::unix::ffi::OsStrExt };
+
+	let c_path:CString = CString::new(path.as_os_str().as_bytes()).map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+	let mut statfs:libc::statfs = unsafe { std::mem::zeroed() };
+	if unsafe { libc::statfs(c_path.as_ptr(), &mut statfs) } != 0 {
+		return Err(io::Error::last_os_error());
+	}
+	Ok(statfs)
+}
+
+/// Query the volume containing the path, which reports its type and supported features on Windows.
+#[cfg(windows)]
+fn query_fs_info(path:PathBuf) -> io::Result<FsInfo> {
+	use std::{ iter::once, os::windows::ffi::OsStrExt };
+	use windows_sys::Win32::Storage::FileSystem::{ GetVolumeInformationW, GetVolumePathNameW };
+
+	const FILE_SUPPORTS_SPARSE_FILES:u32 = 0x00000040;
+	const FILE_SUPPORTS_REPARSE_POINTS:u32 = 0x00000080;
+	const FILE_SUPPORTS_EXTENDED_ATTRIBUTES:u32 = 0x00800000;
+	const FILE_SUPPORTS_BLOCK_REFCOUNTING:u32 = 0x08000000;
+
+	let wide_path:Vec<u16> = path.as_os_str().encode_wide().chain(once(0)).collect();
+	let mut volume_path:Vec<u16> = vec![0; wide_path.len().max(261)];
+	if unsafe { GetVolumePathNameW(wide_path.as_ptr(), volume_path.as_mut_ptr(), volume_path.len() as u32) } == 0 {
+		return Err(io::Error::last_os_error());
+	}
+	let mut max_name_length:u32 = 0;
+	let mut flags:u32 = 0;
+	let mut fs_name:Vec<u16> = vec![0; 261];
+	if unsafe { GetVolumeInformationW(volume_path.as_ptr(), std::ptr::null_mut(), 0, std::ptr::null_mut(), &mut max_name_length, &mut flags, fs_name.as_mut_ptr(), fs_name.len() as u32) } == 0 {
+		return Err(io::Error::last_os_error());
+	}
+	let fs_name_length:usize = fs_name.iter().position(|character| *character == 0).unwrap_or(fs_name.len());
+	Ok(FsInfo {
+		fs_type: String::from_utf16_lossy(&fs_name[..fs_name_length]),
+		max_name_length: max_name_length as u64,
+		supports_symlinks: flags & FILE_SUPPORTS_REPARSE_POINTS != 0,
+		supports_reflink: flags & FILE_SUPPORTS_BLOCK_REFCOUNTING != 0,
+		supports_sparse: flags & FILE_SUPPORTS_SPARSE_FILES != 0,
+		supports_xattrs: flags & FILE_SUPPORTS_EXTENDED_ATTRIBUTES != 0
+	})
+}
+
+/// Other platforms, and unix without the 'libc' feature, can not be queried, so assume a common unix filesystem.
+#[cfg(not(any(all(any(target_os="linux", target_os="macos"), feature="libc"), windows)))]
+fn query_fs_info(_path:PathBuf) -> io::Result<FsInfo> {
+	Ok(FsInfo::from_unix_type("unknown", 255))
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, FsInfo, MemoryFs, TempDirRef };
+
+
+
+	#[test]
+	fn test_fs_info() {
+		let temp_dir:TempDirRef = TempDirRef::new("fs_info_test_").unwrap();
+		let info:FsInfo = temp_dir.path().fs_info().unwrap();
+		assert!(!info.fs_type.is_empty());
+		assert!(info.max_name_length >= 255);
+		#[cfg(unix)]
+		assert!(info.supports_symlinks);
+
+		let missing:FileRef = temp_dir.path() + "/missing/file.txt";
+		assert_eq!(missing.fs_info().unwrap(), info);
+	}
+
+	#[test]
+	fn test_fs_info_on_memory_fs() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		assert!(memory_fs.file_ref("file.txt").fs_info().is_err());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,255 @@
+use std::{ collections::HashSet, error::Error };
+use crate::{ DISK_SEPARATOR, FileRef, FileScanner, SEPARATOR };
+
+
+
+const ANY_DIRS_SEGMENT:&str = "**";
+const WILDCARD_CHARACTERS:[char; 3] = ['*', '?', '['];
+const MAX_BRACE_EXPANSIONS:usize = 10_000;
+
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlobPattern {
+	alternatives:Vec<Vec<String>>
+}
+impl GlobPattern {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new glob pattern. Supports '*' for any characters within a path segment, '?' for a single character, '[abc]', '[a-z]' and '[!abc]' for character classes, '**' for any number of path segments, '{jpg,png}' for alternatives and '{1..9}' for numeric ranges. Fails if the braces expand into more than 10000 patterns.
+	pub fn new(pattern:&str) -> Result<GlobPattern, Box<dyn Error>> {
+		Ok(GlobPattern::from_expanded(&Self::expand_braces(&pattern.replace('\\', SEPARATOR))?))
+	}
+
+	/// Create a glob pattern matching any of the given patterns, which should not contain braces.
+	fn from_expanded(patterns:&[String]) -> GlobPattern {
+		GlobPattern {
+			alternatives: patterns.iter().map(|alternative| alternative.split(SEPARATOR).filter(|segment| !segment.is_empty()).map(|segment| segment.to_string()).collect()).collect()
+		}
+	}
+
+	/// Expand all braces in a pattern into the patterns they stand for, like 'a.{jpg,png}' into 'a.jpg' and 'a.png', or 'v{1..3}' into 'v1', 'v2' and 'v3'. Braces without a comma or range, or without a closing brace, are kept as literal characters. Fails if the braces expand into more than 10000 patterns.
+	pub(crate) fn expand_braces(pattern:&str) -> Result<Vec<String>, Box<dyn Error>> {
+		Self::expand_braces_from(pattern, 0)
+	}
+
+	/// Expand the braces in a pattern, starting the search for an opening brace at the given index.
+	fn expand_braces_from(pattern:&str, search_start:usize) -> Result<Vec<String>, Box<dyn Error>> {
+		let Some(open_index) = pattern[search_start..].find('{').map(|offset| search_start + offset) else {
+			return Ok(vec![pattern.to_string()]);
+		};
+
+		// Find the matching closing brace and the commas separating top-level options.
+		let mut depth:usize = 0;
+		let mut separators:Vec<usize> = vec![open_index];
+		let mut close_index:Option<usize> = None;
+		for (offset, character) in pattern[open_index..].char_indices() {
+			match character {
+				'{' => depth += 1,
+				'}' => {
+					depth -= 1;
+					if depth == 0 {
+						close_index = Some(open_index + offset);
+						break;
+					}
+				},
+				',' if depth == 1 => separators.push(open_index + offset),
+				_ => {}
+			}
+		}
+		let Some(close_index) = close_index else {
+			return Ok(vec![pattern.to_string()]);
+		};
+
+		// Build the options, or keep the brace as a literal if it has none.
+		let options:Vec<String> = if separators.len() > 1 {
+			separators.push(close_index);
+			separators.windows(2).map(|bounds| pattern[bounds[0] + 1..bounds[1]].to_string()).collect()
+		} else {
+			match Self::expand_range(&pattern[open_index + 1..close_index])? {
+				Some(options) => options,
+				None => return Self::expand_braces_from(pattern, open_index + 1)
+			}
+		};
+		let prefix:&str = &pattern[..open_index];
+		let suffix:&str = &pattern[close_index + 1..];
+		let mut expanded:Vec<String> = Vec::new();
+		for option in options {
+			expanded.extend(Self::expand_braces_from(&format!("{prefix}{option}{suffix}"), open_index)?);
+			if expanded.len() > MAX_BRACE_EXPANSIONS {
+				return Err(format!("Could not expand glob pattern \"{pattern}\". Braces expand into more than {MAX_BRACE_EXPANSIONS} patterns.").into());
+			}
+		}
+		Ok(expanded)
+	}
+
+	/// Expand a numeric range like '1..9' or '09..12' into its values. Ranges can count down, and values are zero-padded if either bound is. Returns None if the text is not a range, and fails if the range has more than 10000 values.
+	fn expand_range(range:&str) -> Result<Option<Vec<String>>, Box<dyn Error>> {
+		let Some((start_text, end_text)) = range.split_once("..") else { return Ok(None) };
+		let (Ok(start), Ok(end)) = (start_text.parse::<i64>(), end_text.parse::<i64>()) else { return Ok(None) };
+		if start.abs_diff(end) >= MAX_BRACE_EXPANSIONS as u64 {
+			return Err(format!("Could not expand range \"{{{range}}}\". Ranges can contain at most {MAX_BRACE_EXPANSIONS} values.").into());
+		}
+		let is_padded:bool = [start_text, end_text].iter().any(|bound| bound.trim_start_matches('-').len() > 1 && bound.trim_start_matches('-').starts_with('0'));
+		let width:usize = if is_padded { start_text.len().max(end_text.len()) } else { 0 };
+		let values:Vec<i64> = if start <= end { (start..=end).collect() } else { (end..=start).rev().collect() };
+		Ok(Some(values.iter().map(|value| format!("{value:0width$}")).collect()))
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the maximum amount of path segments a matching path can have. Returns None if the pattern contains '**'.
+	pub fn max_depth(&self) -> Option<usize> {
+		if self.alternatives.iter().flatten().any(|segment| segment == ANY_DIRS_SEGMENT) { None } else { self.alternatives.iter().map(|segments| segments.len()).max() }
+	}
+
+	/// Check if a path segment contains wildcards, rather than only literal characters.
+	pub(crate) fn is_dynamic_segment(segment:&str) -> bool {
+		segment.contains(WILDCARD_CHARACTERS)
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Check if the given path matches the pattern.
+	pub fn matches(&self, path:&str) -> bool {
+		let path:String = path.replace('\\', SEPARATOR);
+		let path_segments:Vec<&str> = path.split(SEPARATOR).filter(|segment| !segment.is_empty()).collect();
+		self.alternatives.iter().any(|segments| {
+			let pattern_segments:Vec<&str> = segments.iter().map(|segment| segment.as_str()).collect();
+			Self::segments_match(&pattern_segments, &path_segments)
+		})
+	}
+
+	/// Check if a list of path segments matches a list of pattern segments.
+	fn segments_match(pattern:&[&str], path:&[&str]) -> bool {
+		match pattern.first() {
+			None => path.is_empty(),
+			Some(&ANY_DIRS_SEGMENT) => (0..=path.len()).any(|skip| Self::segments_match(&pattern[1..], &path[skip..])),
+			Some(pattern_segment) => !path.is_empty() && Self::segment_matches(&pattern_segment.chars().collect::<Vec<char>>(), &path[0].chars().collect::<Vec<char>>()) && Self::segments_match(&pattern[1..], &path[1..])
+		}
+	}
+
+	/// Check if a single path segment matches a single pattern segment.
+	fn segment_matches(pattern:&[char], text:&[char]) -> bool {
+		match pattern.first() {
+			None => text.is_empty(),
+			Some('*') => (0..=text.len()).any(|skip| Self::segment_matches(&pattern[1..], &text[skip..])),
+			Some('?') => !text.is_empty() && Self::segment_matches(&pattern[1..], &text[1..]),
+			Some('[') => {
+				match (pattern.iter().skip(2).position(|character| *character == ']'), text.first()) {
+					(Some(close_index), Some(character)) => {
+						let class:&[char] = &pattern[1..close_index + 2];
+						Self::class_matches(class, *character) && Self::segment_matches(&pattern[close_index + 3..], &text[1..])
+					},
+					(None, Some(character)) => *character == '[' && Self::segment_matches(&pattern[1..], &text[1..]),
+					_ => false
+				}
+			},
+			Some(character) => text.first() == Some(character) && Self::segment_matches(&pattern[1..], &text[1..])
+		}
+	}
+
+	/// Check if a character matches the contents of a character class, excluding the brackets.
+	fn class_matches(class:&[char], character:char) -> bool {
+		let (negated, class) = match class.first() {
+			Some('!') | Some('^') => (true, &class[1..]),
+			_ => (false, class)
+		};
+		let mut index:usize = 0;
+		let mut found:bool = false;
+		while index < class.len() {
+			if index + 2 < class.len() && class[index + 1] == '-' {
+				found |= class[index] <= character && character <= class[index + 2];
+				index += 3;
+			} else {
+				found |= class[index] == character;
+				index += 1;
+			}
+		}
+		found != negated
+	}
+}
+
+
+
+/// Find all files and dirs matching the given glob pattern. Fails if the braces in the pattern expand into more than 10000 patterns. Only the part of the tree below the static prefix of the pattern is scanned, and dirs deeper than the pattern can match are skipped. Results use the same form as the pattern, so relative patterns give relative paths. Braces are expanded first, and alternatives below the same static prefix are matched in a single scan, so every dir is walked at most once.
+pub fn glob(pattern:&str) -> Result<impl Iterator<Item = FileRef>, Box<dyn Error>> {
+	let mut literals:Vec<String> = Vec::new();
+	let mut scans:Vec<GlobScan> = Vec::new();
+	for expanded in GlobPattern::expand_braces(&pattern.replace('\\', SEPARATOR))? {
+		let segments:Vec<String> = expanded.split(SEPARATOR).enumerate().filter(|(index, segment)| *index == 0 || !segment.is_empty()).map(|(_, segment)| segment.to_string()).collect();
+		let static_length:usize = segments.iter().position(|segment| GlobPattern::is_dynamic_segment(segment)).unwrap_or(segments.len());
+		if static_length == segments.len() {
+			literals.push(segments.join(SEPARATOR));
+		} else {
+			GlobScan::add(&mut scans, segments[..static_length].to_vec(), segments[static_length..].join(SEPARATOR));
+		}
+	}
+
+	// Without wildcards, a pattern can only match itself. Otherwise scan each static prefix once.
+	let mut found:HashSet<String> = HashSet::new();
+	let literals = literals.into_iter().map(|literal| FileRef::new(&literal)).filter(|file| file.exists());
+	Ok(literals.chain(scans.into_iter().flat_map(|scan| scan.run())).filter(move |file| found.insert(file.path().to_string())))
+}
+
+
+
+/// A scan of a single static prefix, matching the paths below it against the remaining patterns of all alternatives sharing that prefix.
+struct GlobScan {
+	prefix:Vec<String>,
+	remaining:Vec<String>
+}
+impl GlobScan {
+
+	/// Add the remaining pattern of an alternative to the scan of its prefix. Scans of prefixes nested in each other are merged into the outer one.
+	fn add(scans:&mut Vec<GlobScan>, prefix:Vec<String>, remaining:String) {
+		let is_absolute = |prefix:&[String]| prefix.first().is_some_and(|segment| segment.is_empty() || segment.contains(DISK_SEPARATOR));
+		let is_nested = |outer:&[String], inner:&[String]| outer.len() <= inner.len() && inner[..outer.len()] == *outer && is_absolute(outer) == is_absolute(inner);
+		if let Some(scan) = scans.iter_mut().find(|scan| is_nested(&scan.prefix, &prefix)) {
+			let nested:Vec<String> = prefix[scan.prefix.len()..].iter().cloned().chain([remaining]).collect();
+			scan.remaining.push(nested.join(SEPARATOR));
+			return;
+		}
+
+		// Merge all existing scans nested in the new prefix into it.
+		let mut merged:Vec<String> = vec![remaining];
+		let mut index:usize = 0;
+		while index < scans.len() {
+			if is_nested(&prefix, &scans[index].prefix) {
+				let nested:GlobScan = scans.remove(index);
+				let nested_prefix:&[String] = &nested.prefix[prefix.len()..];
+				merged.extend(nested.remaining.iter().map(|remaining| nested_prefix.iter().cloned().chain([remaining.clone()]).collect::<Vec<String>>().join(SEPARATOR)));
+			} else {
+				index += 1;
+			}
+		}
+		scans.push(GlobScan { prefix, remaining: merged });
+	}
+
+	/// Scan the prefix and return all entries matching any of the remaining patterns.
+	fn run(self) -> impl Iterator<Item = FileRef> {
+		let is_relative_root:bool = self.prefix.is_empty();
+		let prefix:String = self.prefix.join(SEPARATOR);
+		let remaining:GlobPattern = GlobPattern::from_expanded(&self.remaining);
+		let root_dir:FileRef = if is_relative_root { FileRef::working_dir() } else if prefix.is_empty() { FileRef::new(SEPARATOR) } else { FileRef::new(&prefix) };
+		let scan_root:FileRef = root_dir.absolute().trim_dir_separator();
+		let recurse_root:FileRef = scan_root.clone();
+		let max_depth:Option<usize> = remaining.max_depth();
+		let scanner:FileScanner = scan_root.scanner().include_files().include_dirs().recurse_filter(move |dir| max_depth.is_none_or(|max_depth| dir.relative_to_dir(&recurse_root).depth() < max_depth));
+		scanner.filter_map(move |entry| {
+			let relative:FileRef = entry.relative_to_dir(&scan_root);
+			if !remaining.matches(relative.path()) {
+				None
+			} else if is_relative_root {
+				Some(relative)
+			} else {
+				Some(FileRef::new(&format!("{prefix}{SEPARATOR}{}", relative.path())))
+			}
+		})
+	}
+}
\ No newline at end of file
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, GlobPattern, TempDirRef, glob };
+
+
+
+	#[test]
+	fn test_literal() {
+		let pattern:GlobPattern = GlobPattern::new("dir/file.txt").unwrap();
+		assert!(pattern.matches("dir/file.txt"));
+		assert!(!pattern.matches("dir/file.txt2"));
+		assert!(!pattern.matches("file.txt"));
+	}
+
+	#[test]
+	fn test_wildcard() {
+		let pattern:GlobPattern = GlobPattern::new("dir/*.txt").unwrap();
+		assert!(pattern.matches("dir/file.txt"));
+		assert!(pattern.matches("dir/.txt"));
+		assert!(!pattern.matches("dir/sub/file.txt"));
+		assert!(!pattern.matches("dir/file.png"));
+	}
+
+	#[test]
+	fn test_single_character() {
+		let pattern:GlobPattern = GlobPattern::new("file?.txt").unwrap();
+		assert!(pattern.matches("file1.txt"));
+		assert!(!pattern.matches("file.txt"));
+		assert!(!pattern.matches("file12.txt"));
+	}
+
+	#[test]
+	fn test_character_class() {
+		let pattern:GlobPattern = GlobPattern::new("file[0-2a].txt").unwrap();
+		assert!(pattern.matches("file1.txt"));
+		assert!(pattern.matches("filea.txt"));
+		assert!(!pattern.matches("file3.txt"));
+
+		let negated:GlobPattern = GlobPattern::new("file[!0-2].txt").unwrap();
+		assert!(negated.matches("file3.txt"));
+		assert!(!negated.matches("file1.txt"));
+	}
+
+	#[test]
+	fn test_any_dirs() {
+		let pattern:GlobPattern = GlobPattern::new("photos/**/*.jpg").unwrap();
+		assert!(pattern.matches("photos/image.jpg"));
+		assert!(pattern.matches("photos/2024/06/image.jpg"));
+		assert!(!pattern.matches("videos/image.jpg"));
+		assert!(!pattern.matches("photos/2024/image.png"));
+	}
+
+	#[test]
+	fn test_separators() {
+		let pattern:GlobPattern = GlobPattern::new("dir\\*.txt").unwrap();
+		assert!(pattern.matches("dir\\file.txt"));
+		assert!(pattern.matches("dir//file.txt"));
+	}
+
+	#[test]
+	fn test_braces() {
+		let pattern:GlobPattern = GlobPattern::new("img/*.{jpg,png}").unwrap();
+		assert!(pattern.matches("img/a.jpg"));
+		assert!(pattern.matches("img/b.png"));
+		assert!(!pattern.matches("img/c.gif"));
+
+		let pattern:GlobPattern = GlobPattern::new("{src,tests/**}/*.rs").unwrap();
+		assert!(pattern.matches("src/lib.rs"));
+		assert!(pattern.matches("tests/unit/a.rs"));
+		assert!(!pattern.matches("benches/a.rs"));
+		assert_eq!(pattern.max_depth(), None);
+	}
+
+	#[test]
+	fn test_brace_expansion() {
+		assert_eq!(GlobPattern::expand_braces("a.{jpg,png}").unwrap(), vec!["a.jpg", "a.png"]);
+		assert_eq!(GlobPattern::expand_braces("{a,b{1,2}}x").unwrap(), vec!["ax", "b1x", "b2x"]);
+		assert_eq!(GlobPattern::expand_braces("log{1..3}").unwrap(), vec!["log1", "log2", "log3"]);
+		assert_eq!(GlobPattern::expand_braces("{3..1}").unwrap(), vec!["3", "2", "1"]);
+		assert_eq!(GlobPattern::expand_braces("{09..11}").unwrap(), vec!["09", "10", "11"]);
+		assert_eq!(GlobPattern::expand_braces("{a}{b,c}").unwrap(), vec!["{a}b", "{a}c"]);
+		assert_eq!(GlobPattern::expand_braces("{a,b").unwrap(), vec!["{a,b"]);
+		assert!(GlobPattern::expand_braces("{0..10000}").is_err());
+		assert!(GlobPattern::expand_braces("{1..100}{1..100}{1..2}").is_err());
+		assert!(GlobPattern::new("{0..999999999999}").is_err());
+	}
+
+	#[test]
+	fn test_glob() {
+		let temp_dir:TempDirRef = TempDirRef::new("glob_test_").unwrap();
+		let root:String = temp_dir.path().path().to_string();
+		for path in ["photos/a.jpg", "photos/b.png", "photos/2024/c.jpg", "photos/2024/summer/d.jpg", "notes.txt"] {
+			(temp_dir.path() + "/" + path).write("contents").unwrap();
+		}
+
+		let mut found:Vec<String> = glob(&format!("{root}/photos/**/*.jpg")).unwrap().map(|file| file.path().to_string()).collect();
+		found.sort();
+		assert_eq!(found, vec![format!("{root}/photos/2024/c.jpg"), format!("{root}/photos/2024/summer/d.jpg"), format!("{root}/photos/a.jpg")]);
+
+		let found:Vec<FileRef> = glob(&format!("{root}/photos/*/*.jpg")).unwrap().collect();
+		assert_eq!(found, vec![FileRef::new(&format!("{root}/photos/2024/c.jpg"))]);
+
+		let found:Vec<FileRef> = glob(&format!("{root}/photos/*")).unwrap().collect();
+		assert_eq!(found.len(), 3);
+
+		// Patterns without wildcards only match themselves, if they exist.
+		assert_eq!(glob(&format!("{root}/notes.txt")).unwrap().count(), 1);
+		assert_eq!(glob(&format!("{root}/missing.txt")).unwrap().count(), 0);
+	}
+
+	#[test]
+	fn test_glob_braces() {
+		let temp_dir:TempDirRef = TempDirRef::new("glob_braces_test_").unwrap();
+		let root:String = temp_dir.path().path().to_string();
+		for path in ["a.jpg", "b.png", "c.gif", "log1.txt", "log2.txt", "log5.txt"] {
+			(temp_dir.path() + "/" + path).write("contents").unwrap();
+		}
+
+		let mut found:Vec<String> = glob(&format!("{root}/*.{{jpg,png}}")).unwrap().map(|file| file.path().to_string()).collect();
+		found.sort();
+		assert_eq!(found, vec![format!("{root}/a.jpg"), format!("{root}/b.png")]);
+
+		assert_eq!(glob(&format!("{root}/log{{1..3}}.txt")).unwrap().count(), 2);
+		assert_eq!(glob(&format!("{root}/{{*.jpg,a.*}}")).unwrap().count(), 1);
+	}
+
+	#[test]
+	fn test_glob_nested_brace_prefixes() {
+		let temp_dir:TempDirRef = TempDirRef::new("glob_braces_test_").unwrap();
+		let root:String = temp_dir.path().path().to_string();
+		for path in ["top.txt", "sub/a.txt", "sub/deeper/b.txt", "other/c.txt"] {
+			(temp_dir.path() + "/" + path).write("contents").unwrap();
+		}
+
+		let mut found:Vec<String> = glob(&format!("{root}/{{sub,sub/deeper,other}}/*.txt")).unwrap().map(|file| file.path().to_string()).collect();
+		found.sort();
+		assert_eq!(found, vec![format!("{root}/other/c.txt"), format!("{root}/sub/a.txt"), format!("{root}/sub/deeper/b.txt")]);
+		let mut found:Vec<String> = glob(&format!("{root}/**/*.{{txt,md}}")).unwrap().map(|file| file.path().to_string()).collect();
+		found.sort();
+		assert_eq!(found.len(), 4);
+	}
+
+	#[test]
+	fn test_glob_root() {
+		let found:Vec<FileRef> = glob("/*").unwrap().collect();
+		assert!(!found.is_empty());
+		assert!(found.iter().all(|file| file.path().starts_with('/') && file.depth() == 2));
+	}
+}
\ No newline at end of file
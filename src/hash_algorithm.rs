@@ -0,0 +1,159 @@
+use std::error::Error;
+use sha2::{ Digest, Sha256 };
+use crate::{ Access, Cancelled, FileOperation, FileRef, NoProgress, Progress, ProgressReader, ProgressTracker };
+
+
+
+const HASH_BUFFER_SIZE:usize = 64 * 1024;
+
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature="bincode", derive(bincode::Encode, bincode::Decode))]
+pub enum HashAlgorithm {
+	Crc32,
+	#[default]
+	Sha256
+}
+impl HashAlgorithm {
+
+	/// Get the lowercase name of the algorithm, as used in file extensions and manifests.
+	pub fn name(&self) -> &'static str {
+		match self {
+			HashAlgorithm::Crc32 => "crc32",
+			HashAlgorithm::Sha256 => "sha256"
+		}
+	}
+
+	/// Find the algorithm with the given name. The name is not case-sensitive.
+	pub fn from_name(name:&str) -> Option<HashAlgorithm> {
+		[HashAlgorithm::Crc32, HashAlgorithm::Sha256].into_iter().find(|algorithm| algorithm.name().eq_ignore_ascii_case(name))
+	}
+
+	/// Hash the given bytes, returning the digest as lowercase hex.
+	pub fn hash_bytes(&self, data:&[u8]) -> String {
+		let mut hasher:Hasher = Hasher::new(*self);
+		hasher.update(data);
+		hasher.finish()
+	}
+}
+
+
+
+pub(crate) enum Hasher {
+	Crc32(crc32fast::Hasher),
+	Sha256(Sha256)
+}
+impl Hasher {
+
+	/// Create a new hasher for the given algorithm.
+	pub(crate) fn new(algorithm:HashAlgorithm) -> Hasher {
+		match algorithm {
+			HashAlgorithm::Crc32 => Hasher::Crc32(crc32fast::Hasher::new()),
+			HashAlgorithm::Sha256 => Hasher::Sha256(Sha256::new())
+		}
+	}
+
+	/// Add data to the hash.
+	pub(crate) fn update(&mut self, data:&[u8]) {
+		match self {
+			Hasher::Crc32(hasher) => hasher.update(data),
+			Hasher::Sha256(hasher) => hasher.update(data)
+		}
+	}
+
+	/// Finish the hash, returning the digest as lowercase hex.
+	pub(crate) fn finish(self) -> String {
+		self.finish_bytes().iter().map(|byte| format!("{byte:02x}")).collect()
+	}
+
+	/// Finish the hash, returning the raw digest.
+	pub(crate) fn finish_bytes(self) -> Vec<u8> {
+		match self {
+			Hasher::Crc32(hasher) => hasher.finalize().to_be_bytes().to_vec(),
+			Hasher::Sha256(hasher) => hasher.finalize().to_vec()
+		}
+	}
+}
+
+
+
+impl FileRef {
+
+	/* HASHING METHODS */
+
+	/// Hash the contents of the file, returning the digest as lowercase hex. The file is streamed, so it never has to fit in memory.
+	pub fn hash(&self, algorithm:HashAlgorithm) -> Result<String, Box<dyn Error>> {
+		self.hash_with_progress(algorithm, &NoProgress)
+	}
+
+	/// Hash the contents of the file, reporting the bytes hashed so far to the progress. If the progress is cancelled, a `Cancelled` error with the number of bytes hashed so far is returned.
+	pub fn hash_with_progress(&self, algorithm:HashAlgorithm, progress:&dyn Progress) -> Result<String, Box<dyn Error>> {
+		use std::{ fs::File, io::Read };
+
+		if !self.exists() {
+			return Err(self.error(FileOperation::Read, "File does not exist."));
+		} else if self.is_dir_fs() {
+			return Err(self.error(FileOperation::Read, "Only able to hash files."));
+		}
+
+		let file:File = self.open_read_with_access(Access::Sequential)?;
+		let mut tracker:ProgressTracker = ProgressTracker::new(progress, file.metadata()?.len());
+		tracker.start_file(self);
+		let mut reader:ProgressReader<File> = tracker.reader(file);
+		let mut hasher:Hasher = Hasher::new(algorithm);
+		let mut buffer:Vec<u8> = vec![0; HASH_BUFFER_SIZE];
+		let mut bytes_hashed:u64 = 0;
+		loop {
+			let read_size:usize = match reader.read(&mut buffer) {
+				Ok(read_size) => read_size,
+				Err(_) if progress.is_cancelled() => return Err(Box::new(Cancelled::new(bytes_hashed))),
+				Err(error) => return Err(error.into())
+			};
+			if read_size == 0 {
+				break;
+			}
+			hasher.update(&buffer[..read_size]);
+			bytes_hashed += read_size as u64;
+		}
+		tracker.finish();
+		Ok(hasher.finish())
+	}
+
+
+
+	/* CHECKSUM FILE METHODS */
+
+	/// Get the checksum sidecar file of this file for the given algorithm, for example 'file.txt.sha256'.
+	pub fn checksum_file(&self, algorithm:HashAlgorithm) -> FileRef {
+		self.clone() + "." + algorithm.name()
+	}
+
+	/// Write a checksum sidecar file next to this file in the '<hash>  <name>' format used by tools like sha256sum. Returns the sidecar file.
+	pub fn write_checksum_file(&self, algorithm:HashAlgorithm) -> Result<FileRef, Box<dyn Error>> {
+		let checksum_file:FileRef = self.checksum_file(algorithm);
+		checksum_file.write(&format!("{}  {}\n", self.hash(algorithm)?, self.name()))?;
+		Ok(checksum_file)
+	}
+
+	/// Verify this file against its checksum sidecar file. Uses the first sidecar found for any of the supported algorithms. Returns false if the contents of the file do not match the stored checksum.
+	pub fn verify_checksum_file(&self) -> Result<bool, Box<dyn Error>> {
+		let algorithm:HashAlgorithm = match [HashAlgorithm::Sha256, HashAlgorithm::Crc32].into_iter().find(|algorithm| self.checksum_file(*algorithm).exists()) {
+			Some(algorithm) => algorithm,
+			None => return Err(self.error(FileOperation::Read, "No checksum file found."))
+		};
+		let checksum_file:FileRef = self.checksum_file(algorithm);
+
+		// Find the line of this file. The name can be prefixed with '*' to indicate binary mode.
+		for line in checksum_file.read()?.lines() {
+			if let Some((expected_hash, name)) = line.split_once(' ') {
+				let name:&str = name.trim_start_matches(' ').trim_start_matches('*');
+				if name == self.name() || FileRef::new(name).name() == self.name() {
+					return Ok(expected_hash.eq_ignore_ascii_case(&self.hash(algorithm)?));
+				}
+			}
+		}
+		Err(self.error(FileOperation::Read, format!("Checksum file \"{}\" contains no entry for the file.", checksum_file.path())))
+	}
+}
\ No newline at end of file
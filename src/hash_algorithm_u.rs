@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, HashAlgorithm, TempDirRef, TempFileRef };
+
+
+
+	#[test]
+	fn test_sha256() {
+		assert_eq!(HashAlgorithm::Sha256.hash_bytes(b""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+		assert_eq!(HashAlgorithm::Sha256.hash_bytes(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+		assert_eq!(HashAlgorithm::Sha256.hash_bytes(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"), "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+		assert_eq!(HashAlgorithm::Sha256.hash_bytes(&vec![b'a'; 1_000_000]), "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0");
+	}
+
+	#[test]
+	fn test_crc32() {
+		assert_eq!(HashAlgorithm::Crc32.hash_bytes(b""), "00000000");
+		assert_eq!(HashAlgorithm::Crc32.hash_bytes(b"123456789"), "cbf43926");
+	}
+
+	#[test]
+	fn test_from_name() {
+		assert_eq!(HashAlgorithm::from_name("SHA256"), Some(HashAlgorithm::Sha256));
+		assert_eq!(HashAlgorithm::from_name("crc32"), Some(HashAlgorithm::Crc32));
+		assert_eq!(HashAlgorithm::from_name("md4"), None);
+	}
+
+	#[test]
+	fn test_hash_file() {
+		let temp_file:TempFileRef = FileRef::temp("txt").unwrap();
+		let contents:String = "hashable contents ".repeat(10_000);
+		temp_file.write(&contents).unwrap();
+		assert_eq!(temp_file.hash(HashAlgorithm::Sha256).unwrap(), HashAlgorithm::Sha256.hash_bytes(contents.as_bytes()));
+	}
+
+	#[test]
+	fn test_checksum_file() {
+		let temp_dir:TempDirRef = TempDirRef::new("checksum_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/release.bin";
+		file.write("abc").unwrap();
+
+		let checksum_file:FileRef = file.write_checksum_file(HashAlgorithm::Sha256).unwrap();
+		assert_eq!(checksum_file.name(), "release.bin.sha256");
+		assert_eq!(checksum_file.read().unwrap(), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  release.bin\n");
+		assert!(file.verify_checksum_file().unwrap());
+
+		file.write("abd").unwrap();
+		assert!(!file.verify_checksum_file().unwrap());
+	}
+
+	#[test]
+	fn test_verify_sha256sum_output() {
+		let temp_dir:TempDirRef = TempDirRef::new("checksum_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/release.bin";
+		file.write("abc").unwrap();
+		file.checksum_file(HashAlgorithm::Sha256).write("0000  other.bin\nBA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD *dist/release.bin\n").unwrap();
+		assert!(file.verify_checksum_file().unwrap());
+	}
+
+	#[test]
+	fn test_verify_missing_checksum_file() {
+		let temp_dir:TempDirRef = TempDirRef::new("checksum_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/release.bin";
+		file.write("abc").unwrap();
+		assert!(file.verify_checksum_file().is_err());
+	}
+
+	#[test]
+	fn test_verify_published_vectors() {
+		let temp_dir:TempDirRef = TempDirRef::new("checksum_test_").unwrap();
+		let vectors:[(&[u8], HashAlgorithm, &str); 6] = [
+			(b"", HashAlgorithm::Sha256, "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+			(b"abc", HashAlgorithm::Sha256, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+			(b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu", HashAlgorithm::Sha256, "cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1"),
+			(b"The quick brown fox jumps over the lazy dog", HashAlgorithm::Sha256, "d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592"),
+			(b"123456789", HashAlgorithm::Crc32, "cbf43926"),
+			(b"The quick brown fox jumps over the lazy dog", HashAlgorithm::Crc32, "414fa339")
+		];
+		for (index, (data, algorithm, expected_hash)) in vectors.into_iter().enumerate() {
+			let file:FileRef = temp_dir.path() + &format!("/vector_{index}.bin");
+			file.write_bytes(data).unwrap();
+			assert_eq!(file.hash(algorithm).unwrap(), expected_hash);
+			file.checksum_file(algorithm).write(&format!("{expected_hash}  {}\n", file.name())).unwrap();
+			assert!(file.verify_checksum_file().unwrap());
+			file.checksum_file(algorithm).delete().unwrap();
+		}
+	}
+}
\ No newline at end of file
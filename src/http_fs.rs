@@ -0,0 +1,131 @@
+use std::{ error::Error, io::Read, sync::Arc };
+use ureq::{ Agent, AgentBuilder, Response };
+use crate::{ FileOperation, FileRef, FileSystem, SEPARATOR, mount_file_system };
+
+
+
+const HTTP_PARTIAL_CONTENT:u16 = 206;
+
+
+
+pub struct HttpFileSystem {
+	root:String,
+	base_url:String,
+	agent:Agent
+}
+impl HttpFileSystem {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new read-only filesystem that serves the files below the base url. A FileRef like 'root/dir/file.txt' is requested from 'base_url/dir/file.txt'. Call `mount` to bind it to the root.
+	pub fn new(root:&str, base_url:&str) -> HttpFileSystem {
+		HttpFileSystem {
+			root: FileRef::new(root).path().trim_end_matches(SEPARATOR).to_owned(),
+			base_url: base_url.trim_end_matches('/').to_owned(),
+			agent: AgentBuilder::new().build()
+		}
+	}
+
+	/// Mount the filesystem at its root. Returns the root dir.
+	pub fn mount(self) -> FileRef {
+		let root:FileRef = FileRef::new(&self.root);
+		mount_file_system(&root, Arc::new(self));
+		root
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Get the url of a path in the filesystem.
+	fn url(&self, path:&str) -> String {
+		self.base_url.clone() + &percent_encode(path.strip_prefix(&self.root).unwrap_or(path), true)
+	}
+
+	/// Read the full body of a response.
+	fn read_body(response:Response) -> Result<Vec<u8>, Box<dyn Error>> {
+		let mut body:Vec<u8> = Vec::new();
+		response.into_reader().read_to_end(&mut body)?;
+		Ok(body)
+	}
+}
+impl FileSystem for HttpFileSystem {
+	fn exists(&self, path:&str) -> bool {
+		path.trim_end_matches(SEPARATOR) == self.root || self.agent.head(&self.url(path)).call().is_ok()
+	}
+
+	fn is_accessible(&self, path:&str) -> bool {
+		self.exists(path)
+	}
+
+	fn create(&self, path:&str, _is_dir:bool) -> Result<(), Box<dyn Error>> {
+		Err(read_only_error(path))
+	}
+
+	fn read(&self, path:&str) -> Result<Vec<u8>, Box<dyn Error>> {
+		let response:Response = self.agent.get(&self.url(path)).call().map_err(|error| FileRef::new(path).error(FileOperation::Read, error))?;
+		HttpFileSystem::read_body(response)
+	}
+
+	fn size(&self, path:&str) -> Result<u64, Box<dyn Error>> {
+		let response:Response = self.agent.head(&self.url(path)).call().map_err(|error| FileRef::new(path).error(FileOperation::Read, error))?;
+		content_length(&response).ok_or_else(|| FileRef::new(path).error(FileOperation::Read, "Server did not send a content length."))
+	}
+
+	fn read_range(&self, path:&str, start:u64, end:u64) -> Result<Vec<u8>, Box<dyn Error>> {
+		if end <= start {
+			return Ok(Vec::new());
+		}
+		let response:Response = self.agent.get(&self.url(path)).set("Range", &format!("bytes={start}-{}", end - 1)).call().map_err(|error| FileRef::new(path).error(FileOperation::Read, error))?;
+
+		// Servers without range support return the full file.
+		let partial:bool = response.status() == HTTP_PARTIAL_CONTENT;
+		let body:Vec<u8> = HttpFileSystem::read_body(response)?;
+		let range:&[u8] = if partial { &body } else { body.get(start as usize..).unwrap_or_default() };
+		if range.len() < (end - start) as usize {
+			return Err(FileRef::new(path).error(FileOperation::Read, format!("Range {start}..{end} is out of bounds.")));
+		}
+		Ok(range[..(end - start) as usize].to_vec())
+	}
+
+	fn write_at(&self, path:&str, _offset:Option<u64>, _data:&[u8]) -> Result<(), Box<dyn Error>> {
+		Err(read_only_error(path))
+	}
+
+	fn append(&self, path:&str, _data:&[u8]) -> Result<(), Box<dyn Error>> {
+		Err(read_only_error(path))
+	}
+
+	fn copy(&self, _source:&str, target:&str) -> Result<u64, Box<dyn Error>> {
+		Err(read_only_error(target))
+	}
+
+	fn remove(&self, path:&str) -> Result<(), Box<dyn Error>> {
+		Err(read_only_error(path))
+	}
+
+	fn list(&self, path:&str) -> Result<Vec<String>, Box<dyn Error>> {
+		Err(FileRef::new(path).error(FileOperation::Scan, "Plain HTTP servers do not support listing dirs."))
+	}
+}
+
+
+
+/// Create the error returned when modifying a remote filesystem.
+pub(crate) fn read_only_error(path:&str) -> Box<dyn Error> {
+	FileRef::new(path).error(FileOperation::Write, "Remote filesystems are read-only.")
+}
+
+/// Get the content length of a response, if the server sent one.
+pub(crate) fn content_length(response:&Response) -> Option<u64> {
+	response.header("Content-Length").and_then(|length| length.trim().parse().ok())
+}
+
+/// Percent-encode a value for use in an url. Unreserved characters are kept as they are, as well as '/' if requested.
+pub(crate) fn percent_encode(value:&str, keep_slash:bool) -> String {
+	value.bytes().map(|byte| match byte {
+		b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+		b'/' if keep_slash => "/".to_owned(),
+		_ => format!("%{byte:02X}")
+	}).collect()
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+	use std::{ io::{ BufRead, BufReader, Write }, net::{ TcpListener, TcpStream }, thread };
+	use crate::{ FileRef, HttpFileSystem, percent_encode, unmount_file_system };
+
+
+
+	const SERVED_PATH:&str = "/files/dir/file.txt";
+	const SERVED_CONTENTS:&str = "0123456789";
+
+
+
+	/// Start a server that serves a single file, supporting range requests. Returns the base url.
+	fn start_server() -> String {
+		let listener:TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let address:String = format!("http://{}", listener.local_addr().unwrap());
+		thread::spawn(move || {
+			for stream in listener.incoming().flatten() {
+				handle_request(stream);
+			}
+		});
+		address
+	}
+
+	/// Respond to a single request.
+	fn handle_request(mut stream:TcpStream) {
+		let mut reader:BufReader<TcpStream> = BufReader::new(stream.try_clone().unwrap());
+		let mut request_line:String = String::new();
+		reader.read_line(&mut request_line).unwrap();
+		let mut range:Option<(usize, usize)> = None;
+		loop {
+			let mut header:String = String::new();
+			reader.read_line(&mut header).unwrap();
+			if header.trim().is_empty() {
+				break;
+			}
+			if let Some(value) = header.to_lowercase().strip_prefix("range: bytes=") {
+				let (start, end):(&str, &str) = value.trim().split_once('-').unwrap();
+				range = Some((start.parse().unwrap(), end.parse::<usize>().unwrap() + 1));
+			}
+		}
+
+		let parts:Vec<&str> = request_line.split_whitespace().collect();
+		let response:String = if parts[1] != SERVED_PATH {
+			"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_owned()
+		} else {
+			let (status, body):(&str, &str) = match range {
+				Some((start, end)) => ("206 Partial Content", &SERVED_CONTENTS[start..end]),
+				None => ("200 OK", SERVED_CONTENTS)
+			};
+			let body:&str = if parts[0] == "HEAD" { "" } else { body };
+			format!("HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len())
+		};
+		stream.write_all(response.as_bytes()).unwrap();
+	}
+
+
+
+	#[test]
+	fn test_http_read() {
+		let root:FileRef = HttpFileSystem::new("http_test_read:", &(start_server() + "/files")).mount();
+		let file:FileRef = root.clone() + "/dir/file.txt";
+		assert!(file.exists());
+		assert!(!(root.clone() + "/dir/missing.txt").exists());
+		assert_eq!(file.read().unwrap(), SERVED_CONTENTS);
+		assert_eq!(file.read_range(2, 5).unwrap(), b"234");
+		assert!(file.read_range(8, 12).is_err());
+		unmount_file_system(&root);
+	}
+
+	#[test]
+	fn test_http_read_only() {
+		let root:FileRef = HttpFileSystem::new("http_test_read_only:", &(start_server() + "/files")).mount();
+		let file:FileRef = root.clone() + "/dir/file.txt";
+		assert!(file.write("changed").is_err());
+		assert!(file.delete().is_err());
+		assert!(root.list_files().is_empty());
+		unmount_file_system(&root);
+	}
+
+	#[test]
+	fn test_percent_encode() {
+		assert_eq!(percent_encode("/dir/my file.txt", true), "/dir/my%20file.txt");
+		assert_eq!(percent_encode("a/b=c", false), "a%2Fb%3Dc");
+	}
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::path::{ Path, PathBuf };
+use crate::FileRef;
+
+
+
+/// Extension trait to turn strings and paths into FileRefs directly, like `"config/app.toml".as_file_ref().read()`.
+pub trait IntoFileRef {
+
+	/// Get a FileRef pointing to this path.
+	fn as_file_ref(&self) -> FileRef;
+}
+impl IntoFileRef for str {
+	fn as_file_ref(&self) -> FileRef {
+		FileRef::new(self)
+	}
+}
+impl IntoFileRef for String {
+	fn as_file_ref(&self) -> FileRef {
+		FileRef::new(self)
+	}
+}
+impl IntoFileRef for Path {
+
+	/// Get a FileRef pointing to this path. Characters that are not valid UTF-8 are replaced.
+	fn as_file_ref(&self) -> FileRef {
+		FileRef::new(&self.to_string_lossy())
+	}
+}
+impl IntoFileRef for PathBuf {
+
+	/// Get a FileRef pointing to this path. Characters that are not valid UTF-8 are replaced.
+	fn as_file_ref(&self) -> FileRef {
+		self.as_path().as_file_ref()
+	}
+}
\ No newline at end of file
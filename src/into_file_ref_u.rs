@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+	use std::path::{ Path, PathBuf };
+	use crate::{ FileRef, IntoFileRef, TempDirRef };
+
+
+
+	#[test]
+	fn test_as_file_ref() {
+		assert_eq!("config\\app.toml".as_file_ref().path(), "config/app.toml");
+		assert_eq!(String::from("config/app.toml").as_file_ref().path(), "config/app.toml");
+		assert_eq!(Path::new("config/app.toml").as_file_ref().path(), "config/app.toml");
+		assert_eq!(PathBuf::from("config/app.toml").as_file_ref().path(), "config/app.toml");
+	}
+
+	#[test]
+	fn test_as_file_ref_read() {
+		let temp_dir:TempDirRef = TempDirRef::new("into_file_ref_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/app.toml";
+		file.write("name = \"app\"").unwrap();
+		assert_eq!(file.path().as_file_ref().read().unwrap(), "name = \"app\"");
+	}
+}
\ No newline at end of file
@@ -0,0 +1,110 @@
+use std::{ collections::HashMap, error::Error, io::{ Error as IoError, ErrorKind }, sync::{ Mutex, MutexGuard, atomic::{ AtomicU64, Ordering } } };
+use crate::FileRefError;
+
+
+
+static IO_COUNTERS:IoCounters = IoCounters {
+	files_read: AtomicU64::new(0),
+	bytes_read: AtomicU64::new(0),
+	files_written: AtomicU64::new(0),
+	bytes_written: AtomicU64::new(0),
+	files_copied: AtomicU64::new(0),
+	bytes_copied: AtomicU64::new(0),
+	dirs_scanned: AtomicU64::new(0),
+	scan_entries: AtomicU64::new(0),
+	errors: Mutex::new(Vec::new())
+};
+
+
+
+/// A snapshot of the IO executed through FileRefs since the process started or the metrics were last reset. Reads, writes and appends of whole files and ranges, copies, and the dirs listed by scanners are counted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IoMetrics {
+	pub files_read:u64,
+	pub bytes_read:u64,
+	pub files_written:u64,
+	pub bytes_written:u64,
+	pub files_copied:u64,
+	pub bytes_copied:u64,
+	pub dirs_scanned:u64,
+	pub scan_entries:u64,
+	pub errors:HashMap<ErrorKind, u64>
+}
+impl IoMetrics {
+
+	/// Get the total amount of failed operations.
+	pub fn total_errors(&self) -> u64 {
+		self.errors.values().sum()
+	}
+}
+
+
+
+struct IoCounters {
+	files_read:AtomicU64,
+	bytes_read:AtomicU64,
+	files_written:AtomicU64,
+	bytes_written:AtomicU64,
+	files_copied:AtomicU64,
+	bytes_copied:AtomicU64,
+	dirs_scanned:AtomicU64,
+	scan_entries:AtomicU64,
+	errors:Mutex<Vec<(ErrorKind, u64)>>
+}
+impl IoCounters {
+
+	/// Get all atomic counters, in the order of the fields of IoMetrics.
+	fn counters(&self) -> [&AtomicU64; 8] {
+		[&self.files_read, &self.bytes_read, &self.files_written, &self.bytes_written, &self.files_copied, &self.bytes_copied, &self.dirs_scanned, &self.scan_entries]
+	}
+}
+
+
+
+/// Get a snapshot of the IO metrics of the process.
+pub fn io_metrics() -> IoMetrics {
+	let [files_read, bytes_read, files_written, bytes_written, files_copied, bytes_copied, dirs_scanned, scan_entries]:[u64; 8] = IO_COUNTERS.counters().map(|counter| counter.load(Ordering::Relaxed));
+	let errors:HashMap<ErrorKind, u64> = IO_COUNTERS.errors.lock().unwrap().iter().copied().collect();
+	IoMetrics { files_read, bytes_read, files_written, bytes_written, files_copied, bytes_copied, dirs_scanned, scan_entries, errors }
+}
+
+/// Reset all IO metrics to zero.
+pub fn reset_io_metrics() {
+	for counter in IO_COUNTERS.counters() {
+		counter.store(0, Ordering::Relaxed);
+	}
+	IO_COUNTERS.errors.lock().unwrap().clear();
+}
+
+/// Count a finished operation. With the 'metrics' feature, the counts are also reported to the recorder of the `metrics` crate.
+pub(crate) fn record_io_operation(operation:&str, amount:Option<u64>, error:Option<&(dyn Error + 'static)>) {
+	if let Some(error) = error {
+		let kind:ErrorKind = error.downcast_ref::<FileRefError>().and_then(|error| error.io_error_kind()).or_else(|| error.downcast_ref::<IoError>().map(|error| error.kind())).unwrap_or(ErrorKind::Other);
+		let mut errors:MutexGuard<Vec<(ErrorKind, u64)>> = IO_COUNTERS.errors.lock().unwrap();
+		match errors.iter_mut().find(|(counted_kind, _)| *counted_kind == kind) {
+			Some((_, count)) => *count += 1,
+			None => errors.push((kind, 1))
+		}
+		#[cfg(feature="metrics")]
+		metrics::counter!("file_ref_errors_total", "operation" => operation.to_owned(), "kind" => format!("{kind:?}")).increment(1);
+		return;
+	}
+
+	let amount:u64 = amount.unwrap_or(0);
+	let (count_counter, amount_counter, count_name, amount_name):(&AtomicU64, &AtomicU64, &'static str, &'static str) = match operation {
+		"read" => (&IO_COUNTERS.files_read, &IO_COUNTERS.bytes_read, "file_ref_files_read_total", "file_ref_bytes_read_total"),
+		"write" | "append" => (&IO_COUNTERS.files_written, &IO_COUNTERS.bytes_written, "file_ref_files_written_total", "file_ref_bytes_written_total"),
+		"copy" => (&IO_COUNTERS.files_copied, &IO_COUNTERS.bytes_copied, "file_ref_files_copied_total", "file_ref_bytes_copied_total"),
+		"scan" => (&IO_COUNTERS.dirs_scanned, &IO_COUNTERS.scan_entries, "file_ref_dirs_scanned_total", "file_ref_scan_entries_total"),
+		_ => return
+	};
+	count_counter.fetch_add(1, Ordering::Relaxed);
+	amount_counter.fetch_add(amount, Ordering::Relaxed);
+	#[cfg(feature="metrics")]
+	{
+		metrics::counter!(count_name).increment(1);
+		metrics::counter!(amount_name).increment(amount);
+	}
+	#[cfg(not(feature="metrics"))]
+	let _ = (count_name, amount_name);
+}
\ No newline at end of file
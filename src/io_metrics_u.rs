@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+	use std::io::ErrorKind;
+	use crate::{ FileRef, IoMetrics, TempDirRef, io_metrics };
+
+
+
+	#[test]
+	fn test_io_metrics() {
+		let temp_dir:TempDirRef = TempDirRef::new("io_metrics_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/file.txt";
+
+		// Other tests run in parallel, so only check the metrics increased by at least the IO of this test.
+		let before:IoMetrics = io_metrics();
+		file.write("contents").unwrap();
+		file.append_bytes(b"!").unwrap();
+		assert_eq!(file.read().unwrap(), "contents!");
+		file.copy_to(&(temp_dir.path() + "/copy.txt")).unwrap();
+		assert_eq!(temp_dir.path().list_files().len(), 2);
+		assert!((temp_dir.path() + "/missing.txt").read().is_err());
+		let after:IoMetrics = io_metrics();
+
+		assert!(after.files_written >= before.files_written + 2);
+		assert!(after.bytes_written >= before.bytes_written + 9);
+		assert!(after.files_read > before.files_read);
+		assert!(after.bytes_read >= before.bytes_read + 9);
+		assert!(after.files_copied > before.files_copied);
+		assert!(after.dirs_scanned > before.dirs_scanned);
+		assert!(after.scan_entries >= before.scan_entries + 2);
+		assert!(after.errors.get(&ErrorKind::NotFound).copied().unwrap_or(0) > before.errors.get(&ErrorKind::NotFound).copied().unwrap_or(0));
+		assert!(after.total_errors() > before.total_errors());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,169 @@
+use std::{ error::Error, fs::{ File, OpenOptions }, io::{ self, BufReader, ErrorKind, Read, Seek, SeekFrom, Write } };
+use crate::{ FileOperation, FileRef, HashAlgorithm, Hasher };
+
+
+
+const RECORD_HEADER_SIZE:usize = 8;
+
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FsyncPolicy {
+	Never,
+	#[default]
+	EveryRecord,
+	EveryRecords(u32)
+}
+
+
+
+pub struct JournalFile {
+	file:FileRef,
+	handle:File,
+	policy:FsyncPolicy,
+	length:u64,
+	unsynced_records:u32
+}
+impl JournalFile {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Open the journal, creating it if it does not exist. A torn record at the end, left by a crash during an append, is cut off so new records are appended after the last complete one. Fails without cutting anything off if the journal could not be read.
+	pub fn open(file:&FileRef, policy:FsyncPolicy) -> Result<JournalFile, Box<dyn Error>> {
+		if file.is_dir() {
+			return Err(file.error(FileOperation::Write, "Only able to journal to files."));
+		}
+		file.require_std_file_system(FileOperation::Write)?;
+		file.guarantee_parent_dir()?;
+		let handle:File = OpenOptions::new().read(true).append(true).create(true).open(file.os_path()).map_err(|error| file.error(FileOperation::Write, error))?;
+		let mut journal:JournalFile = JournalFile { file: file.clone(), handle, policy, length: 0, unsynced_records: 0 };
+
+		// Cut off torn record.
+		let mut records:JournalRecords = journal.records()?;
+		for _ in records.by_ref() {}
+		if let Some(error) = records.error.take() {
+			return Err(file.error(FileOperation::Read, error));
+		}
+		journal.length = records.offset;
+		if journal.handle.metadata()?.len() > journal.length {
+			journal.handle.set_len(journal.length).map_err(|error| file.error(FileOperation::Write, error))?;
+			journal.handle.sync_all()?;
+		}
+		Ok(journal)
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the file the journal is stored in.
+	pub fn file(&self) -> &FileRef {
+		&self.file
+	}
+
+	/// Get the size of all complete records in bytes.
+	pub fn len(&self) -> u64 {
+		self.length
+	}
+
+	/// Check if the journal contains no records.
+	pub fn is_empty(&self) -> bool {
+		self.length == 0
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Append a record to the journal. The record is written in a single write, prefixed by its length and checksum. If the write fails, the partially written record is removed again. Returns the offset of the record in the file.
+	pub fn append(&mut self, data:&[u8]) -> Result<u64, Box<dyn Error>> {
+		let length:u32 = u32::try_from(data.len()).map_err(|_| self.file.error(FileOperation::Append, "Record is larger than 4GiB."))?;
+		let mut record:Vec<u8> = Vec::with_capacity(RECORD_HEADER_SIZE + data.len());
+		record.extend_from_slice(&length.to_le_bytes());
+		record.extend_from_slice(&record_checksum(data));
+		record.extend_from_slice(data);
+		if let Err(error) = self.handle.write_all(&record) {
+
+			// Remove the partially written record, so the next append does not end up behind it.
+			let _ = self.handle.set_len(self.length);
+			return Err(self.file.error(FileOperation::Append, error));
+		}
+
+		let offset:u64 = self.length;
+		self.length += record.len() as u64;
+		self.unsynced_records += 1;
+		let sync_now:bool = match self.policy {
+			FsyncPolicy::Never => false,
+			FsyncPolicy::EveryRecord => true,
+			FsyncPolicy::EveryRecords(count) => self.unsynced_records >= count
+		};
+		if sync_now {
+			self.sync()?;
+		}
+		Ok(offset)
+	}
+
+	/// Flush all appended records to disk, regardless of the fsync policy.
+	pub fn sync(&mut self) -> Result<(), Box<dyn Error>> {
+		self.handle.sync_data().map_err(|error| self.file.error(FileOperation::Write, error))?;
+		self.unsynced_records = 0;
+		Ok(())
+	}
+
+	/// Iterate over the records in the journal from the start. Iteration stops at the first torn or corrupted record, or when reading fails.
+	pub fn records(&self) -> Result<JournalRecords, Box<dyn Error>> {
+		let mut reader:File = self.handle.try_clone()?;
+		reader.seek(SeekFrom::Start(0))?;
+		Ok(JournalRecords { reader: BufReader::new(reader), offset: 0, done: false, error: None })
+	}
+}
+
+
+
+/// Iterates over the complete records in a journal.
+pub struct JournalRecords {
+	reader:BufReader<File>,
+	offset:u64,
+	done:bool,
+	error:Option<io::Error>
+}
+impl JournalRecords {
+
+	/// Read the next record, returning None if it is missing, cut short or does not match its checksum. Other read errors are returned, as they do not mean the record is torn.
+	fn read_record(&mut self) -> io::Result<Option<Vec<u8>>> {
+		let mut header:[u8; RECORD_HEADER_SIZE] = [0; RECORD_HEADER_SIZE];
+		match self.reader.read_exact(&mut header) {
+			Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+			result => result?
+		}
+		let length:usize = u32::from_le_bytes(header[..4].try_into().unwrap_or_default()) as usize;
+		let mut data:Vec<u8> = Vec::new();
+		self.reader.by_ref().take(length as u64).read_to_end(&mut data)?;
+		if data.len() != length || header[4..] != record_checksum(&data) {
+			return Ok(None);
+		}
+		self.offset += (RECORD_HEADER_SIZE + length) as u64;
+		Ok(Some(data))
+	}
+}
+impl Iterator for JournalRecords {
+	type Item = Vec<u8>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let record:Option<Vec<u8>> = self.read_record().unwrap_or_else(|error| { self.error = Some(error); None });
+		self.done = record.is_none();
+		record
+	}
+}
+
+
+
+/// Calculate the checksum stored in the header of a record.
+fn record_checksum(data:&[u8]) -> [u8; 4] {
+	let mut hasher:Hasher = Hasher::new(HashAlgorithm::Crc32);
+	hasher.update(data);
+	hasher.finish_bytes().try_into().unwrap_or_default()
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, FsyncPolicy, JournalFile, TempDirRef };
+
+
+
+	#[test]
+	fn test_journal_append_and_read() {
+		let temp_dir:TempDirRef = TempDirRef::new("journal_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/log.wal";
+
+		let mut journal:JournalFile = JournalFile::open(&file, FsyncPolicy::EveryRecord).unwrap();
+		assert_eq!(journal.append(b"first").unwrap(), 0);
+		assert_eq!(journal.append(b"").unwrap(), 13);
+		journal.append(b"third").unwrap();
+		drop(journal);
+
+		let journal:JournalFile = JournalFile::open(&file, FsyncPolicy::Never).unwrap();
+		let records:Vec<Vec<u8>> = journal.records().unwrap().collect();
+		assert_eq!(records, vec![b"first".to_vec(), Vec::new(), b"third".to_vec()]);
+		assert_eq!(journal.len(), 34);
+	}
+
+	#[test]
+	fn test_journal_recovers_from_torn_record() {
+		let temp_dir:TempDirRef = TempDirRef::new("journal_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/log.wal";
+
+		let mut journal:JournalFile = JournalFile::open(&file, FsyncPolicy::EveryRecords(10)).unwrap();
+		journal.append(b"complete").unwrap();
+		journal.append(b"torn record").unwrap();
+		journal.sync().unwrap();
+		drop(journal);
+
+		// Simulate a crash halfway through the second record.
+		let contents:Vec<u8> = file.read_bytes().unwrap();
+		file.write_bytes(&contents[..contents.len() - 4]).unwrap();
+
+		let mut journal:JournalFile = JournalFile::open(&file, FsyncPolicy::EveryRecord).unwrap();
+		assert_eq!(journal.records().unwrap().collect::<Vec<Vec<u8>>>(), vec![b"complete".to_vec()]);
+		journal.append(b"after recovery").unwrap();
+		assert_eq!(journal.records().unwrap().collect::<Vec<Vec<u8>>>(), vec![b"complete".to_vec(), b"after recovery".to_vec()]);
+	}
+
+	#[test]
+	fn test_journal_stops_at_corrupted_record() {
+		let temp_dir:TempDirRef = TempDirRef::new("journal_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/log.wal";
+
+		let mut journal:JournalFile = JournalFile::open(&file, FsyncPolicy::Never).unwrap();
+		journal.append(b"good").unwrap();
+		journal.append(b"flipped").unwrap();
+		journal.append(b"unreachable").unwrap();
+		drop(journal);
+
+		file.write_bytes_to_range(12 + 8, b"X").unwrap();
+		let journal:JournalFile = JournalFile::open(&file, FsyncPolicy::Never).unwrap();
+		assert_eq!(journal.records().unwrap().collect::<Vec<Vec<u8>>>(), vec![b"good".to_vec()]);
+	}
+}
\ No newline at end of file
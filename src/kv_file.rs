@@ -0,0 +1,132 @@
+use std::{ collections::BTreeMap, error::Error, str::Chars };
+use crate::{ FileOperation, FileRef };
+
+
+
+const KV_SEPARATOR:char = '\t';
+
+
+
+/// A small persistent key-value store kept in a single file. All entries are held in memory and every change rewrites the file atomically.
+pub struct KvFile {
+	file:FileRef,
+	entries:BTreeMap<String, String>
+}
+impl KvFile {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Open the store, loading all entries from the file. A missing file is treated as an empty store.
+	pub fn open(file:&FileRef) -> Result<KvFile, Box<dyn Error>> {
+		let mut entries:BTreeMap<String, String> = BTreeMap::new();
+		if file.exists() {
+			for (line_index, line) in file.read()?.lines().enumerate().filter(|(_, line)| !line.is_empty()) {
+				let (key, value) = line.split_once(KV_SEPARATOR).ok_or_else(|| file.error(FileOperation::Read, format!("Line {} has no value.", line_index + 1)))?;
+				entries.insert(unescape(key), unescape(value));
+			}
+		}
+		Ok(KvFile { file: file.clone(), entries })
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the file the store is kept in.
+	pub fn file(&self) -> &FileRef {
+		&self.file
+	}
+
+	/// Get the amount of entries in the store.
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Check if the store contains no entries.
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Get the value stored under a key.
+	pub fn get(&self, key:&str) -> Option<&str> {
+		self.entries.get(key).map(|value| value.as_str())
+	}
+
+	/// Check if the store contains a key.
+	pub fn contains_key(&self, key:&str) -> bool {
+		self.entries.contains_key(key)
+	}
+
+	/// Store a value under a key and persist the store.
+	pub fn set(&mut self, key:&str, value:&str) -> Result<(), Box<dyn Error>> {
+		let previous:Option<String> = self.entries.insert(key.to_string(), value.to_string());
+		if let Err(error) = self.persist() {
+			match previous {
+				Some(previous) => self.entries.insert(key.to_string(), previous),
+				None => self.entries.remove(key)
+			};
+			return Err(error);
+		}
+		Ok(())
+	}
+
+	/// Remove a key and persist the store. Returns the value that was stored under the key.
+	pub fn remove(&mut self, key:&str) -> Result<Option<String>, Box<dyn Error>> {
+		let previous:Option<String> = self.entries.remove(key);
+		if previous.is_some() {
+			if let Err(error) = self.persist() {
+				self.entries.insert(key.to_string(), previous.unwrap_or_default());
+				return Err(error);
+			}
+		}
+		Ok(previous)
+	}
+
+	/// Iterate over all entries, ordered by key.
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+		self.entries.iter().map(|(key, value)| (key.as_str(), value.as_str()))
+	}
+
+	/// Write all entries to the file.
+	fn persist(&self) -> Result<(), Box<dyn Error>> {
+		let mut contents:String = String::new();
+		for (key, value) in &self.entries {
+			contents += &escape(key);
+			contents.push(KV_SEPARATOR);
+			contents += &escape(value);
+			contents.push('\n');
+		}
+		self.file.write_atomic(contents.as_bytes())
+	}
+}
+
+
+
+/// Escape the characters that have a meaning in the file format.
+fn escape(text:&str) -> String {
+	text.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+/// Revert the escaping done by `escape`.
+fn unescape(text:&str) -> String {
+	let mut output:String = String::with_capacity(text.len());
+	let mut characters:Chars = text.chars();
+	while let Some(character) = characters.next() {
+		if character != '\\' {
+			output.push(character);
+			continue;
+		}
+		match characters.next() {
+			Some('t') => output.push('\t'),
+			Some('n') => output.push('\n'),
+			Some('r') => output.push('\r'),
+			Some(other) => output.push(other),
+			None => output.push('\\')
+		}
+	}
+	output
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, KvFile, TempDirRef };
+
+
+
+	#[test]
+	fn test_kv_set_get_remove() {
+		let temp_dir:TempDirRef = TempDirRef::new("kv_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/state.kv";
+
+		let mut store:KvFile = KvFile::open(&file).unwrap();
+		assert!(store.is_empty());
+		store.set("b", "2").unwrap();
+		store.set("a", "1").unwrap();
+		store.set("a", "one").unwrap();
+		assert_eq!(store.get("a"), Some("one"));
+		assert_eq!(store.remove("b").unwrap(), Some("2".to_string()));
+		assert_eq!(store.remove("b").unwrap(), None);
+		assert_eq!(store.iter().collect::<Vec<(&str, &str)>>(), vec![("a", "one")]);
+	}
+
+	#[test]
+	fn test_kv_persists_between_opens() {
+		let temp_dir:TempDirRef = TempDirRef::new("kv_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/state.kv";
+
+		let mut store:KvFile = KvFile::open(&file).unwrap();
+		store.set("multi\tline", "first\nsecond\\third").unwrap();
+		store.set("empty", "").unwrap();
+		drop(store);
+
+		let store:KvFile = KvFile::open(&file).unwrap();
+		assert_eq!(store.len(), 2);
+		assert_eq!(store.get("multi\tline"), Some("first\nsecond\\third"));
+		assert_eq!(store.get("empty"), Some(""));
+		assert!(!(file.clone() + ".tmp").exists());
+	}
+
+	#[test]
+	fn test_kv_invalid_file() {
+		let temp_dir:TempDirRef = TempDirRef::new("kv_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/state.kv";
+		file.write("no separator").unwrap();
+		assert!(KvFile::open(&file).is_err());
+	}
+}
\ No newline at end of file
@@ -1,7 +1,267 @@
+#[cfg(feature="hashing")]
+mod blob_store;
+#[cfg(feature="hashing")]
+mod blob_store_u;
+mod cached_file_ref;
+mod cached_file_ref_u;
+mod cancellation;
+mod cancellation_u;
+mod clock;
+mod clock_u;
+mod compression_level;
+#[cfg(any(feature="json", feature="toml", feature="yaml"))]
+mod config_file;
+#[cfg(any(feature="json", feature="toml", feature="yaml"))]
+mod config_file_u;
+mod dir_builder;
+mod dir_builder_u;
+mod dir_ref;
+mod dir_ref_u;
+mod dir_snapshot;
+mod dir_snapshot_u;
+mod file_lock;
+mod file_lock_u;
+mod file_metadata;
+mod file_metadata_u;
 mod file_ref;
 mod file_ref_u;
+mod file_ref_advise;
+mod file_ref_advise_u;
+#[cfg(any(feature="arbitrary", feature="proptest"))]
+mod file_ref_arbitrary;
+#[cfg(any(feature="arbitrary", feature="proptest"))]
+mod file_ref_arbitrary_u;
+#[cfg(any(feature="bincode", feature="rkyv"))]
+mod file_ref_binary;
+#[cfg(any(feature="bincode", feature="rkyv"))]
+mod file_ref_binary_u;
+#[cfg(feature="camino")]
+mod file_ref_camino;
+#[cfg(feature="camino")]
+mod file_ref_camino_u;
+mod file_ref_cleanup;
+mod file_ref_cleanup_u;
+#[cfg(feature="clap")]
+mod file_ref_clap;
+#[cfg(feature="clap")]
+mod file_ref_clap_u;
+#[cfg(feature="tokio")]
+mod file_ref_async;
+#[cfg(feature="tokio")]
+mod file_ref_async_u;
+#[cfg(feature="encryption")]
+mod file_ref_encryption;
+#[cfg(feature="encryption")]
+mod file_ref_encryption_u;
+#[cfg(feature="hashing")]
+mod file_ref_dedupe;
+#[cfg(feature="hashing")]
+mod file_ref_dedupe_u;
+mod file_ref_diff;
+mod file_ref_error;
+mod file_ref_error_u;
+mod file_ref_hidden;
+mod file_ref_hidden_u;
+#[cfg(feature="mmap")]
+mod file_ref_mmap;
+#[cfg(feature="mmap")]
+mod file_ref_mmap_u;
+mod file_ref_quota;
+mod file_ref_quota_u;
+mod file_ref_retry;
+mod file_ref_retry_u;
+#[cfg(feature="schemars")]
+mod file_ref_schema;
+#[cfg(feature="schemars")]
+mod file_ref_schema_u;
+mod file_ref_diff_u;
+#[cfg(any(feature="gzip", feature="zstd"))]
+mod file_ref_compression;
+#[cfg(any(feature="gzip", feature="zstd"))]
+mod file_ref_compression_u;
+mod file_ref_copy;
+mod file_ref_copy_u;
+#[cfg(feature="tar")]
+mod file_ref_tar;
+#[cfg(feature="tar")]
+mod file_ref_tar_u;
+#[cfg(feature="hashing")]
+mod file_ref_split;
+#[cfg(feature="hashing")]
+mod file_ref_split_u;
+mod file_ref_throttle;
+mod file_ref_throttle_u;
+mod file_ref_timestamped;
+mod file_ref_timestamped_u;
+mod file_ref_tracing;
+#[cfg(feature="tracing")]
+mod file_ref_tracing_u;
+mod file_ref_tree;
+mod file_ref_tree_u;
+#[cfg(feature="url")]
+mod file_ref_url;
+#[cfg(feature="url")]
+mod file_ref_url_u;
+#[cfg(feature="uuid")]
+mod file_ref_uuid;
+#[cfg(feature="uuid")]
+mod file_ref_uuid_u;
+mod file_ref_verbatim;
+mod file_ref_verbatim_u;
+#[cfg(feature="hashing")]
+mod file_ref_versioned;
+#[cfg(feature="hashing")]
+mod file_ref_versioned_u;
+mod file_ref_streams;
+mod file_ref_streams_u;
+mod file_ref_symlink;
+mod file_ref_symlink_u;
+mod file_ref_sync;
+mod file_ref_sync_u;
+mod file_ref_xattr;
+mod file_ref_xattr_u;
+#[cfg(feature="zip")]
+mod file_ref_zip;
+#[cfg(feature="zip")]
+mod file_ref_zip_u;
 mod file_scanner;
 mod file_scanner_u;
+mod file_system;
+mod file_system_u;
+mod fs_info;
+mod fs_info_u;
+mod glob_pattern;
+mod glob_pattern_u;
+#[cfg(feature="hashing")]
+mod hash_algorithm;
+#[cfg(feature="hashing")]
+mod hash_algorithm_u;
+#[cfg(feature="http")]
+mod http_fs;
+#[cfg(feature="http")]
+mod http_fs_u;
+mod into_file_ref;
+mod into_file_ref_u;
+mod io_metrics;
+mod io_metrics_u;
+#[cfg(feature="hashing")]
+mod journal_file;
+#[cfg(feature="hashing")]
+mod journal_file_u;
+mod kv_file;
+mod kv_file_u;
+mod line_ending;
+mod line_ending_u;
+#[cfg(feature="hashing")]
+mod manifest;
+#[cfg(feature="hashing")]
+mod manifest_u;
+mod memory_fs;
+mod memory_fs_u;
+mod open_file_ref;
+mod open_file_ref_u;
+mod operation_hooks;
+mod operation_hooks_u;
+mod path_prefixes;
+mod path_prefixes_u;
+mod path_table;
+mod path_table_u;
+mod platform;
+mod platform_u;
+/// Re-exports of the most commonly used types, so downstream code only needs a single import.
+pub mod prelude;
+mod prelude_u;
+mod progress;
+mod progress_u;
+mod record_file;
+mod record_file_u;
+mod retention_policy;
+mod retention_policy_u;
+#[cfg(feature="s3")]
+mod s3_fs;
+#[cfg(feature="s3")]
+mod s3_fs_u;
+mod temp_dir_ref;
+mod temp_dir_ref_u;
+mod temp_file_ref;
+mod temp_file_ref_u;
+/// Assertion helpers for tests that work with files, like `assert_content_eq!` and `assert_dir_matches!`.
+pub mod testing;
+mod testing_u;
+#[cfg(any(feature="json", feature="toml", feature="yaml"))]
+mod typed_file;
+#[cfg(feature="json")]
+mod typed_file_u;
+#[cfg(all(target_os="linux", feature="io_uring"))]
+mod uring_backend;
+#[cfg(all(target_os="linux", feature="io_uring"))]
+mod uring_backend_u;
 
+#[cfg(feature="hashing")]
+pub use blob_store::*;
+pub use cached_file_ref::*;
+pub use cancellation::*;
+pub use clock::*;
+pub use compression_level::*;
+#[cfg(any(feature="json", feature="toml", feature="yaml"))]
+pub use config_file::*;
+pub use dir_builder::*;
+pub use dir_ref::*;
+pub use dir_snapshot::*;
+pub use file_lock::*;
+pub use file_metadata::*;
 pub use file_ref::*;
-pub use file_scanner::*;
\ No newline at end of file
+pub use file_ref_advise::*;
+#[cfg(feature="proptest")]
+pub use file_ref_arbitrary::*;
+pub use file_ref_cleanup::*;
+pub use file_ref_copy::*;
+#[cfg(feature="clap")]
+pub use file_ref_clap::*;
+#[cfg(feature="hashing")]
+pub use file_ref_dedupe::*;
+pub use file_ref_diff::*;
+pub use file_ref_error::*;
+#[cfg(feature="mmap")]
+pub use file_ref_mmap::*;
+pub use file_ref_quota::*;
+pub use file_ref_retry::*;
+#[cfg(any(feature="gzip", feature="zstd"))]
+pub use file_ref_compression::*;
+pub use file_ref_sync::*;
+pub use file_ref_throttle::*;
+pub use file_ref_tree::*;
+#[cfg(feature="hashing")]
+pub use file_ref_versioned::*;
+pub use file_scanner::*;
+pub use file_system::*;
+pub use fs_info::*;
+pub use glob_pattern::*;
+#[cfg(feature="hashing")]
+pub use hash_algorithm::*;
+#[cfg(feature="http")]
+pub use http_fs::*;
+pub use into_file_ref::*;
+pub use io_metrics::*;
+#[cfg(feature="hashing")]
+pub use journal_file::*;
+pub use kv_file::*;
+pub use line_ending::*;
+#[cfg(feature="hashing")]
+pub use manifest::*;
+pub use memory_fs::*;
+pub use open_file_ref::*;
+pub use operation_hooks::*;
+pub use path_prefixes::*;
+pub use path_table::*;
+pub use progress::*;
+pub use record_file::*;
+pub use retention_policy::*;
+#[cfg(feature="s3")]
+pub use s3_fs::*;
+pub use temp_dir_ref::*;
+pub use temp_file_ref::*;
+#[cfg(any(feature="json", feature="toml", feature="yaml"))]
+pub use typed_file::*;
+#[cfg(all(target_os="linux", feature="io_uring"))]
+pub use uring_backend::*;
\ No newline at end of file
@@ -0,0 +1,63 @@
+use std::error::Error;
+use crate::FileRef;
+
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineEnding {
+	#[default]
+	Native,
+	Lf,
+	CrLf
+}
+impl LineEnding {
+
+	/// Get the characters that end a line.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			LineEnding::Native => if cfg!(windows) { "\r\n" } else { "\n" },
+			LineEnding::Lf => "\n",
+			LineEnding::CrLf => "\r\n"
+		}
+	}
+
+	/// Replace all line endings in the text, LF and CRLF alike, with this line ending.
+	pub fn normalize(&self, text:&str) -> String {
+		let line_ending:&str = self.as_str();
+		let mut normalized:String = String::with_capacity(text.len());
+		let mut lines:std::iter::Peekable<std::str::Split<'_, char>> = text.split('\n').peekable();
+		while let Some(line) = lines.next() {
+			if lines.peek().is_some() {
+				normalized += line.strip_suffix('\r').unwrap_or(line);
+				normalized += line_ending;
+			} else {
+				normalized += line;
+			}
+		}
+		normalized
+	}
+}
+
+
+
+impl FileRef {
+
+	/* LINE ENDING METHODS */
+
+	/// Write text to the file, replacing all line endings with the given line ending.
+	pub fn write_with_line_ending(&self, contents:&str, line_ending:LineEnding) -> Result<(), Box<dyn Error>> {
+		self.write(&line_ending.normalize(contents))
+	}
+
+	/// Write lines to the file, ending every line with the given line ending.
+	pub fn write_lines<T:IntoIterator<Item = U>, U:AsRef<str>>(&self, lines:T, line_ending:LineEnding) -> Result<(), Box<dyn Error>> {
+		let contents:String = lines.into_iter().map(|line| line.as_ref().to_owned() + line_ending.as_str()).collect();
+		self.write(&line_ending.normalize(&contents))
+	}
+
+	/// Append a single line to the file, ending it with the given line ending. Creates the file if it does not exist.
+	pub fn append_line(&self, line:&str, line_ending:LineEnding) -> Result<(), Box<dyn Error>> {
+		self.guarantee_exists()?;
+		self.append_bytes(line_ending.normalize(&(line.to_owned() + line_ending.as_str())).as_bytes())
+	}
+}
\ No newline at end of file
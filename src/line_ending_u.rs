@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, LineEnding, TempDirRef };
+
+
+
+	#[test]
+	fn test_normalize() {
+		assert_eq!(LineEnding::CrLf.normalize("a\nb\r\nc"), "a\r\nb\r\nc");
+		assert_eq!(LineEnding::Lf.normalize("a\r\nb\n\r\n"), "a\nb\n\n");
+		assert_eq!(LineEnding::Lf.normalize("no line ending\r"), "no line ending\r");
+		assert_eq!(LineEnding::Native.as_str(), if cfg!(windows) { "\r\n" } else { "\n" });
+	}
+
+	#[test]
+	fn test_write_with_line_endings() {
+		let temp_dir:TempDirRef = TempDirRef::new("line_ending_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/lines.txt";
+
+		file.write_with_line_ending("a\nb\n", LineEnding::CrLf).unwrap();
+		assert_eq!(file.read().unwrap(), "a\r\nb\r\n");
+
+		file.write_lines(["first", "second\r\n"], LineEnding::Lf).unwrap();
+		assert_eq!(file.read().unwrap(), "first\nsecond\n\n");
+
+		let log:FileRef = temp_dir.path() + "/log.txt";
+		log.append_line("one", LineEnding::CrLf).unwrap();
+		log.append_line("two", LineEnding::CrLf).unwrap();
+		assert_eq!(log.read().unwrap(), "one\r\ntwo\r\n");
+	}
+}
\ No newline at end of file
@@ -0,0 +1,157 @@
+use std::{ collections::BTreeMap, error::Error };
+use crate::{ DirEntries, FileOperation, FileRef, HashAlgorithm, SEPARATOR };
+
+
+
+const MANIFEST_HEADER:&str = "# file_ref manifest";
+const MANIFEST_FIELD_SEPARATOR:&str = "  ";
+
+
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature="rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature="bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct ManifestEntry {
+	pub path:FileRef,
+	pub size:u64,
+	pub digest:String
+}
+
+
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ManifestMismatch {
+	Missing(FileRef),
+	Unexpected(FileRef),
+	SizeChanged { path:FileRef, expected:u64, actual:u64 },
+	DigestChanged { path:FileRef, expected:String, actual:String }
+}
+
+
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature="rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature="bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct Manifest {
+	algorithm:HashAlgorithm,
+	entries:Vec<ManifestEntry>
+}
+impl Manifest {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a manifest of all files in the given dir, recursively. Paths in the manifest are relative to the dir. Files are found by their metadata, so extensionless files are included and dirs with a dot in their name are recursed into.
+	pub fn create(dir:&FileRef, algorithm:HashAlgorithm) -> Result<Manifest, Box<dyn Error>> {
+		if !dir.exists() {
+			return Err(dir.error(FileOperation::Scan, "Dir does not exist."));
+		} else if !dir.is_dir_fs() {
+			return Err(dir.error(FileOperation::Scan, "Only able to create manifests of dirs."));
+		}
+		dir.require_std_file_system(FileOperation::Scan)?;
+
+		let dir:FileRef = dir.clone().absolute().trim_end_matches(SEPARATOR);
+		let mut entries:Vec<ManifestEntry> = Vec::new();
+		for (file, metadata) in DirEntries::collect(&dir)?.files {
+			entries.push(ManifestEntry {
+				path: file.relative_to_dir(&dir),
+				size: metadata.len(),
+				digest: file.hash(algorithm)?
+			});
+		}
+		entries.sort_by(|left, right| left.path.path().cmp(right.path.path()));
+		Ok(Manifest { algorithm, entries })
+	}
+
+	/// Parse a manifest from the text format produced by `to_text`.
+	pub fn parse(text:&str) -> Result<Manifest, Box<dyn Error>> {
+		let mut lines:std::iter::Filter<std::str::Lines, _> = text.lines().filter(|line| !line.trim().is_empty());
+		let algorithm:HashAlgorithm = match lines.next().and_then(|header| header.strip_prefix(MANIFEST_HEADER)).and_then(|algorithm_name| HashAlgorithm::from_name(algorithm_name.trim())) {
+			Some(algorithm) => algorithm,
+			None => return Err("Could not parse manifest. Missing or invalid header.".into())
+		};
+		let mut entries:Vec<ManifestEntry> = Vec::new();
+		for line in lines {
+			let fields:Vec<&str> = line.splitn(3, MANIFEST_FIELD_SEPARATOR).collect();
+			if fields.len() != 3 {
+				return Err(format!("Could not parse manifest. Invalid line \"{line}\".").into());
+			}
+			entries.push(ManifestEntry { path: FileRef::new(fields[2]), size: fields[1].parse()?, digest: fields[0].to_string() });
+		}
+		Ok(Manifest { algorithm, entries })
+	}
+
+	/// Load a manifest from a file.
+	pub fn load(file:&FileRef) -> Result<Manifest, Box<dyn Error>> {
+		Manifest::parse(&file.read()?).map_err(|error| file.error(FileOperation::Read, error))
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the algorithm used to create the digests.
+	pub fn algorithm(&self) -> HashAlgorithm {
+		self.algorithm
+	}
+
+	/// Get all entries, ordered by path.
+	pub fn entries(&self) -> &[ManifestEntry] {
+		&self.entries
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Convert the manifest to text. The first line holds the algorithm, every next line holds the digest, size and path of a file.
+	pub fn to_text(&self) -> String {
+		let mut text:String = format!("{MANIFEST_HEADER} {}\n", self.algorithm.name());
+		for entry in &self.entries {
+			text += &format!("{}{MANIFEST_FIELD_SEPARATOR}{}{MANIFEST_FIELD_SEPARATOR}{}\n", entry.digest, entry.size, entry.path.path());
+		}
+		text
+	}
+
+	/// Store the manifest in a file.
+	pub fn save(&self, file:&FileRef) -> Result<(), Box<dyn Error>> {
+		file.write(&self.to_text())
+	}
+
+	/// Verify the files in the given dir against this manifest. Returns all differences found, an empty list means the dir matches the manifest.
+	pub fn verify(&self, dir:&FileRef) -> Result<Vec<ManifestMismatch>, Box<dyn Error>> {
+		let actual:Manifest = Manifest::create(dir, self.algorithm)?;
+		let actual_entries:BTreeMap<&str, &ManifestEntry> = actual.entries.iter().map(|entry| (entry.path.path(), entry)).collect();
+		let mut mismatches:Vec<ManifestMismatch> = Vec::new();
+		for expected in &self.entries {
+			match actual_entries.get(expected.path.path()) {
+				None => mismatches.push(ManifestMismatch::Missing(expected.path.clone())),
+				Some(actual) if actual.size != expected.size => mismatches.push(ManifestMismatch::SizeChanged { path: expected.path.clone(), expected: expected.size, actual: actual.size }),
+				Some(actual) if actual.digest != expected.digest => mismatches.push(ManifestMismatch::DigestChanged { path: expected.path.clone(), expected: expected.digest.clone(), actual: actual.digest.clone() }),
+				_ => {}
+			}
+		}
+		for actual in &actual.entries {
+			if !self.entries.iter().any(|expected| expected.path.path() == actual.path.path()) {
+				mismatches.push(ManifestMismatch::Unexpected(actual.path.clone()));
+			}
+		}
+		Ok(mismatches)
+	}
+}
+
+
+
+impl FileRef {
+
+	/* MANIFEST METHODS */
+
+	/// Create a manifest of the relative path, size and digest of all files in this dir.
+	pub fn manifest(&self, algorithm:HashAlgorithm) -> Result<Manifest, Box<dyn Error>> {
+		Manifest::create(self, algorithm)
+	}
+
+	/// Verify the files in this dir against a manifest. Returns all differences found, an empty list means the dir matches the manifest.
+	pub fn verify_manifest(&self, manifest:&Manifest) -> Result<Vec<ManifestMismatch>, Box<dyn Error>> {
+		manifest.verify(self)
+	}
+}
\ No newline at end of file
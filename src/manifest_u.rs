@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, HashAlgorithm, Manifest, ManifestMismatch, TempDirRef };
+
+
+
+	fn create_test_dir(temp_dir:&TempDirRef) -> FileRef {
+		let dir:FileRef = temp_dir.path() + "/release";
+		(dir.clone() + "/abc.txt").write("abc").unwrap();
+		(dir.clone() + "/sub_dir/data.bin").write("data").unwrap();
+		dir
+	}
+
+	#[test]
+	fn test_manifest() {
+		let temp_dir:TempDirRef = TempDirRef::new("manifest_test_").unwrap();
+		let dir:FileRef = create_test_dir(&temp_dir);
+
+		let manifest:Manifest = dir.manifest(HashAlgorithm::Sha256).unwrap();
+		assert_eq!(manifest.entries().len(), 2);
+		assert_eq!(manifest.entries()[0].path, FileRef::new("abc.txt"));
+		assert_eq!(manifest.entries()[0].size, 3);
+		assert_eq!(manifest.entries()[0].digest, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+		assert!(dir.verify_manifest(&manifest).unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_manifest_save_and_load() {
+		let temp_dir:TempDirRef = TempDirRef::new("manifest_test_").unwrap();
+		let dir:FileRef = create_test_dir(&temp_dir);
+		let manifest_file:FileRef = temp_dir.path() + "/manifest.txt";
+
+		let manifest:Manifest = dir.manifest(HashAlgorithm::Crc32).unwrap();
+		manifest.save(&manifest_file).unwrap();
+		assert_eq!(Manifest::load(&manifest_file).unwrap(), manifest);
+		assert!(Manifest::parse("no header").is_err());
+	}
+
+	#[test]
+	fn test_verify_manifest_mismatches() {
+		let temp_dir:TempDirRef = TempDirRef::new("manifest_test_").unwrap();
+		let dir:FileRef = create_test_dir(&temp_dir);
+		let manifest:Manifest = dir.manifest(HashAlgorithm::Sha256).unwrap();
+
+		(dir.clone() + "/abc.txt").write("abd").unwrap();
+		(dir.clone() + "/sub_dir/data.bin").delete().unwrap();
+		(dir.clone() + "/extra.txt").write("extra").unwrap();
+		let mismatches:Vec<ManifestMismatch> = dir.verify_manifest(&manifest).unwrap();
+		assert_eq!(mismatches.len(), 3);
+		assert!(matches!(&mismatches[0], ManifestMismatch::DigestChanged { path, .. } if path == &FileRef::new("abc.txt")));
+		assert_eq!(mismatches[1], ManifestMismatch::Missing(FileRef::new("sub_dir/data.bin")));
+		assert_eq!(mismatches[2], ManifestMismatch::Unexpected(FileRef::new("extra.txt")));
+	}
+
+	#[test]
+	fn test_verify_manifest_extensionless_files_and_dotted_dirs() {
+		let temp_dir:TempDirRef = TempDirRef::new("manifest_test_").unwrap();
+		let dir:FileRef = create_test_dir(&temp_dir);
+		std::fs::create_dir_all((dir.clone() + "/bin").os_path()).unwrap();
+		std::fs::write((dir.clone() + "/bin/tool").os_path(), "tool").unwrap();
+		std::fs::create_dir((dir.clone() + "/lib.v2").os_path()).unwrap();
+		std::fs::write((dir.clone() + "/lib.v2/LICENSE").os_path(), "MIT").unwrap();
+		let manifest:Manifest = dir.manifest(HashAlgorithm::Sha256).unwrap();
+		assert_eq!(manifest.entries().iter().map(|entry| entry.path.path()).collect::<Vec<&str>>(), ["abc.txt", "bin/tool", "lib.v2/LICENSE", "sub_dir/data.bin"]);
+
+		std::fs::write((dir.clone() + "/bin/tool").os_path(), "evil").unwrap();
+		let mismatches:Vec<ManifestMismatch> = dir.verify_manifest(&manifest).unwrap();
+		assert_eq!(mismatches.len(), 1);
+		assert!(matches!(&mismatches[0], ManifestMismatch::DigestChanged { path, .. } if path == &FileRef::new("bin/tool")));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,303 @@
+use std::{ collections::BTreeMap, error::Error, io::{ Error as IoError, ErrorKind }, sync::{ Arc, Mutex, MutexGuard, atomic::{ AtomicUsize, Ordering } } };
+use crate::{ FileRef, FileSystem, SEPARATOR, mount_file_system, unmount_file_system };
+
+
+
+static MEMORY_FS_COUNTER:AtomicUsize = AtomicUsize::new(0);
+const MEMORY_FS_ROOT_PREFIX:&str = "memfs";
+const MEMORY_FS_ROOT_SUFFIX:&str = ":";
+
+
+
+pub struct MemoryFs {
+	root:String,
+	backend:Arc<MemoryFsBackend>
+}
+impl MemoryFs {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new empty in-memory filesystem, mounted at a unique root. FileRefs created with `file_ref` are bound to it until it is dropped.
+	pub fn new() -> MemoryFs {
+		let root:String = format!("{MEMORY_FS_ROOT_PREFIX}{}{MEMORY_FS_ROOT_SUFFIX}", MEMORY_FS_COUNTER.fetch_add(1, Ordering::Relaxed));
+		let state:MemoryFsState = MemoryFsState { root: root.clone(), entries: BTreeMap::new(), capacity: None, denied: Vec::new(), read_only: Vec::new() };
+		let backend:Arc<MemoryFsBackend> = Arc::new(MemoryFsBackend { state: Mutex::new(state) });
+		mount_file_system(&FileRef::new(&root), backend.clone());
+		MemoryFs { root, backend }
+	}
+
+
+
+	/// Create an empty in-memory backend that is not mounted anywhere and stores the full paths of FileRefs. Used in place of the filesystem of the OS on targets that have none.
+	pub(crate) fn detached_backend() -> Arc<dyn FileSystem> {
+		let state:MemoryFsState = MemoryFsState { root: String::new(), entries: BTreeMap::new(), capacity: None, denied: Vec::new(), read_only: Vec::new() };
+		Arc::new(MemoryFsBackend { state: Mutex::new(state) })
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the root dir of the filesystem.
+	pub fn root(&self) -> FileRef {
+		FileRef::new(&self.root)
+	}
+
+	/// Create a FileRef bound to this filesystem. The path is interpreted relative to the root of the filesystem.
+	pub fn file_ref(&self, path:&str) -> FileRef {
+		FileRef::new(&(self.root.clone() + SEPARATOR + path.replace('\\', SEPARATOR).trim_start_matches(SEPARATOR)))
+	}
+
+	/// Get the total size of all files in the filesystem.
+	pub fn used_bytes(&self) -> u64 {
+		self.backend.state.lock().unwrap().used_bytes()
+	}
+
+
+
+	/* SIMULATION METHODS */
+
+	/// Limit the total size of all files. Writes that would exceed the capacity fail with a `StorageFull` error, simulating a full disk.
+	pub fn set_capacity(&self, capacity:Option<u64>) {
+		self.backend.state.lock().unwrap().capacity = capacity;
+	}
+
+	/// Make all access to the given file or dir and its contents fail with a `PermissionDenied` error.
+	pub fn deny_access(&self, file:&FileRef) {
+		let mut state:MutexGuard<MemoryFsState> = self.backend.state.lock().unwrap();
+		let path:String = state.inner_path(file.path());
+		state.denied.push(path);
+	}
+
+	/// Make all modifications to the given file or dir and its contents fail with a `PermissionDenied` error.
+	pub fn set_read_only(&self, file:&FileRef) {
+		let mut state:MutexGuard<MemoryFsState> = self.backend.state.lock().unwrap();
+		let path:String = state.inner_path(file.path());
+		state.read_only.push(path);
+	}
+
+	/// Remove all access restrictions set by `deny_access` and `set_read_only`.
+	pub fn clear_permissions(&self) {
+		let mut state:MutexGuard<MemoryFsState> = self.backend.state.lock().unwrap();
+		state.denied.clear();
+		state.read_only.clear();
+	}
+}
+impl Default for MemoryFs {
+	fn default() -> Self {
+		MemoryFs::new()
+	}
+}
+impl Drop for MemoryFs {
+	fn drop(&mut self) {
+		unmount_file_system(&FileRef::new(&self.root));
+	}
+}
+
+
+
+enum MemoryEntry {
+	Dir,
+	File(Vec<u8>)
+}
+
+
+
+struct MemoryFsState {
+	root:String,
+	entries:BTreeMap<String, MemoryEntry>,
+	capacity:Option<u64>,
+	denied:Vec<String>,
+	read_only:Vec<String>
+}
+impl MemoryFsState {
+
+	/* PATH METHODS */
+
+	/// Get the path of a file inside the filesystem, like '/dir/file.txt'. The root is an empty path.
+	fn inner_path(&self, path:&str) -> String {
+		path.strip_prefix(&self.root).unwrap_or(path).trim_end_matches(SEPARATOR).to_owned()
+	}
+
+	/// Check if the path is the given dir or inside it.
+	fn is_in(path:&str, dir:&str) -> bool {
+		path == dir || path.starts_with(&(dir.to_owned() + SEPARATOR))
+	}
+
+	/// Return an error if the path may not be accessed, or not be modified when writing.
+	fn check_access(&self, path:&str, write:bool) -> Result<(), IoError> {
+		let denied:bool = self.denied.iter().any(|dir| MemoryFsState::is_in(path, dir));
+		let read_only:bool = write && self.read_only.iter().any(|dir| MemoryFsState::is_in(path, dir));
+		if denied || read_only {
+			Err(IoError::new(ErrorKind::PermissionDenied, format!("Permission denied for \"{}{path}\".", self.root)))
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Get the total size of all files.
+	fn used_bytes(&self) -> u64 {
+		self.entries.values().map(|entry| if let MemoryEntry::File(data) = entry { data.len() as u64 } else { 0 }).sum()
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Check if the file or dir exists.
+	fn exists(&self, path:&str) -> bool {
+		path.is_empty() || self.entries.contains_key(path)
+	}
+
+	/// Check if the file or dir can be accessed.
+	fn is_accessible(&self, path:&str) -> bool {
+		self.check_access(path, false).is_ok()
+	}
+
+	/// Check if the path is an existing dir.
+	fn is_dir(&self, path:&str) -> bool {
+		path.is_empty() || matches!(self.entries.get(path), Some(MemoryEntry::Dir))
+	}
+
+	/// Create an empty file or dir. The parent dir must exist.
+	fn create(&mut self, path:&str, is_dir:bool) -> Result<(), IoError> {
+		self.check_access(path, true)?;
+		let parent_dir:&str = &path[..path.rfind(SEPARATOR).unwrap_or(0)];
+		if self.exists(path) {
+			Err(IoError::new(ErrorKind::AlreadyExists, format!("\"{}{path}\" already exists.", self.root)))
+		} else if !parent_dir.is_empty() && !matches!(self.entries.get(parent_dir), Some(MemoryEntry::Dir)) {
+			Err(IoError::new(ErrorKind::NotFound, format!("Parent dir \"{}{parent_dir}\" does not exist.", self.root)))
+		} else {
+			self.entries.insert(path.to_owned(), if is_dir { MemoryEntry::Dir } else { MemoryEntry::File(Vec::new()) });
+			Ok(())
+		}
+	}
+
+	/// Read the contents of a file.
+	fn read(&self, path:&str) -> Result<Vec<u8>, IoError> {
+		self.check_access(path, false)?;
+		match self.entries.get(path) {
+			Some(MemoryEntry::File(data)) => Ok(data.clone()),
+			Some(MemoryEntry::Dir) => Err(IoError::new(ErrorKind::IsADirectory, format!("\"{}{path}\" is a dir.", self.root))),
+			None => Err(IoError::new(ErrorKind::NotFound, format!("\"{}{path}\" does not exist.", self.root)))
+		}
+	}
+
+	/// Get the size of a file in bytes.
+	fn size(&self, path:&str) -> Result<u64, IoError> {
+		self.check_access(path, false)?;
+		match self.entries.get(path) {
+			Some(MemoryEntry::File(data)) => Ok(data.len() as u64),
+			Some(MemoryEntry::Dir) => Err(IoError::new(ErrorKind::IsADirectory, format!("\"{}{path}\" is a dir.", self.root))),
+			None => Err(IoError::new(ErrorKind::NotFound, format!("\"{}{path}\" does not exist.", self.root)))
+		}
+	}
+
+	/// Write data to an existing file at the given offset, or replace all contents if no offset is given.
+	fn write_at(&mut self, path:&str, offset:Option<u64>, data:&[u8]) -> Result<(), IoError> {
+		let mut contents:Vec<u8> = self.read(path)?;
+		self.check_access(path, true)?;
+		let previous_size:u64 = contents.len() as u64;
+		match offset {
+			Some(offset) => {
+				let end:usize = offset as usize + data.len();
+				if contents.len() < end {
+					contents.resize(end, 0);
+				}
+				contents[offset as usize..end].copy_from_slice(data);
+			},
+			None => contents = data.to_vec()
+		}
+
+		// Simulate a full disk.
+		if let Some(capacity) = self.capacity {
+			if self.used_bytes() - previous_size + contents.len() as u64 > capacity {
+				return Err(IoError::new(ErrorKind::StorageFull, format!("Could not write to \"{}{path}\". Filesystem capacity of {capacity} bytes exceeded.", self.root)));
+			}
+		}
+		self.entries.insert(path.to_owned(), MemoryEntry::File(contents));
+		Ok(())
+	}
+
+	/// Append data to an existing file.
+	fn append(&mut self, path:&str, data:&[u8]) -> Result<(), IoError> {
+		let size:u64 = self.read(path)?.len() as u64;
+		self.write_at(path, Some(size), data)
+	}
+
+	/// Remove a file, or a dir and all of its contents.
+	fn remove(&mut self, path:&str) -> Result<(), IoError> {
+		self.check_access(path, true)?;
+		if !self.exists(path) {
+			return Err(IoError::new(ErrorKind::NotFound, format!("\"{}{path}\" does not exist.", self.root)));
+		}
+		self.entries.retain(|entry_path, _| !MemoryFsState::is_in(entry_path, path));
+		Ok(())
+	}
+
+	/// List the direct children of a dir.
+	fn list(&self, path:&str) -> Result<Vec<String>, IoError> {
+		self.check_access(path, false)?;
+		if !path.is_empty() && !matches!(self.entries.get(path), Some(MemoryEntry::Dir)) {
+			return Err(IoError::new(ErrorKind::NotFound, format!("Dir \"{}{path}\" does not exist.", self.root)));
+		}
+		let child_prefix:String = path.to_owned() + SEPARATOR;
+		Ok(self.entries.keys().filter(|entry_path| entry_path.strip_prefix(&child_prefix).map(|name| !name.contains(SEPARATOR)).unwrap_or(false)).map(|entry_path| self.root.clone() + entry_path).collect())
+	}
+}
+
+
+
+struct MemoryFsBackend {
+	state:Mutex<MemoryFsState>
+}
+impl MemoryFsBackend {
+
+	/// Run a function on the state, passing the path inside the filesystem.
+	fn with_state<T, U>(&self, path:&str, function:T) -> U where T:FnOnce(&mut MemoryFsState, &str) -> U {
+		let mut state:MutexGuard<MemoryFsState> = self.state.lock().unwrap();
+		let path:String = state.inner_path(path);
+		function(&mut state, &path)
+	}
+}
+impl FileSystem for MemoryFsBackend {
+	fn exists(&self, path:&str) -> bool {
+		self.with_state(path, |state, path| state.exists(path))
+	}
+
+	fn is_accessible(&self, path:&str) -> bool {
+		self.with_state(path, |state, path| state.is_accessible(path))
+	}
+
+	fn is_dir(&self, path:&str) -> bool {
+		self.with_state(path, |state, path| state.is_dir(path))
+	}
+
+	fn create(&self, path:&str, is_dir:bool) -> Result<(), Box<dyn Error>> {
+		self.with_state(path, |state, path| state.create(path, is_dir)).map_err(|error| error.into())
+	}
+
+	fn read(&self, path:&str) -> Result<Vec<u8>, Box<dyn Error>> {
+		self.with_state(path, |state, path| state.read(path)).map_err(|error| error.into())
+	}
+
+	fn size(&self, path:&str) -> Result<u64, Box<dyn Error>> {
+		self.with_state(path, |state, path| state.size(path)).map_err(|error| error.into())
+	}
+
+	fn write_at(&self, path:&str, offset:Option<u64>, data:&[u8]) -> Result<(), Box<dyn Error>> {
+		self.with_state(path, |state, path| state.write_at(path, offset, data)).map_err(|error| error.into())
+	}
+
+	fn append(&self, path:&str, data:&[u8]) -> Result<(), Box<dyn Error>> {
+		self.with_state(path, |state, path| state.append(path, data)).map_err(|error| error.into())
+	}
+
+	fn remove(&self, path:&str) -> Result<(), Box<dyn Error>> {
+		self.with_state(path, |state, path| state.remove(path)).map_err(|error| error.into())
+	}
+
+	fn list(&self, path:&str) -> Result<Vec<String>, Box<dyn Error>> {
+		self.with_state(path, |state, path| state.list(path)).map_err(|error| error.into())
+	}
+}
\ No newline at end of file
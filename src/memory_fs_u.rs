@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+	use std::{ error::Error, io::ErrorKind };
+	use crate::{ DirEntries, DirSnapshot, FileRef, FileRefError, MemoryFs, SyncOptions, TempFileRef };
+
+
+
+	fn error_kind(error:Box<dyn Error>) -> ErrorKind {
+		error.downcast::<FileRefError>().ok().and_then(|error| error.io_error_kind()).unwrap_or(ErrorKind::Other)
+	}
+
+
+
+	#[test]
+	fn test_memory_fs_read_write() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let file:FileRef = memory_fs.file_ref("dir/sub/file.txt");
+		assert!(!file.exists());
+		file.write("Hello").unwrap();
+		file.append_bytes(b" world").unwrap();
+		file.write_bytes_to_range(0, b"J").unwrap();
+		assert!(file.exists());
+		assert!(memory_fs.file_ref("dir/sub").exists());
+		assert_eq!(file.read().unwrap(), "Jello world");
+		assert_eq!(file.read_range(6, 11).unwrap(), b"world");
+		assert_eq!(memory_fs.used_bytes(), 11);
+	}
+
+	#[test]
+	fn test_memory_fs_is_dir_fs() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let dotted_dir:FileRef = memory_fs.file_ref("backup.2024");
+		dotted_dir.file_system().create(dotted_dir.path(), true).unwrap();
+		memory_fs.file_ref("backup.2024/file.txt").write("contents").unwrap();
+		assert!(dotted_dir.is_dir_fs());
+		assert!(memory_fs.file_ref("backup.2024/file.txt").is_file_fs());
+		assert!(memory_fs.root().is_dir_fs());
+		assert!(!memory_fs.file_ref("missing").is_dir_fs());
+	}
+
+	#[test]
+	fn test_memory_fs_does_not_touch_disk() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let file:FileRef = memory_fs.file_ref("file.txt");
+		file.write("contents").unwrap();
+		assert!(!std::path::Path::new(file.path()).exists());
+		assert!(file.is_absolute_path());
+	}
+
+	#[test]
+	fn test_memory_fs_scan_copy_delete() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		memory_fs.file_ref("a.txt").write("a").unwrap();
+		memory_fs.file_ref("dir/b.txt").write("b").unwrap();
+		memory_fs.file_ref("dir/c.txt").write("c").unwrap();
+		let mut files:Vec<String> = memory_fs.root().list_files_recurse().iter().map(|file| file.name().to_owned()).collect();
+		files.sort();
+		assert_eq!(files, vec!["a.txt", "b.txt", "c.txt"]);
+
+		assert_eq!(memory_fs.file_ref("a.txt").copy_to(&memory_fs.file_ref("copy/a.txt")).unwrap(), 1);
+		assert_eq!(memory_fs.file_ref("copy/a.txt").read().unwrap(), "a");
+
+		memory_fs.file_ref("dir").delete().unwrap();
+		assert!(!memory_fs.file_ref("dir/b.txt").exists());
+		assert_eq!(memory_fs.used_bytes(), 2);
+	}
+
+	#[test]
+	fn test_memory_fs_permission_denied() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let secret:FileRef = memory_fs.file_ref("secret/key.txt");
+		let config:FileRef = memory_fs.file_ref("config.txt");
+		secret.write("key").unwrap();
+		config.write("config").unwrap();
+
+		memory_fs.deny_access(&memory_fs.file_ref("secret"));
+		memory_fs.set_read_only(&config);
+		assert!(!secret.is_accessible());
+		assert_eq!(error_kind(secret.read().unwrap_err()), ErrorKind::PermissionDenied);
+		assert_eq!(config.read().unwrap(), "config");
+		assert_eq!(error_kind(config.write("changed").unwrap_err()), ErrorKind::PermissionDenied);
+		assert_eq!(error_kind(config.delete().unwrap_err()), ErrorKind::PermissionDenied);
+
+		memory_fs.clear_permissions();
+		assert_eq!(secret.read().unwrap(), "key");
+	}
+
+	#[test]
+	fn test_memory_fs_full_disk() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		memory_fs.set_capacity(Some(8));
+		let file:FileRef = memory_fs.file_ref("file.txt");
+		file.write("12345").unwrap();
+		assert_eq!(error_kind(file.append_bytes(b"6789").unwrap_err()), ErrorKind::StorageFull);
+		assert_eq!(file.read().unwrap(), "12345");
+		file.write("12345678").unwrap();
+	}
+
+	#[test]
+	fn test_memory_fs_separate_instances() {
+		let first:MemoryFs = MemoryFs::new();
+		let second:MemoryFs = MemoryFs::new();
+		first.file_ref("file.txt").write("first").unwrap();
+		assert!(!second.file_ref("file.txt").exists());
+
+		let file:FileRef = first.file_ref("file.txt");
+		drop(first);
+		assert!(!file.exists());
+	}
+
+	#[test]
+	fn test_memory_fs_rejects_os_only_features() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let dir:FileRef = memory_fs.file_ref("dir");
+		(dir.clone() + "/file.txt").write("contents").unwrap();
+
+		let error:Box<dyn Error> = DirSnapshot::capture(&dir).unwrap_err();
+		assert!(error.to_string().contains("Only supported on the filesystem of the OS"));
+		assert!(TempFileRef::new_in(&dir, "tmp").is_err());
+		assert!(DirEntries::collect(&dir).is_err());
+		assert!(dir.diff_dir(&dir).is_err());
+		assert!(dir.sync_to(&memory_fs.file_ref("copy"), SyncOptions::new()).is_err());
+		assert!(!memory_fs.file_ref("copy").exists());
+		#[cfg(unix)]
+		assert!((dir.clone() + "/file.txt").set_mode(0o600).is_err());
+	}
+}
\ No newline at end of file
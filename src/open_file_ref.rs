@@ -0,0 +1,199 @@
+use std::{ error::Error, fs::{ File, OpenOptions }, io::{ Read, Seek, SeekFrom, Write } };
+use crate::{ FileOperation, FileRef };
+
+
+
+/// Options for opening a file, mirroring `std::fs::OpenOptions`. Parent dirs are created automatically when the file may be created, and errors mention the file.
+#[derive(Clone, Debug)]
+pub struct FileOpenOptions {
+	file:FileRef,
+	read:bool,
+	write:bool,
+	append:bool,
+	truncate:bool,
+	create:bool,
+	create_new:bool,
+	create_parent_dirs:bool,
+	mode:Option<u32>
+}
+impl FileOpenOptions {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create new options for the given file. All options start disabled, except for creating parent dirs.
+	pub fn new(file:&FileRef) -> FileOpenOptions {
+		FileOpenOptions { file: file.clone(), read: false, write: false, append: false, truncate: false, create: false, create_new: false, create_parent_dirs: true, mode: None }
+	}
+
+	/// Return self with a setting to open the file for reading.
+	pub fn read(mut self, read:bool) -> Self {
+		self.read = read;
+		self
+	}
+
+	/// Return self with a setting to open the file for writing.
+	pub fn write(mut self, write:bool) -> Self {
+		self.write = write;
+		self
+	}
+
+	/// Return self with a setting to append writes to the end of the file.
+	pub fn append(mut self, append:bool) -> Self {
+		self.append = append;
+		self
+	}
+
+	/// Return self with a setting to clear the file when opening it.
+	pub fn truncate(mut self, truncate:bool) -> Self {
+		self.truncate = truncate;
+		self
+	}
+
+	/// Return self with a setting to create the file if it does not exist.
+	pub fn create(mut self, create:bool) -> Self {
+		self.create = create;
+		self
+	}
+
+	/// Return self with a setting to create the file, failing if it already exists.
+	pub fn create_new(mut self, create_new:bool) -> Self {
+		self.create_new = create_new;
+		self
+	}
+
+	/// Return self with a setting to create missing parent dirs when the file may be created. Enabled by default.
+	pub fn create_parent_dirs(mut self, create_parent_dirs:bool) -> Self {
+		self.create_parent_dirs = create_parent_dirs;
+		self
+	}
+
+	/// Return self with the unix permission bits used when the file is created. Ignored on other platforms.
+	pub fn mode(mut self, mode:u32) -> Self {
+		self.mode = Some(mode);
+		self
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Open the file with the configured options.
+	pub fn open(&self) -> Result<OpenFileRef, Box<dyn Error>> {
+		let operation:FileOperation = if self.append { FileOperation::Append } else if self.write || self.create || self.create_new { FileOperation::Write } else { FileOperation::Read };
+		self.file.require_std_file_system(operation)?;
+		if self.file.is_dir() {
+			return Err(self.file.error(operation, "Only able to open files."));
+		}
+		if self.create_parent_dirs && (self.create || self.create_new) {
+			self.file.guarantee_parent_dir()?;
+		}
+
+		let mut options:OpenOptions = OpenOptions::new();
+		options.read(self.read).write(self.write).append(self.append).truncate(self.truncate).create(self.create).create_new(self.create_new);
+		#[cfg(unix)]
+		if let Some(mode) = self.mode {
+			use std::os::unix::fs::OpenOptionsExt;
+			options.mode(mode);
+		}
+		let handle:File = options.open(self.file.os_path()).map_err(|error| self.file.io_failure(operation, Box::new(error)))?;
+		Ok(OpenFileRef { file: self.file.clone(), handle })
+	}
+}
+
+
+
+/// A file that is kept open between operations, so random access reads and writes do not have to reopen the file for every call.
+pub struct OpenFileRef {
+	file:FileRef,
+	handle:File
+}
+impl OpenFileRef {
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the file that is opened.
+	pub fn file(&self) -> &FileRef {
+		&self.file
+	}
+
+	/// Get the underlying file handle, for operations not covered by the OpenFileRef.
+	pub fn into_handle(self) -> File {
+		self.handle
+	}
+
+	/// Get the current size of the file in bytes.
+	pub fn len(&self) -> Result<u64, Box<dyn Error>> {
+		Ok(self.handle.metadata().map_err(|error| self.file.error(FileOperation::Read, error))?.len())
+	}
+
+	/// Check if the file is empty.
+	pub fn is_empty(&self) -> Result<bool, Box<dyn Error>> {
+		Ok(self.len()? == 0)
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Read a specific range of bytes from the file.
+	pub fn read_range(&mut self, start:u64, end:u64) -> Result<Vec<u8>, Box<dyn Error>> {
+		let mut buffer:Vec<u8> = vec![0; end.saturating_sub(start) as usize];
+		self.handle.seek(SeekFrom::Start(start)).and_then(|_| self.handle.read_exact(&mut buffer)).map_err(|error| self.file.error(FileOperation::Read, error))?;
+		Ok(buffer)
+	}
+
+	/// Read the entire contents of the file.
+	pub fn read_bytes(&mut self) -> Result<Vec<u8>, Box<dyn Error>> {
+		let mut buffer:Vec<u8> = Vec::new();
+		self.handle.seek(SeekFrom::Start(0)).and_then(|_| self.handle.read_to_end(&mut buffer)).map_err(|error| self.file.error(FileOperation::Read, error))?;
+		Ok(buffer)
+	}
+
+	/// Write bytes to the file at the given offset, overwriting existing contents.
+	pub fn write_bytes_to_range(&mut self, start:u64, data:&[u8]) -> Result<(), Box<dyn Error>> {
+		self.handle.seek(SeekFrom::Start(start)).and_then(|_| self.handle.write_all(data)).map_err(|error| self.file.error(FileOperation::Write, error))
+	}
+
+	/// Append bytes to the end of the file.
+	pub fn append_bytes(&mut self, data:&[u8]) -> Result<(), Box<dyn Error>> {
+		self.handle.seek(SeekFrom::End(0)).and_then(|_| self.handle.write_all(data)).map_err(|error| self.file.error(FileOperation::Append, error))
+	}
+
+	/// Flush all written data to disk.
+	pub fn sync(&self) -> Result<(), Box<dyn Error>> {
+		self.handle.sync_data().map_err(|error| self.file.error(FileOperation::Write, error))
+	}
+}
+
+
+
+impl FileRef {
+
+	/* OPEN FILE METHODS */
+
+	/// Get options to open the file with, like `file.options().append(true).create(true).open()`.
+	pub fn options(&self) -> FileOpenOptions {
+		FileOpenOptions::new(self)
+	}
+
+	/// Open the file for reading and keep it open for repeated operations.
+	pub fn open(&self) -> Result<OpenFileRef, Box<dyn Error>> {
+		self.require_std_file_system(FileOperation::Read)?;
+		if self.is_dir() {
+			return Err(self.error(FileOperation::Read, "Only able to read files."));
+		}
+		let handle:File = File::open(self.os_path()).map_err(|error| self.error(FileOperation::Read, error))?;
+		Ok(OpenFileRef { file: self.clone(), handle })
+	}
+
+	/// Open the file for reading and writing and keep it open for repeated operations. Creates the file and its parent dirs if they do not exist.
+	pub fn open_writable(&self) -> Result<OpenFileRef, Box<dyn Error>> {
+		self.require_std_file_system(FileOperation::Write)?;
+		if self.is_dir() {
+			return Err(self.error(FileOperation::Write, "Only able to write to files."));
+		}
+		self.guarantee_parent_dir()?;
+		let handle:File = OpenOptions::new().read(true).write(true).create(true).truncate(false).open(self.os_path()).map_err(|error| self.error(FileOperation::Write, error))?;
+		Ok(OpenFileRef { file: self.clone(), handle })
+	}
+}
\ No newline at end of file
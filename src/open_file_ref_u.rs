@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileOperation, FileRef, FileRefError, MemoryFs, OpenFileRef, TempDirRef };
+
+
+
+	#[test]
+	fn test_open_file_random_access() {
+		let temp_dir:TempDirRef = TempDirRef::new("open_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/sub/data.bin";
+
+		let mut handle:OpenFileRef = file.open_writable().unwrap();
+		assert!(handle.is_empty().unwrap());
+		handle.append_bytes(b"0123456789").unwrap();
+		handle.write_bytes_to_range(2, b"ab").unwrap();
+		handle.append_bytes(b"!").unwrap();
+		handle.sync().unwrap();
+		assert_eq!(handle.read_range(1, 5).unwrap(), b"1ab4");
+		assert_eq!(handle.len().unwrap(), 11);
+		drop(handle);
+
+		let mut handle:OpenFileRef = file.open().unwrap();
+		assert_eq!(handle.read_bytes().unwrap(), b"01ab456789!");
+		assert!(handle.read_range(8, 20).is_err());
+		assert!(handle.append_bytes(b"read only").is_err());
+	}
+
+	#[test]
+	fn test_open_options() {
+		let temp_dir:TempDirRef = TempDirRef::new("open_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/logs/app.log";
+
+		let mut handle:OpenFileRef = file.options().append(true).create(true).mode(0o600).open().unwrap();
+		handle.append_bytes(b"first\n").unwrap();
+		drop(handle);
+		let mut handle:OpenFileRef = file.options().append(true).open().unwrap();
+		handle.append_bytes(b"second\n").unwrap();
+		drop(handle);
+		assert_eq!(file.read().unwrap(), "first\nsecond\n");
+
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			assert_eq!(std::fs::metadata(file.path()).unwrap().permissions().mode() & 0o777, 0o600);
+		}
+
+		let error:Box<dyn std::error::Error> = file.options().write(true).create_new(true).open().err().unwrap();
+		assert_eq!(error.downcast_ref::<FileRefError>().unwrap().operation(), FileOperation::Write);
+
+		let missing:FileRef = temp_dir.path() + "/missing/file.txt";
+		assert!(missing.options().write(true).create(true).create_parent_dirs(false).open().is_err());
+		let error:Box<dyn std::error::Error> = missing.options().read(true).open().err().unwrap();
+		assert!(error.to_string().ends_with("File does not exist."));
+	}
+
+	#[test]
+	fn test_open_file_errors() {
+		let temp_dir:TempDirRef = TempDirRef::new("open_file_test_").unwrap();
+		assert!((temp_dir.path() + "/missing.bin").open().is_err());
+
+		let memory_fs:MemoryFs = MemoryFs::new();
+		assert!(memory_fs.file_ref("data.bin").open_writable().is_err());
+	}
+}
\ No newline at end of file
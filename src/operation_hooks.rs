@@ -0,0 +1,123 @@
+use std::{ cell::Cell, error::Error, sync::{ Arc, RwLock, RwLockReadGuard, RwLockWriteGuard, atomic::{ AtomicU64, Ordering } } };
+use crate::{ FileOperation, FileRef, SEPARATOR };
+
+
+
+static OPERATION_HOOKS:RwLock<Vec<RegisteredHook>> = RwLock::new(Vec::new());
+static OPERATION_HOOK_COUNTER:AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+	static OPERATION_DEPTH:Cell<usize> = const { Cell::new(0) };
+}
+
+
+
+pub type OperationHook = Arc<dyn Fn(&OperationEvent) + Send + Sync>;
+
+
+
+/// The id of a registered hook, used to remove it again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OperationHookId(u64);
+
+
+
+/// A mutating operation executed through a FileRef, passed to the registered hooks after it finished.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OperationEvent {
+	pub operation:FileOperation,
+	pub path:FileRef,
+	pub target:Option<FileRef>,
+	pub error:Option<String>
+}
+impl OperationEvent {
+
+	/// Check if the operation succeeded.
+	pub fn succeeded(&self) -> bool {
+		self.error.is_none()
+	}
+}
+
+
+
+struct RegisteredHook {
+	id:OperationHookId,
+	root:Option<String>,
+	hook:OperationHook
+}
+impl RegisteredHook {
+
+	/// Check if the hook should be invoked for an operation on the given path or target.
+	fn applies_to(&self, path:&FileRef, target:Option<&FileRef>) -> bool {
+		match &self.root {
+			None => true,
+			Some(root) => std::iter::once(path).chain(target).any(|file| file.path().strip_prefix(root.as_str()).is_some_and(|remainder| remainder.is_empty() || remainder.starts_with(SEPARATOR)))
+		}
+	}
+}
+
+
+
+/// Stops tracking the operation of the current thread when dropped, so a panicking operation does not silence the hooks of the thread.
+struct OperationDepthGuard;
+impl Drop for OperationDepthGuard {
+	fn drop(&mut self) {
+		OPERATION_DEPTH.with(|depth| depth.set(depth.get() - 1));
+	}
+}
+
+
+
+/// Register a hook invoked after every create, write, copy, move and delete executed through a FileRef, like to keep an audit log or undo history. Returns the id to remove the hook with.
+pub fn add_operation_hook<T>(hook:T) -> OperationHookId where T:Fn(&OperationEvent) + Send + Sync + 'static {
+	register_hook(None, Arc::new(hook))
+}
+
+/// Remove a previously registered hook. Removing a hook that is not registered does nothing.
+pub fn remove_operation_hook(id:OperationHookId) {
+	if let Ok(mut hooks) = OPERATION_HOOKS.write() {
+		hooks.retain(|registered| registered.id != id);
+	}
+}
+
+/// Store a hook in the registry.
+fn register_hook(root:Option<String>, hook:OperationHook) -> OperationHookId {
+	let id:OperationHookId = OperationHookId(OPERATION_HOOK_COUNTER.fetch_add(1, Ordering::SeqCst));
+	let mut hooks:RwLockWriteGuard<Vec<RegisteredHook>> = OPERATION_HOOKS.write().unwrap();
+	hooks.push(RegisteredHook { id, root, hook });
+	id
+}
+
+
+
+impl FileRef {
+
+	/* OPERATION HOOK METHODS */
+
+	/// Register a hook only invoked for operations on this file or the contents of this dir, including copies and moves into it. Returns the id to remove the hook with.
+	pub fn add_operation_hook<T>(&self, hook:T) -> OperationHookId where T:Fn(&OperationEvent) + Send + Sync + 'static {
+		register_hook(Some(self.path().trim_end_matches(SEPARATOR).to_owned()), Arc::new(hook))
+	}
+
+	/// Execute a mutating operation and report its outcome to the registered hooks. Operations nested in another operation, like the delete of a move, or executed by a hook itself, are not reported separately.
+	pub(crate) fn with_operation_hooks<T, U>(&self, operation:FileOperation, target:Option<&FileRef>, action:U) -> Result<T, Box<dyn Error>> where U:FnOnce() -> Result<T, Box<dyn Error>> {
+		let is_nested:bool = OPERATION_DEPTH.with(|depth| { depth.set(depth.get() + 1); depth.get() > 1 });
+		let _guard:OperationDepthGuard = OperationDepthGuard;
+		let result:Result<T, Box<dyn Error>> = action();
+		if is_nested {
+			return result;
+		}
+
+		let hooks:Vec<OperationHook> = {
+			let registered:RwLockReadGuard<Vec<RegisteredHook>> = OPERATION_HOOKS.read().unwrap();
+			registered.iter().filter(|registered| registered.applies_to(self, target)).map(|registered| registered.hook.clone()).collect()
+		};
+		if !hooks.is_empty() {
+			let event:OperationEvent = OperationEvent { operation, path: self.clone(), target: target.cloned(), error: result.as_ref().err().map(|error| error.to_string()) };
+			for hook in &hooks {
+				hook(&event);
+			}
+		}
+		result
+	}
+}
\ No newline at end of file
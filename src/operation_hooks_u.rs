@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+	use std::{ sync::{ Arc, Mutex }, time::SystemTime };
+	use crate::{ FileOperation, FileRef, OperationEvent, OperationHookId, TempDirRef, add_operation_hook, remove_operation_hook };
+
+
+
+	/// Register a hook on the dir that collects all events it receives.
+	fn record_events(dir:&FileRef) -> (OperationHookId, Arc<Mutex<Vec<OperationEvent>>>) {
+		let events:Arc<Mutex<Vec<OperationEvent>>> = Arc::new(Mutex::new(Vec::new()));
+		let recorded:Arc<Mutex<Vec<OperationEvent>>> = events.clone();
+		let id:OperationHookId = dir.add_operation_hook(move |event| recorded.lock().unwrap().push(event.clone()));
+		(id, events)
+	}
+
+
+
+	#[test]
+	fn test_operation_hooks() {
+		let temp_dir:TempDirRef = TempDirRef::new("operation_hooks_test_").unwrap();
+		let (id, events):(OperationHookId, Arc<Mutex<Vec<OperationEvent>>>) = record_events(&temp_dir.path());
+		let file:FileRef = temp_dir.path() + "/sub/file.txt";
+		let moved:FileRef = temp_dir.path() + "/moved.txt";
+
+		file.write("contents").unwrap();
+		file.append_bytes(b"!").unwrap();
+		file.move_into(&temp_dir.path()).unwrap();
+		(temp_dir.path() + "/file.txt").delete().unwrap();
+		assert!(moved.delete().is_err());
+
+		let operations:Vec<(FileOperation, bool)> = events.lock().unwrap().iter().map(|event| (event.operation, event.succeeded())).collect();
+		assert_eq!(operations, vec![(FileOperation::Write, true), (FileOperation::Append, true), (FileOperation::Move, true), (FileOperation::Delete, true), (FileOperation::Delete, false)]);
+		assert_eq!(events.lock().unwrap()[2].target, Some(temp_dir.path() + "/file.txt"));
+
+		remove_operation_hook(id);
+		moved.write("contents").unwrap();
+		assert_eq!(events.lock().unwrap().len(), 5);
+	}
+
+	#[test]
+	fn test_operation_hooks_metadata_setters() {
+		let temp_dir:TempDirRef = TempDirRef::new("operation_hooks_metadata_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/file.txt";
+		file.write("contents").unwrap();
+		let (id, events):(OperationHookId, Arc<Mutex<Vec<OperationEvent>>>) = record_events(&temp_dir.path());
+
+		file.set_modified(SystemTime::UNIX_EPOCH).unwrap();
+		file.set_accessed(SystemTime::UNIX_EPOCH).unwrap();
+		file.set_readonly(true).unwrap();
+		file.set_readonly(false).unwrap();
+		assert!((temp_dir.path() + "/missing.txt").set_modified(SystemTime::UNIX_EPOCH).is_err());
+		remove_operation_hook(id);
+
+		let operations:Vec<(FileOperation, bool)> = events.lock().unwrap().iter().map(|event| (event.operation, event.succeeded())).collect();
+		assert_eq!(operations, vec![(FileOperation::Write, true), (FileOperation::Write, true), (FileOperation::Write, true), (FileOperation::Write, true), (FileOperation::Write, false)]);
+	}
+
+	#[test]
+	fn test_operation_hooks_scope() {
+		let temp_dir:TempDirRef = TempDirRef::new("operation_hooks_scope_test_").unwrap();
+		let watched:FileRef = temp_dir.path() + "/watched";
+		let (_, events):(OperationHookId, Arc<Mutex<Vec<OperationEvent>>>) = record_events(&watched);
+
+		(temp_dir.path() + "/other.txt").write("contents").unwrap();
+		(temp_dir.path() + "/watched_sibling.txt").write("contents").unwrap();
+		(temp_dir.path() + "/other.txt").copy_to(&(watched.clone() + "/copy.txt")).unwrap();
+		assert_eq!(events.lock().unwrap().len(), 1);
+		assert_eq!(events.lock().unwrap()[0].operation, FileOperation::Copy);
+	}
+
+	#[test]
+	fn test_operation_hooks_audit_log() {
+		let temp_dir:TempDirRef = TempDirRef::new("operation_hooks_log_test_").unwrap();
+		let log:FileRef = temp_dir.path() + "/audit.log";
+		let data_dir:FileRef = temp_dir.path() + "/data";
+		let hook_log:FileRef = log.clone();
+		let hook_dir:FileRef = data_dir.clone();
+		let id:OperationHookId = add_operation_hook(move |event| {
+			if event.path.path().starts_with(hook_dir.path()) {
+				// Writes executed by a hook are not reported again.
+				hook_log.write(&format!("{}{} {}\n", hook_log.read_or_default().unwrap(), event.operation, event.path.name())).unwrap();
+			}
+		});
+
+		(data_dir.clone() + "/a.txt").write("a").unwrap();
+		(data_dir.clone() + "/a.txt").delete().unwrap();
+		remove_operation_hook(id);
+		assert_eq!(log.read().unwrap(), "write to a.txt\ndelete a.txt\n");
+	}
+}
\ No newline at end of file
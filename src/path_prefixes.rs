@@ -0,0 +1,70 @@
+use crate::{ FileRef, SEPARATOR };
+
+
+
+/// An iterator over the accumulated prefixes of a path, like 'a', 'a/b' and 'a/b/c' for 'a/b/c'. Empty nodes, like the one before the separator of an absolute path, are skipped.
+pub struct PathPrefixes {
+	path:FileRef,
+	search_index:Option<usize>
+}
+impl PathPrefixes {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new iterator over the prefixes of the given path.
+	pub fn new(path:&FileRef) -> PathPrefixes {
+		PathPrefixes { path: path.clone(), search_index: Some(0) }
+	}
+}
+impl Iterator for PathPrefixes {
+	type Item = FileRef;
+
+	fn next(&mut self) -> Option<FileRef> {
+		let path:&str = self.path.path();
+		while let Some(search_index) = self.search_index {
+			match path[search_index..].find(SEPARATOR) {
+				Some(relative_index) => {
+					self.search_index = Some(search_index + relative_index + SEPARATOR.len());
+					if relative_index > 0 {
+						return Some(FileRef::new(&path[..search_index + relative_index]));
+					}
+				},
+				None => {
+					self.search_index = None;
+					if search_index < path.len() {
+						return Some(self.path.clone());
+					}
+				}
+			}
+		}
+		None
+	}
+}
+
+
+
+impl FileRef {
+
+	/// Get an iterator over the accumulated prefixes of the path, ending with the path itself.
+	pub fn prefixes(&self) -> PathPrefixes {
+		PathPrefixes::new(self)
+	}
+
+	/// Find the closest file or dir with the given name, searching the path itself and each dir above it up to the root.
+	pub fn find_up(&self, name:&str) -> Option<FileRef> {
+		let start:FileRef = self.clone().absolute();
+		let mut dirs:Vec<FileRef> = start.prefixes().collect();
+		if start.path().starts_with(SEPARATOR) {
+			dirs.insert(0, FileRef::new(""));
+		}
+		dirs.into_iter().rev().map(|dir| dir + SEPARATOR + name).find(|candidate| candidate.exists())
+	}
+}
+impl IntoIterator for &FileRef {
+	type Item = FileRef;
+	type IntoIter = PathPrefixes;
+
+	fn into_iter(self) -> PathPrefixes {
+		self.prefixes()
+	}
+}
\ No newline at end of file
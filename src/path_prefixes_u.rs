@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, TempDirRef };
+
+
+
+	fn prefixes(path:&str) -> Vec<String> {
+		FileRef::new(path).prefixes().map(|prefix| prefix.path().to_string()).collect()
+	}
+
+
+
+	#[test]
+	fn test_prefixes() {
+		assert_eq!(prefixes("a/b/c.txt"), vec!["a", "a/b", "a/b/c.txt"]);
+		assert_eq!(prefixes("file.txt"), vec!["file.txt"]);
+		assert_eq!(prefixes(""), Vec::<String>::new());
+	}
+
+	#[test]
+	fn test_prefixes_skip_empty_nodes() {
+		assert_eq!(prefixes("/home/user"), vec!["/home", "/home/user"]);
+		assert_eq!(prefixes("C:/dir//file.txt"), vec!["C:", "C:/dir", "C:/dir/file.txt"]);
+		assert_eq!(prefixes("dir/sub/"), vec!["dir", "dir/sub"]);
+	}
+
+	#[test]
+	fn test_prefixes_into_iterator() {
+		let path:FileRef = FileRef::new("a/b");
+		let mut found:Vec<FileRef> = Vec::new();
+		for prefix in &path {
+			found.push(prefix);
+		}
+		assert_eq!(found, vec![FileRef::new("a"), FileRef::new("a/b")]);
+	}
+
+	#[test]
+	fn test_find_up() {
+		let temp_dir:TempDirRef = TempDirRef::new("find_up_test_").unwrap();
+		let root:String = temp_dir.path().path().to_string();
+		std::fs::create_dir_all(format!("{root}/project/src/deep")).unwrap();
+		std::fs::write(format!("{root}/project/marker.toml"), "").unwrap();
+		std::fs::write(format!("{root}/project/src/deep/main.rs"), "").unwrap();
+		let source:FileRef = FileRef::new(&format!("{root}/project/src/deep/main.rs"));
+
+		assert_eq!(source.find_up("marker.toml").unwrap().path(), format!("{root}/project/marker.toml"));
+		assert_eq!(source.find_up("src").unwrap().path(), format!("{root}/project/src"));
+		assert!(source.find_up("find_up_missing_marker.toml").is_none());
+
+		std::fs::write(format!("{root}/project/src/marker.toml"), "").unwrap();
+		assert_eq!(source.find_up("marker.toml").unwrap().path(), format!("{root}/project/src/marker.toml"));
+	}
+}
\ No newline at end of file
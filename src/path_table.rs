@@ -0,0 +1,150 @@
+use std::{ collections::{ HashMap, HashSet }, sync::Arc };
+use crate::{ FileRef, SEPARATOR };
+
+
+
+/// The id of a path stored in a PathTable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PathId(u32);
+
+
+
+struct PathNode {
+	parent:Option<PathId>,
+	name:Arc<str>,
+	is_entry:bool
+}
+
+
+
+/// A compact store for large amounts of paths. Every path is stored as a parent id and a name, and equal names are shared, so paths with long common prefixes like the results of a recursive scan take a fraction of the memory of separate strings.
+#[derive(Default)]
+pub struct PathTable {
+	nodes:Vec<PathNode>,
+	children:HashMap<(Option<PathId>, Arc<str>), PathId>,
+	names:HashSet<Arc<str>>,
+	entries:Vec<PathId>
+}
+impl PathTable {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new empty table.
+	pub fn new() -> PathTable {
+		PathTable::default()
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the amount of paths inserted into the table.
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Check if no paths were inserted into the table.
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Get the amount of nodes stored, including the parent dirs of inserted paths.
+	pub fn node_count(&self) -> usize {
+		self.nodes.len()
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Insert a path into the table, returning its id. Inserting the same path twice returns the same id.
+	pub fn insert(&mut self, file:&FileRef) -> PathId {
+		let nodes:Vec<&str> = file.path_nodes();
+		let mut id:PathId = self.child(None, nodes[0]);
+		for name in &nodes[1..] {
+			id = self.child(Some(id), name);
+		}
+
+		let node:&mut PathNode = &mut self.nodes[id.0 as usize];
+		if !node.is_entry {
+			node.is_entry = true;
+			self.entries.push(id);
+		}
+		id
+	}
+
+	/// Find the id of a path, if it was inserted.
+	pub fn find(&self, file:&FileRef) -> Option<PathId> {
+		let mut parent:Option<PathId> = None;
+		for name in file.path_nodes() {
+			parent = Some(*self.children.get(&(parent, Arc::from(name)))?);
+		}
+		parent.filter(|id| self.nodes[id.0 as usize].is_entry)
+	}
+
+	/// Get the full path of an id.
+	pub fn get(&self, id:PathId) -> FileRef {
+		let mut names:Vec<&str> = Vec::new();
+		let mut current:Option<PathId> = Some(id);
+		while let Some(id) = current {
+			let node:&PathNode = &self.nodes[id.0 as usize];
+			names.push(&node.name);
+			current = node.parent;
+		}
+		names.reverse();
+		FileRef::new(&names.join(SEPARATOR))
+	}
+
+	/// Get the name of the file/dir of an id.
+	pub fn name(&self, id:PathId) -> &str {
+		&self.nodes[id.0 as usize].name
+	}
+
+	/// Get the id of the parent dir of an id.
+	pub fn parent(&self, id:PathId) -> Option<PathId> {
+		self.nodes[id.0 as usize].parent
+	}
+
+	/// Iterate over all inserted paths in the order they were inserted.
+	pub fn iter(&self) -> impl Iterator<Item = FileRef> + '_ {
+		self.entries.iter().map(|id| self.get(*id))
+	}
+
+	/// Get the id of a child of a dir node, adding it if it is new.
+	fn child(&mut self, parent:Option<PathId>, name:&str) -> PathId {
+		let name:Arc<str> = self.intern_name(name);
+		if let Some(id) = self.children.get(&(parent, name.clone())) {
+			return *id;
+		}
+		let id:PathId = PathId(self.nodes.len() as u32);
+		self.nodes.push(PathNode { parent, name: name.clone(), is_entry: false });
+		self.children.insert((parent, name), id);
+		id
+	}
+
+	/// Get the shared copy of a name, adding it if it is new.
+	fn intern_name(&mut self, name:&str) -> Arc<str> {
+		match self.names.get(name) {
+			Some(name) => name.clone(),
+			None => {
+				let name:Arc<str> = Arc::from(name);
+				self.names.insert(name.clone());
+				name
+			}
+		}
+	}
+}
+impl Extend<FileRef> for PathTable {
+	fn extend<T:IntoIterator<Item = FileRef>>(&mut self, iter:T) {
+		for file in iter {
+			self.insert(&file);
+		}
+	}
+}
+impl FromIterator<FileRef> for PathTable {
+	fn from_iter<T:IntoIterator<Item = FileRef>>(iter:T) -> Self {
+		let mut table:PathTable = PathTable::new();
+		table.extend(iter);
+		table
+	}
+}
\ No newline at end of file
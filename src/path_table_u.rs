@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, PathId, PathTable, TempDirRef };
+
+
+
+	#[test]
+	fn test_path_table_insert_and_get() {
+		let mut table:PathTable = PathTable::new();
+		let first:PathId = table.insert(&FileRef::new("/projects/app/src/main.rs"));
+		let second:PathId = table.insert(&FileRef::new("/projects/app/src/lib.rs"));
+		let relative:PathId = table.insert(&FileRef::new("notes.txt"));
+		assert_eq!(table.insert(&FileRef::new("/projects/app/src/main.rs")), first);
+
+		assert_eq!(table.len(), 3);
+		assert_eq!(table.node_count(), 7);
+		assert_eq!(table.get(first).path(), "/projects/app/src/main.rs");
+		assert_eq!(table.get(relative).path(), "notes.txt");
+		assert_eq!(table.name(second), "lib.rs");
+		assert_eq!(table.parent(first), table.parent(second));
+		assert_eq!(table.get(table.parent(first).unwrap()).path(), "/projects/app/src");
+	}
+
+	#[test]
+	fn test_path_table_find() {
+		let table:PathTable = vec![FileRef::new("C:/data/file.txt")].into_iter().collect();
+		assert!(table.find(&FileRef::new("C:/data/file.txt")).is_some());
+
+		// Parent dirs are stored, but were not inserted themselves.
+		assert!(table.find(&FileRef::new("C:/data")).is_none());
+		assert!(table.find(&FileRef::new("C:/other.txt")).is_none());
+	}
+
+	#[test]
+	fn test_path_table_from_scan() {
+		let temp_dir:TempDirRef = TempDirRef::new("path_table_test_").unwrap();
+		for index in 0..10 {
+			(temp_dir.path() + &format!("/dir_{}/file_{index}.txt", index % 2)).write("contents").unwrap();
+		}
+
+		let mut table:PathTable = PathTable::new();
+		table.extend(temp_dir.path().list_files_recurse());
+		assert_eq!(table.len(), 10);
+		let mut paths:Vec<String> = table.iter().map(|file| file.path().to_string()).collect();
+		let mut expected:Vec<String> = temp_dir.path().list_files_recurse().iter().map(|file| file.path().to_string()).collect();
+		paths.sort();
+		expected.sort();
+		assert_eq!(paths, expected);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use std::sync::{ Arc, OnceLock };
+use crate::{ FileRef, FileSystem, MemoryFs, SEPARATOR };
+
+
+
+/// Whether the target has a filesystem of the OS. On `wasm32-unknown-unknown` all std::fs calls fail, so FileRefs use an in-memory filesystem instead. WASI targets use their preopened dirs through std::fs like any other OS.
+pub(crate) const HAS_OS_FILE_SYSTEM:bool = !cfg!(all(target_arch="wasm32", target_os="unknown"));
+
+static FALLBACK_FILE_SYSTEM:OnceLock<Arc<dyn FileSystem>> = OnceLock::new();
+
+
+
+/// Get the filesystem used for paths outside of any mount on targets without a filesystem of the OS. Returns None on all other targets.
+pub(crate) fn fallback_file_system() -> Option<Arc<dyn FileSystem>> {
+	if HAS_OS_FILE_SYSTEM {
+		None
+	} else {
+		Some(FALLBACK_FILE_SYSTEM.get_or_init(MemoryFs::detached_backend).clone())
+	}
+}
+
+/// Get the id of the process, used to keep temporary names of processes apart. WebAssembly has no process ids, so zero is used there.
+pub(crate) fn process_id() -> u32 {
+	if cfg!(target_arch="wasm32") { 0 } else { std::process::id() }
+}
+
+/// Get the nanoseconds since the unix epoch, used to make temporary names unique. Targets without a clock use zero.
+pub(crate) fn unix_nanos() -> u128 {
+	use std::time::{ SystemTime, UNIX_EPOCH };
+
+	if HAS_OS_FILE_SYSTEM { SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_nanos()).unwrap_or_default() } else { 0 }
+}
+
+/// Get the temp dir of the OS. WebAssembly has no temp dir of its own, so the dir in the TMPDIR variable or '/tmp' is used, which a WASI host has to preopen.
+pub(crate) fn os_temp_dir() -> FileRef {
+	if cfg!(target_arch="wasm32") {
+		FileRef::new(&std::env::var("TMPDIR").unwrap_or_else(|_| format!("{SEPARATOR}tmp")))
+	} else {
+		FileRef::new(&std::env::temp_dir().display().to_string())
+	}
+}
+
+/// Get the current working dir of the process. Targets without a filesystem of the OS use the root of the in-memory filesystem.
+pub(crate) fn working_dir() -> std::io::Result<String> {
+	if HAS_OS_FILE_SYSTEM {
+		std::env::current_dir().map(|dir| dir.display().to_string())
+	} else {
+		Ok(SEPARATOR.to_owned())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+	use crate::{ FileRef, FileSystem, MemoryFs, platform::{ fallback_file_system, os_temp_dir, process_id, working_dir } };
+
+
+
+	#[test]
+	fn test_native_platform() {
+		assert!(fallback_file_system().is_none());
+		assert_eq!(process_id(), std::process::id());
+		assert_eq!(os_temp_dir(), FileRef::new(&std::env::temp_dir().display().to_string()));
+		assert_eq!(FileRef::new(&working_dir().unwrap()), FileRef::working_dir());
+	}
+
+	#[test]
+	fn test_detached_backend() {
+		let file_system:Arc<dyn FileSystem> = MemoryFs::detached_backend();
+		file_system.create("/data", true).unwrap();
+		file_system.create("/data/file.txt", false).unwrap();
+		file_system.write_at("/data/file.txt", None, b"contents").unwrap();
+		assert_eq!(file_system.read("/data/file.txt").unwrap(), b"contents");
+		assert_eq!(file_system.list("/").unwrap(), vec!["/data"]);
+		assert_eq!(file_system.list("/data").unwrap(), vec!["/data/file.txt"]);
+		assert!(file_system.create("/missing/file.txt", false).is_err());
+	}
+}
\ No newline at end of file
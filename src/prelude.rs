@@ -0,0 +1,8 @@
+pub use crate::{
+	Access, CancellationToken, CompressionLevel, ConflictPolicy, CopyOptions, DirRef, FileComparison, FileOperation, FileRef, FileRefError, FileScanner, FileSystem, GlobPattern, IntoFileRef, LineEnding, NoProgress, OverwritePolicy, Progress,
+	RetryPolicy, SyncOptions, TempDirRef, TempFileRef, glob
+};
+#[cfg(feature="hashing")]
+pub use crate::HashAlgorithm;
+#[cfg(any(feature="json", feature="toml", feature="yaml"))]
+pub use crate::{ ConfigFile, ConfigFormat, TypedFile };
\ No newline at end of file
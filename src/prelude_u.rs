@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod tests {
+	use crate::prelude::*;
+
+
+
+	#[test]
+	fn test_prelude_covers_common_usage() {
+		let temp_dir:TempDirRef = TempDirRef::new("prelude_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/file.txt";
+		file.write("contents").unwrap();
+
+		let found:Vec<FileRef> = FileScanner::new(&temp_dir.path()).include_files().collect();
+		assert_eq!(found, vec![file.clone()]);
+		assert_eq!(glob(&format!("{}/*.txt", temp_dir.path())).unwrap().count(), 1);
+		assert!(GlobPattern::new("*.txt").unwrap().matches(file.name()));
+		assert_eq!(file.copy_to_with_options(&(temp_dir.path() + "/copy.txt"), CopyOptions::new().overwrite(OverwritePolicy::Skip)).unwrap(), 8);
+		#[cfg(feature="hashing")]
+		assert_eq!(file.hash(HashAlgorithm::Crc32).unwrap().len(), 8);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,138 @@
+use std::sync::mpsc::{ Receiver, Sender, channel };
+#[cfg(feature="hashing")]
+use std::io::{ self, Read };
+use crate::FileRef;
+
+
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgressUpdate {
+	pub bytes_done:u64,
+	pub bytes_total:u64,
+	pub current_file:Option<FileRef>
+}
+impl ProgressUpdate {
+
+	/// Get the fraction of the operation that is done, between 0 and 1. Operations without any bytes to process are considered done.
+	pub fn fraction(&self) -> f64 {
+		if self.bytes_total == 0 { 1.0 } else { (self.bytes_done as f64 / self.bytes_total as f64).min(1.0) }
+	}
+}
+
+
+
+/// Receives updates from long running operations like hashing, syncing and archiving.
+pub trait Progress:Send + Sync {
+
+	/// Handle an update of the operation.
+	fn update(&self, update:&ProgressUpdate);
+
+	/// Check if the operation should stop. Operations check this between files or chunks of data.
+	fn is_cancelled(&self) -> bool {
+		false
+	}
+}
+impl<T> Progress for T where T:Fn(&ProgressUpdate) + Send + Sync {
+	fn update(&self, update:&ProgressUpdate) {
+		self(update)
+	}
+}
+
+
+
+/// Ignores all updates.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoProgress;
+impl Progress for NoProgress {
+	fn update(&self, _update:&ProgressUpdate) {}
+}
+
+
+
+/// Sends all updates to a channel, so they can be displayed by another thread.
+#[derive(Clone, Debug)]
+pub struct ChannelProgress {
+	sender:Sender<ProgressUpdate>
+}
+impl ChannelProgress {
+
+	/// Create a new channel reporter and the receiver of its updates.
+	pub fn new() -> (ChannelProgress, Receiver<ProgressUpdate>) {
+		let (sender, receiver):(Sender<ProgressUpdate>, Receiver<ProgressUpdate>) = channel();
+		(ChannelProgress { sender }, receiver)
+	}
+}
+impl Progress for ChannelProgress {
+	fn update(&self, update:&ProgressUpdate) {
+		let _ = self.sender.send(update.clone());
+	}
+}
+
+
+
+/// Keeps track of the progress of an operation and reports every change.
+pub(crate) struct ProgressTracker<'a> {
+	progress:&'a dyn Progress,
+	state:ProgressUpdate
+}
+impl<'a> ProgressTracker<'a> {
+
+	/// Create a new tracker for an operation processing the given amount of bytes.
+	pub(crate) fn new(progress:&'a dyn Progress, bytes_total:u64) -> ProgressTracker<'a> {
+		let tracker:ProgressTracker = ProgressTracker { progress, state: ProgressUpdate { bytes_done: 0, bytes_total, current_file: None } };
+		tracker.progress.update(&tracker.state);
+		tracker
+	}
+
+	/// Report that the operation started processing a file.
+	pub(crate) fn start_file(&mut self, file:&FileRef) {
+		self.state.current_file = Some(file.clone());
+		self.progress.update(&self.state);
+	}
+
+	/// Report that the given amount of bytes was processed.
+	pub(crate) fn advance(&mut self, bytes:u64) {
+		if bytes > 0 {
+			self.state.bytes_done += bytes;
+			self.progress.update(&self.state);
+		}
+	}
+
+	/// Wrap a reader so all bytes read from it are reported as processed.
+	#[cfg(feature="hashing")]
+	pub(crate) fn reader<'b, T:Read>(&'b mut self, inner:T) -> ProgressReader<'a, 'b, T> {
+		ProgressReader { inner, tracker: self }
+	}
+
+	/// Check if the operation should stop.
+	pub(crate) fn is_cancelled(&self) -> bool {
+		self.progress.is_cancelled()
+	}
+
+	/// Report that the operation finished.
+	pub(crate) fn finish(&mut self) {
+		self.state.current_file = None;
+		self.state.bytes_done = self.state.bytes_done.max(self.state.bytes_total);
+		self.progress.update(&self.state);
+	}
+}
+
+
+
+/// Reports all bytes read through it to a tracker.
+#[cfg(feature="hashing")]
+pub(crate) struct ProgressReader<'a, 'b, T:Read> {
+	inner:T,
+	tracker:&'b mut ProgressTracker<'a>
+}
+#[cfg(feature="hashing")]
+impl<T:Read> Read for ProgressReader<'_, '_, T> {
+	fn read(&mut self, buffer:&mut [u8]) -> io::Result<usize> {
+		if self.tracker.is_cancelled() {
+			return Err(io::Error::other("Operation was cancelled."));
+		}
+		let bytes_read:usize = self.inner.read(buffer)?;
+		self.tracker.advance(bytes_read as u64);
+		Ok(bytes_read)
+	}
+}
\ No newline at end of file
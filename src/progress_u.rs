@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+	use std::sync::Mutex;
+	#[cfg(any(feature="hashing", feature="zip"))]
+	use std::sync::mpsc::Receiver;
+	use crate::{ FileRef, ProgressUpdate, SyncOptions, TempDirRef };
+	#[cfg(any(feature="hashing", feature="zip"))]
+	use crate::ChannelProgress;
+
+
+
+	#[test]
+	fn test_progress_fraction() {
+		assert_eq!(ProgressUpdate { bytes_done: 25, bytes_total: 100, current_file: None }.fraction(), 0.25);
+		assert_eq!(ProgressUpdate::default().fraction(), 1.0);
+	}
+
+	#[test]
+	#[cfg(feature="hashing")]
+	fn test_channel_progress_hash() {
+		use crate::HashAlgorithm;
+
+		let temp_dir:TempDirRef = TempDirRef::new("progress_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/data.bin";
+		file.write_bytes(&vec![7; 200_000]).unwrap();
+
+		let (progress, receiver):(ChannelProgress, Receiver<ProgressUpdate>) = ChannelProgress::new();
+		assert_eq!(file.hash_with_progress(HashAlgorithm::Sha256, &progress).unwrap(), file.hash(HashAlgorithm::Sha256).unwrap());
+		let updates:Vec<ProgressUpdate> = receiver.try_iter().collect();
+		assert!(updates.len() > 2);
+		assert!(updates.iter().any(|update| update.current_file.as_ref() == Some(&file)));
+		assert!(updates.windows(2).all(|pair| pair[0].bytes_done <= pair[1].bytes_done));
+		assert_eq!(updates.last().unwrap(), &ProgressUpdate { bytes_done: 200_000, bytes_total: 200_000, current_file: None });
+	}
+
+	#[test]
+	fn test_callback_progress_sync() {
+		let temp_dir:TempDirRef = TempDirRef::new("progress_test_").unwrap();
+		let source_dir:FileRef = temp_dir.path() + "/source";
+		(source_dir.clone() + "/a.txt").write("12345").unwrap();
+		(source_dir.clone() + "/sub/b.txt").write("1234567890").unwrap();
+
+		let files:Mutex<Vec<FileRef>> = Mutex::new(Vec::new());
+		let totals:Mutex<Vec<u64>> = Mutex::new(Vec::new());
+		source_dir.sync_to_with_progress(&(temp_dir.path() + "/target"), SyncOptions::new(), &|update:&ProgressUpdate| {
+			files.lock().unwrap().extend(update.current_file.clone());
+			totals.lock().unwrap().push(update.bytes_done);
+		}).unwrap();
+		assert!(files.lock().unwrap().iter().any(|file| file.name() == "b.txt"));
+		assert_eq!(totals.lock().unwrap().last(), Some(&15));
+	}
+
+	#[test]
+	#[cfg(feature="zip")]
+	fn test_channel_progress_zip() {
+		use crate::CompressionLevel;
+
+		let temp_dir:TempDirRef = TempDirRef::new("progress_test_").unwrap();
+		let source_dir:FileRef = temp_dir.path() + "/source";
+		(source_dir.clone() + "/a.txt").write("12345").unwrap();
+
+		let (progress, receiver):(ChannelProgress, Receiver<ProgressUpdate>) = ChannelProgress::new();
+		source_dir.zip_dir_to_with_progress(&(temp_dir.path() + "/archive.zip"), CompressionLevel::Default, &progress).unwrap();
+		assert_eq!(receiver.try_iter().last().map(|update| update.bytes_done), Some(5));
+	}
+}
\ No newline at end of file
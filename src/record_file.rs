@@ -0,0 +1,117 @@
+use std::{ error::Error, fs::File, io::{ self, BufReader, Cursor, ErrorKind, Read, Seek } };
+use crate::{ FileOperation, FileRef };
+
+
+
+const RECORD_LENGTH_SIZE:u64 = 4;
+
+
+
+/// An append-only file of length-prefixed records. Keeps the offset of every record in memory, so any record can be read by its number without scanning the file.
+pub struct RecordFile {
+	file:FileRef,
+	offsets:Vec<u64>,
+	length:u64
+}
+impl RecordFile {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Open the record file, creating it if it does not exist, and build the offset index. An incomplete record at the end of the file is cut off.
+	pub fn open(file:&FileRef) -> Result<RecordFile, Box<dyn Error>> {
+		if file.is_dir() {
+			return Err(file.error(FileOperation::Write, "Only able to store records in files."));
+		}
+		file.guarantee_exists()?;
+
+		// Index the records through a single reader.
+		let size:u64 = file.file_system().size(file.path()).map_err(|error| file.error(FileOperation::Read, error))?;
+		let (offsets, offset):(Vec<u64>, u64) = match file.mounted_file_system() {
+			Some(file_system) => index_records(Cursor::new(file_system.read(file.path()).map_err(|error| file.error(FileOperation::Read, error))?), size),
+			None => index_records(BufReader::new(File::open(file.os_path()).map_err(|error| file.error(FileOperation::Read, error))?), size)
+		}.map_err(|error| file.error(FileOperation::Read, error))?;
+
+		// Cut off incomplete record.
+		if offset < size {
+			file.file_system().truncate(file.path(), offset).map_err(|error| file.error(FileOperation::Write, error))?;
+		}
+		Ok(RecordFile { file: file.clone(), offsets, length: offset })
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the file the records are stored in.
+	pub fn file(&self) -> &FileRef {
+		&self.file
+	}
+
+	/// Get the amount of records in the file.
+	pub fn len(&self) -> usize {
+		self.offsets.len()
+	}
+
+	/// Check if the file contains no records.
+	pub fn is_empty(&self) -> bool {
+		self.offsets.is_empty()
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Append a record to the file. If the write fails, the partially written record is removed again. Returns the number of the record.
+	pub fn append(&mut self, data:&[u8]) -> Result<usize, Box<dyn Error>> {
+		let length:u32 = u32::try_from(data.len()).map_err(|_| self.file.error(FileOperation::Append, "Record is larger than 4GiB."))?;
+		let mut record:Vec<u8> = Vec::with_capacity(RECORD_LENGTH_SIZE as usize + data.len());
+		record.extend_from_slice(&length.to_le_bytes());
+		record.extend_from_slice(data);
+		if let Err(error) = self.file.append_bytes(&record) {
+
+			// Remove the partially written record, so the offsets stay in line with the file.
+			let _ = self.file.file_system().truncate(self.file.path(), self.length);
+			return Err(error);
+		}
+
+		self.offsets.push(self.length);
+		self.length += record.len() as u64;
+		Ok(self.offsets.len() - 1)
+	}
+
+	/// Read the record with the given number.
+	pub fn get(&self, index:usize) -> Result<Vec<u8>, Box<dyn Error>> {
+		let start:u64 = *self.offsets.get(index).ok_or_else(|| self.file.error(FileOperation::Read, format!("Record {index} does not exist.")))?;
+		let end:u64 = self.offsets.get(index + 1).copied().unwrap_or(self.length);
+		self.file.read_range(start + RECORD_LENGTH_SIZE, end)
+	}
+
+	/// Iterate over all records in the file, in the order they were appended.
+	pub fn iter(&self) -> impl Iterator<Item = Result<Vec<u8>, Box<dyn Error>>> + '_ {
+		(0..self.len()).map(|index| self.get(index))
+	}
+}
+
+
+
+/// Find the offsets of all complete records in a reader of the given size. Returns the offsets and the end of the last complete record.
+fn index_records<T:Read + Seek>(mut reader:T, size:u64) -> io::Result<(Vec<u64>, u64)> {
+	let mut offsets:Vec<u64> = Vec::new();
+	let mut offset:u64 = 0;
+	let mut header:[u8; RECORD_LENGTH_SIZE as usize] = [0; RECORD_LENGTH_SIZE as usize];
+	while offset + RECORD_LENGTH_SIZE <= size {
+		match reader.read_exact(&mut header) {
+			Err(error) if error.kind() == ErrorKind::UnexpectedEof => break,
+			result => result?
+		}
+		let length:u64 = u32::from_le_bytes(header) as u64;
+		let record_end:u64 = offset + RECORD_LENGTH_SIZE + length;
+		if record_end > size {
+			break;
+		}
+		reader.seek_relative(length as i64)?;
+		offsets.push(offset);
+		offset = record_end;
+	}
+	Ok((offsets, offset))
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, MemoryFs, RecordFile, TempDirRef };
+
+
+
+	#[test]
+	fn test_record_append_and_get() {
+		let temp_dir:TempDirRef = TempDirRef::new("record_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/events.rec";
+
+		let mut records:RecordFile = RecordFile::open(&file).unwrap();
+		assert_eq!(records.append(b"started").unwrap(), 0);
+		assert_eq!(records.append(b"").unwrap(), 1);
+		assert_eq!(records.append(b"stopped").unwrap(), 2);
+		assert_eq!(records.get(2).unwrap(), b"stopped");
+		assert_eq!(records.get(1).unwrap(), b"");
+		assert!(records.get(3).is_err());
+		drop(records);
+
+		let records:RecordFile = RecordFile::open(&file).unwrap();
+		assert_eq!(records.len(), 3);
+		assert_eq!(records.iter().map(|record| record.unwrap()).collect::<Vec<Vec<u8>>>(), vec![b"started".to_vec(), Vec::new(), b"stopped".to_vec()]);
+	}
+
+	#[test]
+	fn test_record_cuts_off_incomplete_record() {
+		let temp_dir:TempDirRef = TempDirRef::new("record_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/events.rec";
+
+		let mut records:RecordFile = RecordFile::open(&file).unwrap();
+		records.append(b"complete").unwrap();
+		records.append(b"incomplete").unwrap();
+		let contents:Vec<u8> = file.read_bytes().unwrap();
+		file.write_bytes(&contents[..contents.len() - 3]).unwrap();
+
+		let mut records:RecordFile = RecordFile::open(&file).unwrap();
+		assert_eq!(records.len(), 1);
+		assert_eq!(records.append(b"next").unwrap(), 1);
+		assert_eq!(records.get(1).unwrap(), b"next");
+	}
+
+	#[test]
+	fn test_record_on_memory_fs() {
+		let memory_fs:MemoryFs = MemoryFs::new();
+		let file:FileRef = memory_fs.file_ref("events.rec");
+
+		let mut records:RecordFile = RecordFile::open(&file).unwrap();
+		records.append(b"in memory").unwrap();
+		assert_eq!(RecordFile::open(&file).unwrap().get(0).unwrap(), b"in memory");
+
+		file.append_bytes(&[9, 0]).unwrap();
+		assert_eq!(RecordFile::open(&file).unwrap().len(), 1);
+		assert_eq!(file.read_bytes().unwrap().len(), 13);
+	}
+}
\ No newline at end of file
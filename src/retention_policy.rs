@@ -0,0 +1,168 @@
+use std::{ collections::BTreeSet, error::Error, time::{ SystemTime, UNIX_EPOCH } };
+use crate::{ FileOperation, FileRef, file_ref_timestamped::civil_from_days };
+
+
+
+const SECONDS_PER_DAY:u64 = 24 * 60 * 60;
+
+
+
+pub type TimestampSource = Box<dyn Fn(&FileRef) -> Option<SystemTime>>;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetentionRule {
+	Last,
+	Daily,
+	Weekly,
+	Monthly,
+	Yearly
+}
+impl RetentionRule {
+
+	/// Get the period the timestamp falls in for this rule. Periods are calculated in UTC, weeks start on monday.
+	fn period(&self, timestamp:SystemTime) -> i64 {
+		let days:i64 = (timestamp.duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or(0) / SECONDS_PER_DAY) as i64;
+		match self {
+			RetentionRule::Last => 0,
+			RetentionRule::Daily => days,
+			RetentionRule::Weekly => (days + 3).div_euclid(7),
+			RetentionRule::Monthly => { let (year, month, _):(i64, u32, u32) = civil_from_days(days); year * 12 + month as i64 },
+			RetentionRule::Yearly => civil_from_days(days).0
+		}
+	}
+}
+
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetentionDecision {
+	pub file:FileRef,
+	pub timestamp:SystemTime,
+	pub kept_by:Option<RetentionRule>
+}
+impl RetentionDecision {
+
+	/// Check if the file should be kept.
+	pub fn keep(&self) -> bool {
+		self.kept_by.is_some()
+	}
+}
+
+
+
+/// Decides which timestamped files in a dir to keep, like backups. Every rule keeps the newest file in each of the most recent periods it covers, so `keep_daily(7)` keeps the newest file of each of the last 7 days that have files. Files not kept by any rule are deleted when the policy is applied.
+pub struct RetentionPolicy {
+	rules:Vec<(RetentionRule, usize)>,
+	timestamp_source:TimestampSource
+}
+impl Default for RetentionPolicy {
+	fn default() -> RetentionPolicy {
+		RetentionPolicy {
+			rules: Vec::new(),
+			timestamp_source: Box::new(|file| file.modified().ok())
+		}
+	}
+}
+impl RetentionPolicy {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new policy that keeps nothing. Use the builder methods to add rules.
+	pub fn new() -> RetentionPolicy {
+		RetentionPolicy::default()
+	}
+
+	/// Return self keeping the given amount of newest files.
+	pub fn keep_last(self, count:usize) -> Self {
+		self.with_rule(RetentionRule::Last, count)
+	}
+
+	/// Return self keeping the newest file of each of the given amount of most recent days.
+	pub fn keep_daily(self, count:usize) -> Self {
+		self.with_rule(RetentionRule::Daily, count)
+	}
+
+	/// Return self keeping the newest file of each of the given amount of most recent weeks.
+	pub fn keep_weekly(self, count:usize) -> Self {
+		self.with_rule(RetentionRule::Weekly, count)
+	}
+
+	/// Return self keeping the newest file of each of the given amount of most recent months.
+	pub fn keep_monthly(self, count:usize) -> Self {
+		self.with_rule(RetentionRule::Monthly, count)
+	}
+
+	/// Return self keeping the newest file of each of the given amount of most recent years.
+	pub fn keep_yearly(self, count:usize) -> Self {
+		self.with_rule(RetentionRule::Yearly, count)
+	}
+
+	/// Return self with a custom source for the timestamp of files, like a date in the file name. Files without a timestamp are always kept. Uses the modification time by default.
+	pub fn timestamp<T>(mut self, source:T) -> Self where T:Fn(&FileRef) -> Option<SystemTime> + 'static {
+		self.timestamp_source = Box::new(source);
+		self
+	}
+
+	/// Return self with a rule added, replacing any earlier count for the same rule.
+	fn with_rule(mut self, rule:RetentionRule, count:usize) -> Self {
+		self.rules.retain(|(existing_rule, _)| *existing_rule != rule);
+		self.rules.push((rule, count));
+		self
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Decide which of the given files to keep. Decisions are ordered from newest to oldest file.
+	pub fn evaluate_files(&self, files:Vec<(FileRef, SystemTime)>) -> Vec<RetentionDecision> {
+		let mut decisions:Vec<RetentionDecision> = files.into_iter().map(|(file, timestamp)| RetentionDecision { file, timestamp, kept_by: None }).collect();
+		decisions.sort_by(|left, right| right.timestamp.cmp(&left.timestamp).then_with(|| left.file.cmp(&right.file)));
+		for (rule, count) in &self.rules {
+			let mut periods:BTreeSet<i64> = BTreeSet::new();
+			let mut kept:usize = 0;
+			for decision in &mut decisions {
+				if kept >= *count {
+					break;
+				}
+				let period:i64 = if *rule == RetentionRule::Last { kept as i64 } else { rule.period(decision.timestamp) };
+				if periods.insert(period) {
+					kept += 1;
+					decision.kept_by.get_or_insert(*rule);
+				}
+			}
+		}
+		decisions
+	}
+
+	/// Decide which files directly in the given dir to keep. Files without a timestamp are left out, so they are always kept.
+	pub fn evaluate(&self, dir:&FileRef) -> Result<Vec<RetentionDecision>, Box<dyn Error>> {
+		dir.require_std_file_system(FileOperation::Scan)?;
+		let mut files:Vec<(FileRef, SystemTime)> = Vec::new();
+		for entry in std::fs::read_dir(dir.os_path()).map_err(|error| dir.io_failure(FileOperation::Scan, Box::new(error)))? {
+			let entry:std::fs::DirEntry = entry.map_err(|error| dir.error(FileOperation::Scan, error))?;
+			if entry.file_type().is_ok_and(|file_type| file_type.is_file()) {
+				let file:FileRef = FileRef::new(&entry.path().to_string_lossy());
+				if let Some(timestamp) = (self.timestamp_source)(&file) {
+					files.push((file, timestamp));
+				}
+			}
+		}
+		Ok(self.evaluate_files(files))
+	}
+
+	/// Decide which files directly in the given dir to keep and delete all others. Returns all decisions. Fails without deleting anything if no rule is configured, as that would delete every file.
+	pub fn apply(&self, dir:&FileRef) -> Result<Vec<RetentionDecision>, Box<dyn Error>> {
+		if self.rules.iter().all(|(_, count)| *count == 0) {
+			return Err(dir.error(FileOperation::Delete, "Retention policy has no rules, refusing to delete all files."));
+		}
+		dir.require_std_file_system(FileOperation::Delete)?;
+		let decisions:Vec<RetentionDecision> = self.evaluate(dir)?;
+		for decision in decisions.iter().filter(|decision| !decision.keep()) {
+			std::fs::remove_file(decision.file.os_path()).map_err(|error| decision.file.error(FileOperation::Delete, error))?;
+		}
+		Ok(decisions)
+	}
+}
\ No newline at end of file
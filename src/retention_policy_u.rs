@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+	use std::{ fs::File, time::{ Duration, SystemTime, UNIX_EPOCH } };
+	use crate::{ FileRef, RetentionDecision, RetentionPolicy, RetentionRule, TempDirRef };
+
+
+
+	fn at_day(day:u64, hour:u64) -> SystemTime {
+		UNIX_EPOCH + Duration::from_secs(day * 24 * 60 * 60 + hour * 60 * 60)
+	}
+
+	fn kept(decisions:&[RetentionDecision]) -> Vec<&str> {
+		decisions.iter().filter(|decision| decision.keep()).map(|decision| decision.file.name()).collect()
+	}
+
+
+
+	#[test]
+	fn test_retention_daily() {
+		let mut files:Vec<(FileRef, SystemTime)> = (19723..19733).map(|day| (FileRef::new(&format!("backup_{day}.tar")), at_day(day, 12))).collect();
+		files.push((FileRef::new("backup_19732_early.tar"), at_day(19732, 6)));
+
+		let decisions:Vec<RetentionDecision> = RetentionPolicy::new().keep_daily(3).evaluate_files(files);
+		assert_eq!(kept(&decisions), vec!["backup_19732.tar", "backup_19731.tar", "backup_19730.tar"]);
+		assert_eq!(decisions.len(), 11);
+	}
+
+	#[test]
+	fn test_retention_combined_rules() {
+		// 2023-12-31 (sunday), 2024-01-01 (monday), 2024-02-29 and 2024-03-01.
+		let files:Vec<(FileRef, SystemTime)> = [19722, 19723, 19782, 19783].iter().map(|day| (FileRef::new(&format!("{day}.bak")), at_day(*day, 0))).collect();
+
+		let decisions:Vec<RetentionDecision> = RetentionPolicy::new().keep_last(2).keep_monthly(3).evaluate_files(files.clone());
+		assert_eq!(kept(&decisions), vec!["19783.bak", "19782.bak", "19723.bak"]);
+		assert_eq!(decisions[0].kept_by, Some(RetentionRule::Last));
+		assert_eq!(decisions[2].kept_by, Some(RetentionRule::Monthly));
+
+		let decisions:Vec<RetentionDecision> = RetentionPolicy::new().keep_weekly(2).evaluate_files(files.clone());
+		assert_eq!(kept(&decisions), vec!["19783.bak", "19723.bak"]);
+
+		let decisions:Vec<RetentionDecision> = RetentionPolicy::new().keep_yearly(5).evaluate_files(files);
+		assert_eq!(kept(&decisions), vec!["19783.bak", "19722.bak"]);
+	}
+
+	#[test]
+	fn test_retention_apply() {
+		let temp_dir:TempDirRef = TempDirRef::new("retention_test_").unwrap();
+		for age_in_days in 0..3 {
+			let file:FileRef = temp_dir.path() + &format!("/backup_{age_in_days}.tar");
+			file.write("backup").unwrap();
+			File::options().write(true).open(file.path()).unwrap().set_modified(SystemTime::now() - Duration::from_secs(age_in_days * 24 * 60 * 60)).unwrap();
+		}
+		(temp_dir.path() + "/notes.txt").write("notes").unwrap();
+
+		let policy:RetentionPolicy = RetentionPolicy::new().keep_last(1).timestamp(|file| if file.extension() == Some("tar") { std::fs::metadata(file.path()).and_then(|metadata| metadata.modified()).ok() } else { None });
+		let decisions:Vec<RetentionDecision> = policy.apply(&temp_dir.path()).unwrap();
+		assert_eq!(kept(&decisions), vec!["backup_0.tar"]);
+		assert!((temp_dir.path() + "/backup_0.tar").exists());
+		assert!(!(temp_dir.path() + "/backup_1.tar").exists());
+		assert!(!(temp_dir.path() + "/backup_2.tar").exists());
+		assert!((temp_dir.path() + "/notes.txt").exists());
+	}
+	#[test]
+	fn test_retention_apply_without_rules() {
+		let temp_dir:TempDirRef = TempDirRef::new("retention_test_").unwrap();
+		(temp_dir.path() + "/backup.tar").write("backup").unwrap();
+
+		assert!(RetentionPolicy::new().apply(&temp_dir.path()).is_err());
+		assert!(RetentionPolicy::new().keep_daily(0).apply(&temp_dir.path()).is_err());
+		assert!((temp_dir.path() + "/backup.tar").exists());
+	}
+}
\ No newline at end of file
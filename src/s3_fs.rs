@@ -0,0 +1,271 @@
+use std::{ error::Error, io::Read, sync::Arc, time::{ Duration, SystemTime, UNIX_EPOCH } };
+use hmac::{ Hmac, Mac };
+use quick_xml::{ Reader, events::Event };
+use sha2::Sha256;
+use ureq::{ Agent, AgentBuilder, Request, Response };
+use crate::{ FileOperation, FileRef, FileSystem, HashAlgorithm, SEPARATOR, content_length, file_ref_timestamped::civil_from_days, mount_file_system, percent_encode, read_only_error };
+
+
+
+const DEFAULT_S3_REGION:&str = "us-east-1";
+const S3_SERVICE_NAME:&str = "s3";
+const S3_SIGNING_ALGORITHM:&str = "AWS4-HMAC-SHA256";
+const EMPTY_PAYLOAD_HASH:&str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+const S3_KEY_SEPARATOR:&str = "/";
+const DEFAULT_CONNECT_TIMEOUT:Duration = Duration::from_secs(10);
+const DEFAULT_READ_TIMEOUT:Duration = Duration::from_secs(30);
+
+
+
+pub struct S3FileSystem {
+	root:String,
+	endpoint:String,
+	bucket:String,
+	region:String,
+	signer:Option<S3Signer>,
+	agent:Agent
+}
+impl S3FileSystem {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new read-only filesystem on a bucket of an S3-compatible object storage, like 'https://s3.eu-west-1.amazonaws.com' or 'http://localhost:9000'. A FileRef like 'root/dir/file.txt' maps to the key 'dir/file.txt'. Without credentials, requests are sent anonymously. Connecting times out after 10 seconds and reading after 30 seconds. Call `mount` to bind it to the root.
+	pub fn new(root:&str, endpoint:&str, bucket:&str) -> S3FileSystem {
+		S3FileSystem {
+			root: FileRef::new(root).path().trim_end_matches(SEPARATOR).to_owned(),
+			endpoint: endpoint.trim_end_matches('/').to_owned(),
+			bucket: bucket.to_owned(),
+			region: DEFAULT_S3_REGION.to_owned(),
+			signer: None,
+			agent: AgentBuilder::new().timeout_connect(DEFAULT_CONNECT_TIMEOUT).timeout_read(DEFAULT_READ_TIMEOUT).build()
+		}
+	}
+
+	/// Return self with the time to wait for a connection and for data from the server before a request fails.
+	pub fn timeouts(mut self, connect:Duration, read:Duration) -> Self {
+		self.agent = AgentBuilder::new().timeout_connect(connect).timeout_read(read).build();
+		self
+	}
+
+	/// Return self with a region. Defaults to 'us-east-1'.
+	pub fn region(mut self, region:&str) -> Self {
+		self.region = region.to_owned();
+		if let Some(signer) = &mut self.signer {
+			signer.region = region.to_owned();
+		}
+		self
+	}
+
+	/// Return self with credentials to sign requests with.
+	pub fn credentials(mut self, access_key_id:&str, secret_access_key:&str) -> Self {
+		self.signer = Some(S3Signer { access_key_id: access_key_id.to_owned(), secret_access_key: secret_access_key.to_owned(), region: self.region.clone() });
+		self
+	}
+
+	/// Mount the filesystem at its root. Returns the root dir.
+	pub fn mount(self) -> FileRef {
+		let root:FileRef = FileRef::new(&self.root);
+		mount_file_system(&root, Arc::new(self));
+		root
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Get the object key of a path in the filesystem.
+	fn key(&self, path:&str) -> String {
+		path.strip_prefix(&self.root).unwrap_or(path).trim_matches('/').to_owned()
+	}
+
+	/// Get the path in the filesystem of an object key.
+	fn path(&self, key:&str) -> String {
+		self.root.clone() + SEPARATOR + key.trim_end_matches(S3_KEY_SEPARATOR)
+	}
+
+	/// Send a request for an object, or for the bucket if the key is empty.
+	fn request(&self, method:&str, key:&str, query:&[(&str, &str)], range:Option<(u64, u64)>) -> Result<Response, Box<dyn Error>> {
+		let canonical_uri:String = percent_encode(&format!("/{}/{key}", self.bucket), true);
+		let canonical_query:String = canonical_query(query);
+		let url:String = if canonical_query.is_empty() { self.endpoint.clone() + &canonical_uri } else { format!("{}{canonical_uri}?{canonical_query}", self.endpoint) };
+		let mut request:Request = self.agent.request(method, &url);
+		if let Some((start, end)) = range {
+			request = request.set("Range", &format!("bytes={start}-{}", end - 1));
+		}
+
+		// Sign request.
+		if let Some(signer) = &self.signer {
+			let host:&str = self.endpoint.split_once("://").map(|(_, host)| host).unwrap_or(&self.endpoint);
+			let amz_date:String = amz_date(SystemTime::now());
+			let headers:[(&str, &str); 3] = [("host", host), ("x-amz-content-sha256", EMPTY_PAYLOAD_HASH), ("x-amz-date", &amz_date)];
+			let authorization:String = signer.authorization(method, &canonical_uri, &canonical_query, &headers, &amz_date);
+			request = request.set("x-amz-content-sha256", EMPTY_PAYLOAD_HASH).set("x-amz-date", &amz_date).set("Authorization", &authorization);
+		}
+		request.call().map_err(|error| FileRef::new(&url).error(FileOperation::Read, error))
+	}
+
+	/// List the keys of objects and common prefixes directly below a prefix.
+	fn list_keys(&self, prefix:&str, max_keys:Option<usize>) -> Result<Vec<String>, Box<dyn Error>> {
+		let max_keys:String = max_keys.map(|max_keys| max_keys.to_string()).unwrap_or_default();
+		let mut keys:Vec<String> = Vec::new();
+		let mut continuation_token:Option<String> = None;
+		loop {
+			let mut query:Vec<(&str, &str)> = vec![("list-type", "2"), ("delimiter", S3_KEY_SEPARATOR), ("prefix", prefix)];
+			if !max_keys.is_empty() {
+				query.push(("max-keys", &max_keys));
+			}
+			if let Some(token) = &continuation_token {
+				query.push(("continuation-token", token));
+			}
+			let mut body:String = String::new();
+			self.request("GET", "", &query, None)?.into_reader().read_to_string(&mut body)?;
+
+			// Parse listing. Placeholder objects of the dir itself are skipped. Limited listings only fetch the first page.
+			let (page_keys, next_token):(Vec<String>, Option<String>) = parse_list_objects(&body).map_err(|error| FileRef::new(prefix).error(FileOperation::Scan, error))?;
+			keys.extend(page_keys.into_iter().filter(|key| key != prefix));
+			match next_token {
+				Some(token) if max_keys.is_empty() => continuation_token = Some(token),
+				_ => return Ok(keys)
+			}
+		}
+	}
+}
+impl FileSystem for S3FileSystem {
+	fn exists(&self, path:&str) -> bool {
+		let key:String = self.key(path);
+		key.is_empty() || self.request("HEAD", &key, &[], None).is_ok() || self.list_keys(&(key + S3_KEY_SEPARATOR), Some(1)).map(|keys| !keys.is_empty()).unwrap_or(false)
+	}
+
+	fn is_accessible(&self, path:&str) -> bool {
+		self.exists(path)
+	}
+
+	fn create(&self, path:&str, _is_dir:bool) -> Result<(), Box<dyn Error>> {
+		Err(read_only_error(path))
+	}
+
+	fn read(&self, path:&str) -> Result<Vec<u8>, Box<dyn Error>> {
+		let mut body:Vec<u8> = Vec::new();
+		self.request("GET", &self.key(path), &[], None)?.into_reader().read_to_end(&mut body)?;
+		Ok(body)
+	}
+
+	fn size(&self, path:&str) -> Result<u64, Box<dyn Error>> {
+		let response:Response = self.request("HEAD", &self.key(path), &[], None)?;
+		content_length(&response).ok_or_else(|| FileRef::new(path).error(FileOperation::Read, "Server did not send a content length."))
+	}
+
+	fn read_range(&self, path:&str, start:u64, end:u64) -> Result<Vec<u8>, Box<dyn Error>> {
+		if end <= start {
+			return Ok(Vec::new());
+		}
+		let mut body:Vec<u8> = Vec::new();
+		self.request("GET", &self.key(path), &[], Some((start, end)))?.into_reader().read_to_end(&mut body)?;
+		if body.len() != (end - start) as usize {
+			return Err(FileRef::new(path).error(FileOperation::Read, format!("Range {start}..{end} is out of bounds.")));
+		}
+		Ok(body)
+	}
+
+	fn write_at(&self, path:&str, _offset:Option<u64>, _data:&[u8]) -> Result<(), Box<dyn Error>> {
+		Err(read_only_error(path))
+	}
+
+	fn append(&self, path:&str, _data:&[u8]) -> Result<(), Box<dyn Error>> {
+		Err(read_only_error(path))
+	}
+
+	fn copy(&self, _source:&str, target:&str) -> Result<u64, Box<dyn Error>> {
+		Err(read_only_error(target))
+	}
+
+	fn remove(&self, path:&str) -> Result<(), Box<dyn Error>> {
+		Err(read_only_error(path))
+	}
+
+	fn list(&self, path:&str) -> Result<Vec<String>, Box<dyn Error>> {
+		let key:String = self.key(path);
+		let prefix:String = if key.is_empty() { key } else { key + S3_KEY_SEPARATOR };
+		Ok(self.list_keys(&prefix, None)?.iter().map(|key| self.path(key)).collect())
+	}
+}
+
+
+
+pub(crate) struct S3Signer {
+	pub(crate) access_key_id:String,
+	pub(crate) secret_access_key:String,
+	pub(crate) region:String
+}
+impl S3Signer {
+
+	/// Create the authorization header of a request using AWS signature version 4. The headers must have lowercase names, be sorted by name and contain the 'x-amz-content-sha256' header.
+	pub(crate) fn authorization(&self, method:&str, canonical_uri:&str, canonical_query:&str, headers:&[(&str, &str)], amz_date:&str) -> String {
+		let date:&str = &amz_date[..8];
+		let scope:String = format!("{date}/{}/{S3_SERVICE_NAME}/aws4_request", self.region);
+		let signed_headers:String = headers.iter().map(|(name, _)| *name).collect::<Vec<&str>>().join(";");
+		let canonical_headers:String = headers.iter().map(|(name, value)| format!("{name}:{}\n", value.trim())).collect();
+		let payload_hash:&str = headers.iter().find(|(name, _)| *name == "x-amz-content-sha256").map(|(_, value)| *value).unwrap_or(EMPTY_PAYLOAD_HASH);
+
+		let canonical_request:String = format!("{method}\n{canonical_uri}\n{canonical_query}\n{canonical_headers}\n{signed_headers}\n{payload_hash}");
+		let string_to_sign:String = format!("{S3_SIGNING_ALGORITHM}\n{amz_date}\n{scope}\n{}", HashAlgorithm::Sha256.hash_bytes(canonical_request.as_bytes()));
+
+		let mut signing_key:Vec<u8> = hmac_sha256(format!("AWS4{}", self.secret_access_key).as_bytes(), date.as_bytes());
+		for scope_part in [self.region.as_str(), S3_SERVICE_NAME, "aws4_request"] {
+			signing_key = hmac_sha256(&signing_key, scope_part.as_bytes());
+		}
+		let signature:String = hmac_sha256(&signing_key, string_to_sign.as_bytes()).iter().map(|byte| format!("{byte:02x}")).collect();
+		format!("{S3_SIGNING_ALGORITHM} Credential={}/{scope},SignedHeaders={signed_headers},Signature={signature}", self.access_key_id)
+	}
+}
+
+
+
+/// Calculate the HMAC-SHA256 of the data.
+pub(crate) fn hmac_sha256(key:&[u8], data:&[u8]) -> Vec<u8> {
+	let mut mac:Hmac<Sha256> = <Hmac<Sha256> as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length");
+	mac.update(data);
+	mac.finalize().into_bytes().to_vec()
+}
+
+/// Create a canonical query string, with encoded parameters sorted by name.
+pub(crate) fn canonical_query(query:&[(&str, &str)]) -> String {
+	let mut parameters:Vec<String> = query.iter().map(|(name, value)| format!("{}={}", percent_encode(name, false), percent_encode(value, false))).collect();
+	parameters.sort();
+	parameters.join("&")
+}
+
+/// Format a time in the 'YYYYMMDDTHHMMSSZ' format used by AWS.
+pub(crate) fn amz_date(time:SystemTime) -> String {
+	let seconds:u64 = time.duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or_default();
+	let (year, month, day):(i64, u32, u32) = civil_from_days((seconds / 86400) as i64);
+	let seconds_of_day:u64 = seconds % 86400;
+	format!("{year:04}{month:02}{day:02}T{:02}{:02}{:02}Z", seconds_of_day / 3600, seconds_of_day / 60 % 60, seconds_of_day % 60)
+}
+
+/// Parse a ListObjectsV2 response. Returns the keys of all objects and common prefixes, and the continuation token if the listing is truncated.
+pub(crate) fn parse_list_objects(xml:&str) -> Result<(Vec<String>, Option<String>), Box<dyn Error>> {
+	let mut reader:Reader<&[u8]> = Reader::from_str(xml);
+	let mut elements:Vec<String> = Vec::new();
+	let mut keys:Vec<String> = Vec::new();
+	let mut truncated:bool = false;
+	let mut next_token:Option<String> = None;
+	loop {
+		match reader.read_event()? {
+			Event::Start(element) => elements.push(String::from_utf8_lossy(element.local_name().as_ref()).into_owned()),
+			Event::End(_) => { elements.pop(); },
+			Event::Text(text) => {
+				let value:String = text.unescape()?.into_owned();
+				match (elements.len().checked_sub(2).map(|index| elements[index].as_str()), elements.last().map(|element| element.as_str())) {
+					(Some("Contents"), Some("Key")) | (Some("CommonPrefixes"), Some("Prefix")) => keys.push(value),
+					(_, Some("IsTruncated")) => truncated = value == "true",
+					(_, Some("NextContinuationToken")) => next_token = Some(value),
+					_ => {}
+				}
+			},
+			Event::Eof => break,
+			_ => {}
+		}
+	}
+	Ok((keys, if truncated { next_token } else { None }))
+}
\ No newline at end of file
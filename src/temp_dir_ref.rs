@@ -0,0 +1,55 @@
+use std::error::Error;
+use crate::{ FileOperation, FileRef, unique_temp_path };
+
+
+
+pub struct TempDirRef {
+	dir:FileRef,
+	keep:bool
+}
+impl TempDirRef {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new uniquely named dir in the temp dir of the OS. The dir and all of its contents are deleted when the TempDirRef is dropped, unless `keep` is called.
+	pub fn new(prefix:&str) -> Result<TempDirRef, Box<dyn Error>> {
+		use std::{ fs::create_dir, io::ErrorKind };
+
+		// Dots in the name would make the dir look like a file.
+		let prefix:String = prefix.replace('.', "_");
+		loop {
+			let dir:FileRef = unique_temp_path(&prefix, "");
+			match create_dir(dir.os_path()) {
+				Ok(_) => return Ok(TempDirRef { dir, keep: false }),
+				Err(error) if error.kind() == ErrorKind::AlreadyExists => continue,
+				Err(error) => return Err(dir.error(FileOperation::Create, error))
+			}
+		}
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the FileRef of the temp dir. Children can be composed on the returned FileRef.
+	pub fn path(&self) -> FileRef {
+		self.dir.clone()
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Persist the temp dir, returning its FileRef. The dir will no longer be deleted on drop.
+	pub fn keep(mut self) -> FileRef {
+		self.keep = true;
+		self.dir.clone()
+	}
+}
+impl Drop for TempDirRef {
+	fn drop(&mut self) {
+		if !self.keep && self.dir.exists() {
+			let _ = self.dir.delete();
+		}
+	}
+}
\ No newline at end of file
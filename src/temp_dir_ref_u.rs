@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, TempDirRef };
+
+
+
+	#[test]
+	fn test_temp_dir_created() {
+		let temp_dir:TempDirRef = TempDirRef::new("test_").unwrap();
+		assert!(temp_dir.path().exists());
+		assert!(temp_dir.path().is_dir());
+		assert!(temp_dir.path().name().starts_with("test_"));
+	}
+
+	#[test]
+	fn test_temp_dir_prefix_without_dots() {
+		let temp_dir:TempDirRef = TempDirRef::new("test.dir").unwrap();
+		assert!(temp_dir.path().name().starts_with("test_dir"));
+		assert!(temp_dir.path().is_dir());
+	}
+
+	#[test]
+	fn test_temp_dir_children() {
+		let temp_dir:TempDirRef = TempDirRef::new("test_").unwrap();
+		(temp_dir.path() + "/file1.txt").write("one").unwrap();
+		(temp_dir.path() + "/sub_dir/file2.txt").write("two").unwrap();
+		assert_eq!(temp_dir.path().list_files_recurse().len(), 2);
+	}
+
+	#[test]
+	fn test_temp_dir_deleted_on_drop() {
+		let temp_dir:TempDirRef = TempDirRef::new("test_").unwrap();
+		let dir:FileRef = temp_dir.path();
+		(dir.clone() + "/sub_dir/file.txt").write("contents").unwrap();
+		drop(temp_dir);
+		assert!(!dir.exists());
+	}
+
+	#[test]
+	fn test_temp_dir_keep() {
+		let temp_dir:TempDirRef = TempDirRef::new("test_").unwrap();
+		let dir:FileRef = temp_dir.keep();
+		assert!(dir.exists());
+		dir.delete().unwrap();
+	}
+}
\ No newline at end of file
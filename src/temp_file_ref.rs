@@ -0,0 +1,91 @@
+use std::{ error::Error, ops::Deref, sync::atomic::{ AtomicUsize, Ordering } };
+use crate::{ FileOperation, FileRef, platform::{ os_temp_dir, process_id, unix_nanos } };
+
+
+
+static TEMP_NAME_COUNTER:AtomicUsize = AtomicUsize::new(0);
+const DEFAULT_TEMP_EXTENSION:&str = "tmp";
+
+
+
+pub struct TempFileRef {
+	file_ref:FileRef,
+	keep:bool
+}
+impl TempFileRef {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new uniquely named file in the temp dir of the OS. The file is deleted when the TempFileRef is dropped, unless `keep` is called.
+	pub fn new(extension:&str) -> Result<TempFileRef, Box<dyn Error>> {
+		TempFileRef::new_in(&os_temp_dir(), extension)
+	}
+
+	/// Create a new uniquely named file in the given dir, which must exist. Useful to stage a file on the same filesystem it will be moved to. The file is deleted when the TempFileRef is dropped, unless `keep` is called.
+	pub fn new_in(dir:&FileRef, extension:&str) -> Result<TempFileRef, Box<dyn Error>> {
+		use std::{ fs::OpenOptions, io::ErrorKind };
+
+		dir.require_std_file_system(FileOperation::Create)?;
+		let extension:&str = if extension.trim_start_matches('.').is_empty() { DEFAULT_TEMP_EXTENSION } else { extension.trim_start_matches('.') };
+		loop {
+			let file_ref:FileRef = unique_path_in(dir, "file_ref_", extension);
+			match OpenOptions::new().write(true).create_new(true).open(file_ref.os_path()) {
+				Ok(_) => return Ok(TempFileRef { file_ref, keep: false }),
+				Err(error) if error.kind() == ErrorKind::AlreadyExists => continue,
+				Err(error) => return Err(file_ref.error(FileOperation::Create, error))
+			}
+		}
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the FileRef of the temp file.
+	pub fn file_ref(&self) -> &FileRef {
+		&self.file_ref
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Persist the temp file, returning its FileRef. The file will no longer be deleted on drop.
+	pub fn keep(mut self) -> FileRef {
+		self.keep = true;
+		self.file_ref.clone()
+	}
+}
+impl Deref for TempFileRef {
+	type Target = FileRef;
+
+	fn deref(&self) -> &Self::Target {
+		&self.file_ref
+	}
+}
+impl Drop for TempFileRef {
+	fn drop(&mut self) {
+		if !self.keep && self.file_ref.exists() {
+			let _ = self.file_ref.delete();
+		}
+	}
+}
+
+
+
+/// Create a path in the temp dir of the OS that is unique for this process. An empty extension creates a path without extension.
+pub(crate) fn unique_temp_path(prefix:&str, extension:&str) -> FileRef {
+	unique_path_in(&os_temp_dir(), prefix, extension)
+}
+
+/// Create a path in the given dir that is unique for this process. An empty extension creates a path without extension.
+fn unique_path_in(dir:&FileRef, prefix:&str, extension:&str) -> FileRef {
+	let index:usize = TEMP_NAME_COUNTER.fetch_add(1, Ordering::SeqCst);
+	let name:String = format!("{prefix}{}_{}_{index}", process_id(), unix_nanos());
+	let dir:FileRef = dir.clone().trim_end_matches("/");
+	if extension.is_empty() {
+		dir + "/" + &name
+	} else {
+		dir + "/" + &name + "." + extension
+	}
+}
\ No newline at end of file
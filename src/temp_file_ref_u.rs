@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, TempFileRef };
+
+
+
+	#[test]
+	fn test_temp_file_created() {
+		let temp_file:TempFileRef = FileRef::temp("txt").unwrap();
+		assert!(temp_file.exists());
+		assert_eq!(temp_file.extension(), Some("txt"));
+		assert!(temp_file.path().starts_with(FileRef::new(&std::env::temp_dir().display().to_string()).path()));
+	}
+
+	#[test]
+	fn test_temp_file_unique() {
+		let first:TempFileRef = FileRef::temp("txt").unwrap();
+		let second:TempFileRef = FileRef::temp("txt").unwrap();
+		assert_ne!(first.path(), second.path());
+	}
+
+	#[test]
+	fn test_temp_file_deleted_on_drop() {
+		let temp_file:TempFileRef = TempFileRef::new("txt").unwrap();
+		let file_ref:FileRef = temp_file.file_ref().clone();
+		temp_file.write("temporary").unwrap();
+		drop(temp_file);
+		assert!(!file_ref.exists());
+	}
+
+	#[test]
+	fn test_temp_file_keep() {
+		let temp_file:TempFileRef = TempFileRef::new("").unwrap();
+		let file_ref:FileRef = temp_file.keep();
+		assert!(file_ref.exists());
+		assert_eq!(file_ref.extension(), Some("tmp"));
+		file_ref.delete().unwrap();
+	}
+}
\ No newline at end of file
@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+use crate::{ DirBuilder, DirEntries, FileRef, SEPARATOR };
+
+
+
+const MAX_REPORTED_DIFFERENCES:usize = 10;
+
+
+
+/// Assert that a file or dir exists.
+#[macro_export]
+macro_rules! assert_exists {
+	($path:expr $(,)?) => {
+		$crate::testing::assert_exists(&$path)
+	};
+}
+
+/// Assert that a file or dir does not exist.
+#[macro_export]
+macro_rules! assert_not_exists {
+	($path:expr $(,)?) => {
+		$crate::testing::assert_not_exists(&$path)
+	};
+}
+
+/// Assert that a file has the expected contents, printing the differing lines if it does not.
+#[macro_export]
+macro_rules! assert_content_eq {
+	($file:expr, $expected:expr $(,)?) => {
+		$crate::testing::assert_content_eq(&$file, $expected)
+	};
+}
+
+/// Assert that a dir contains exactly the entries of a `DirBuilder`, like one built with `dir_tree!`, printing all missing, unexpected and differing entries if it does not.
+#[macro_export]
+macro_rules! assert_dir_matches {
+	($dir:expr, $expected:expr $(,)?) => {
+		$crate::testing::assert_dir_matches(&$dir, &$expected)
+	};
+}
+
+
+
+/// Panic if the file or dir does not exist.
+#[track_caller]
+pub fn assert_exists(path:&FileRef) {
+	if !path.exists() {
+		let parent_contents:String = path.parent_dir().ok().filter(|parent| parent.exists()).map(|parent| {
+			let mut names:Vec<String> = parent.scanner().include_files().include_dirs().map(|entry| entry.name().to_string()).collect();
+			names.sort();
+			format!("\nParent dir contains: [{}]", names.join(", "))
+		}).unwrap_or_default();
+		panic!("Expected \"{}\" to exist, but it does not.{parent_contents}", path.path());
+	}
+}
+
+/// Panic if the file or dir exists.
+#[track_caller]
+pub fn assert_not_exists(path:&FileRef) {
+	if path.exists() {
+		panic!("Expected \"{}\" not to exist, but it does.", path.path());
+	}
+}
+
+/// Panic if the file does not have the expected contents.
+#[track_caller]
+pub fn assert_content_eq<T>(file:&FileRef, expected:T) where T:AsRef<[u8]> {
+	let actual:Vec<u8> = match file.read_bytes() {
+		Ok(actual) => actual,
+		Err(error) => panic!("Expected \"{}\" to have contents, but it could not be read: {error}", file.path())
+	};
+	if actual != expected.as_ref() {
+		panic!("Contents of \"{}\" differ from the expected contents:\n{}", file.path(), content_diff(expected.as_ref(), &actual));
+	}
+}
+
+/// Panic if the dir does not contain exactly the entries of the builder.
+#[track_caller]
+pub fn assert_dir_matches(dir:&FileRef, expected:&DirBuilder) {
+	let root:FileRef = dir.clone().absolute().trim_end_matches(SEPARATOR);
+	let entries:DirEntries = match DirEntries::collect(&root) {
+		Ok(entries) => entries,
+		Err(error) => panic!("Expected \"{}\" to be a dir, but it could not be scanned: {error}", dir.path())
+	};
+	let mut actual:BTreeMap<String, Option<Vec<u8>>> = BTreeMap::new();
+	for sub_dir in &entries.dirs {
+		actual.insert(sub_dir.relative_to_dir(&root).path().to_string(), None);
+	}
+	for (file, _) in &entries.files {
+		actual.insert(file.relative_to_dir(&root).path().to_string(), Some(std::fs::read(file.os_path()).unwrap_or_default()));
+	}
+
+	let expected:BTreeMap<String, Option<Vec<u8>>> = expected.expected_entries();
+	let mut problems:Vec<String> = Vec::new();
+	for (path, expected_entry) in &expected {
+		match (expected_entry, actual.get(path)) {
+			(_, None) => problems.push(format!("missing: {path}")),
+			(None, Some(Some(_))) => problems.push(format!("expected dir, found file: {path}")),
+			(Some(_), Some(None)) => problems.push(format!("expected file, found dir: {path}")),
+			(Some(expected_contents), Some(Some(actual_contents))) if expected_contents != actual_contents => problems.push(format!("differs: {path}\n{}", content_diff(expected_contents, actual_contents))),
+			_ => {}
+		}
+	}
+	for path in actual.keys().filter(|path| !expected.contains_key(*path)) {
+		problems.push(format!("unexpected: {path}"));
+	}
+	if !problems.is_empty() {
+		panic!("Dir \"{}\" does not match the expected tree:\n{}", dir.path(), problems.join("\n"));
+	}
+}
+
+
+
+/// Describe the differences between expected and actual contents. Text is compared line by line, binary data by size and the first differing byte.
+fn content_diff(expected:&[u8], actual:&[u8]) -> String {
+	match (std::str::from_utf8(expected), std::str::from_utf8(actual)) {
+		(Ok(expected), Ok(actual)) => {
+			let expected_lines:Vec<&str> = expected.lines().collect();
+			let actual_lines:Vec<&str> = actual.lines().collect();
+			let differing_lines:Vec<usize> = (0..expected_lines.len().max(actual_lines.len())).filter(|index| expected_lines.get(*index) != actual_lines.get(*index)).collect();
+			if differing_lines.is_empty() {
+				return "  contents only differ in line endings or a trailing newline".to_string();
+			}
+			let mut diff:Vec<String> = differing_lines.iter().take(MAX_REPORTED_DIFFERENCES).map(|index| {
+				let describe = |line:Option<&&str>| line.map(|line| format!("{line:?}")).unwrap_or_else(|| "<no line>".to_string());
+				format!("  line {}:\n    - {}\n    + {}", index + 1, describe(expected_lines.get(*index)), describe(actual_lines.get(*index)))
+			}).collect();
+			if differing_lines.len() > MAX_REPORTED_DIFFERENCES {
+				diff.push(format!("  ...and {} more differing lines", differing_lines.len() - MAX_REPORTED_DIFFERENCES));
+			}
+			diff.join("\n")
+		},
+		_ => {
+			let first_difference:usize = expected.iter().zip(actual).position(|(expected_byte, actual_byte)| expected_byte != actual_byte).unwrap_or(expected.len().min(actual.len()));
+			format!("  expected {} bytes, found {} bytes, first difference at byte {first_difference}", expected.len(), actual.len())
+		}
+	}
+}
\ No newline at end of file
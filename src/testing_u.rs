@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+	use std::{ any::Any, panic::{ AssertUnwindSafe, catch_unwind } };
+	use crate::{ FileRef, TempDirRef, assert_content_eq, assert_dir_matches, assert_exists, assert_not_exists, dir_tree };
+
+
+
+	/// Run the function and return the message it panicked with.
+	fn panic_message<T:FnOnce()>(function:T) -> String {
+		let payload:Box<dyn Any + Send> = catch_unwind(AssertUnwindSafe(function)).unwrap_err();
+		payload.downcast_ref::<String>().cloned().unwrap_or_else(|| payload.downcast_ref::<&str>().unwrap().to_string())
+	}
+
+
+
+	#[test]
+	fn test_assert_exists() {
+		let temp_dir:TempDirRef = TempDirRef::new("testing_exists_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/file.txt";
+		file.write("contents").unwrap();
+		assert_exists!(file);
+		assert_not_exists!(temp_dir.path() + "/other.txt");
+
+		let message:String = panic_message(|| assert_exists!(temp_dir.path() + "/flie.txt"));
+		assert!(message.contains("flie.txt\" to exist"));
+		assert!(message.contains("Parent dir contains: [file.txt]"));
+		assert!(panic_message(|| assert_not_exists!(file)).contains("not to exist"));
+	}
+
+	#[test]
+	fn test_assert_content_eq() {
+		let temp_dir:TempDirRef = TempDirRef::new("testing_content_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/file.txt";
+		file.write("a\nb\nc").unwrap();
+		assert_content_eq!(file, "a\nb\nc");
+
+		let message:String = panic_message(|| assert_content_eq!(file, "a\nx\nc\nd"));
+		assert!(message.contains("line 2:\n    - \"x\"\n    + \"b\""));
+		assert!(message.contains("line 4:\n    - \"d\"\n    + <no line>"));
+		assert!(!message.contains("line 1:"));
+	}
+
+	#[test]
+	fn test_assert_dir_matches() {
+		let temp_dir:TempDirRef = TempDirRef::new("testing_dir_test_").unwrap();
+		dir_tree! { "Cargo.toml" => "[package]", "src" => { "main.rs" => "fn main() {}" } }.build(&temp_dir.path()).unwrap();
+		assert_dir_matches!(temp_dir.path(), dir_tree! { "Cargo.toml" => "[package]", "src/main.rs" => "fn main() {}" });
+
+		let message:String = panic_message(|| assert_dir_matches!(temp_dir.path(), dir_tree! { "Cargo.toml" => "[lib]", "README.md" => "" }));
+		assert!(message.contains("missing: README.md"));
+		assert!(message.contains("unexpected: src\n"));
+		assert!(message.contains("unexpected: src/main.rs"));
+		assert!(message.contains("differs: Cargo.toml\n  line 1:\n    - \"[lib]\"\n    + \"[package]\""));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,69 @@
+use std::error::Error;
+use serde::{ Serialize, de::DeserializeOwned };
+use crate::{ ConfigFile, ConfigFormat, FileRef };
+
+
+
+/// A file bound to a serializable type. Writes are atomic and serialized between processes through the '.lock' sidecar of the file, so `update` is a safe read-modify-write.
+pub struct TypedFile<T:Serialize + DeserializeOwned> {
+	config_file:ConfigFile<T>
+}
+impl<T:Serialize + DeserializeOwned> TypedFile<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new typed file, detecting the format from the extension of the file.
+	pub fn new(file:&FileRef) -> Result<TypedFile<T>, Box<dyn Error>> {
+		Ok(TypedFile { config_file: ConfigFile::new(file)? })
+	}
+
+	/// Create a new typed file stored in a specific format, regardless of its extension.
+	pub fn with_format(file:&FileRef, format:ConfigFormat) -> TypedFile<T> {
+		TypedFile { config_file: ConfigFile::with_format(file, format) }
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the file the value is stored in.
+	pub fn file(&self) -> &FileRef {
+		self.config_file.file()
+	}
+
+	/// Get the format the value is stored in.
+	pub fn format(&self) -> ConfigFormat {
+		self.config_file.format()
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Load the value from the file. Writes replace the file atomically, so this never sees a half written value and does not need the lock.
+	pub fn load(&self) -> Result<T, Box<dyn Error>> {
+		self.config_file.load()
+	}
+
+	/// Save the value to the file while holding the lock.
+	pub fn save(&self, value:&T) -> Result<(), Box<dyn Error>> {
+		self.file().with_sidecar_lock(|| self.config_file.save(value))?
+	}
+}
+impl<T:Serialize + DeserializeOwned + Default> TypedFile<T> {
+
+	/// Load the value from the file, or return the default value if the file does not exist.
+	pub fn load_or_default(&self) -> Result<T, Box<dyn Error>> {
+		self.config_file.load_or_default()
+	}
+
+	/// Load the value, modify it with the given function and save it, all while holding the lock. Starts from the default value if the file does not exist. Returns the result of the function.
+	pub fn update<U, V>(&self, function:U) -> Result<V, Box<dyn Error>> where U:FnOnce(&mut T) -> V {
+		self.file().with_sidecar_lock(|| {
+			let mut value:T = self.config_file.load_or_default()?;
+			let result:V = function(&mut value);
+			self.config_file.save(&value)?;
+			Ok(result)
+		})?
+	}
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+	use std::{ sync::Arc, thread::{ self, JoinHandle } };
+	use serde::{ Deserialize, Serialize };
+	use crate::{ FileRef, TempDirRef, TypedFile };
+
+
+
+	#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+	struct Counter {
+		count:u32
+	}
+
+
+
+	#[test]
+	fn test_typed_file_load_and_save() {
+		let temp_dir:TempDirRef = TempDirRef::new("typed_file_test_").unwrap();
+		let typed_file:TypedFile<Counter> = TypedFile::new(&(temp_dir.path() + "/state.json")).unwrap();
+		assert!(typed_file.load().is_err());
+		assert_eq!(typed_file.load_or_default().unwrap(), Counter::default());
+
+		typed_file.save(&Counter { count: 5 }).unwrap();
+		assert_eq!(typed_file.load().unwrap(), Counter { count: 5 });
+	}
+
+	#[test]
+	fn test_typed_file_concurrent_updates() {
+		let temp_dir:TempDirRef = TempDirRef::new("typed_file_test_").unwrap();
+		let file:FileRef = temp_dir.path() + "/state.json";
+		let typed_file:Arc<TypedFile<Counter>> = Arc::new(TypedFile::new(&file).unwrap());
+
+		let handles:Vec<JoinHandle<()>> = (0..4).map(|_| {
+			let typed_file:Arc<TypedFile<Counter>> = typed_file.clone();
+			thread::spawn(move || for _ in 0..10 { typed_file.update(|counter| counter.count += 1).unwrap(); })
+		}).collect();
+		for handle in handles {
+			handle.join().unwrap();
+		}
+		assert_eq!(typed_file.load().unwrap(), Counter { count: 40 });
+		assert_eq!(typed_file.update(|counter| counter.count).unwrap(), 40);
+	}
+}
\ No newline at end of file
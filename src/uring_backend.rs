@@ -0,0 +1,340 @@
+use std::{ error::Error, ffi::CString, fs::File, io::{ Error as IoError, ErrorKind }, os::fd::{ AsRawFd, FromRawFd, OwnedFd }, time::{ Duration, SystemTime, UNIX_EPOCH } };
+use io_uring::{ IoUring, opcode, squeue::Entry, types };
+use crate::{ FileRef, FileOperation, SEPARATOR };
+
+
+
+const DEFAULT_QUEUE_DEPTH:u32 = 256;
+const URING_CHUNK_SIZE:usize = 128 * 1024;
+const IORING_ENTER_GETEVENTS:u32 = 1;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UringStat {
+	pub size:u64,
+	pub is_dir:bool,
+	pub modified:SystemTime
+}
+
+
+
+struct UringTransfer {
+	file_index:usize,
+	fd:i32,
+	offset:u64,
+	buffer:Vec<u8>,
+	done:usize
+}
+
+
+
+pub struct UringBackend {
+	ring:IoUring,
+	queue_depth:usize,
+	failed:bool
+}
+impl UringBackend {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new io_uring backend with the default queue depth. Fails if the kernel does not support io_uring or it is disabled.
+	pub fn new() -> Result<UringBackend, Box<dyn Error>> {
+		UringBackend::with_queue_depth(DEFAULT_QUEUE_DEPTH)
+	}
+
+	/// Create a new io_uring backend that keeps at most the given amount of operations in flight.
+	pub fn with_queue_depth(queue_depth:u32) -> Result<UringBackend, Box<dyn Error>> {
+		let ring:IoUring = IoUring::new(queue_depth.max(1)).map_err(|error| format!("Could not create io_uring instance. {error}"))?;
+		Ok(UringBackend { ring, queue_depth: queue_depth.max(1) as usize, failed: false })
+	}
+
+
+
+	/* BULK OPERATION METHODS */
+
+	/// Get the size, type and modification time of many files at once using batched statx calls. Entries that do not exist or can not be accessed are None.
+	pub fn stat_many(&mut self, files:&[FileRef]) -> Result<Vec<Option<UringStat>>, Box<dyn Error>> {
+		let paths:Vec<CString> = files.iter().map(|file| CString::new(file.path())).collect::<Result<Vec<CString>, _>>()?;
+		// SAFETY: statx is a plain C struct, for which all zeroes is a valid value.
+		let mut buffers:Vec<libc::statx> = vec![unsafe { std::mem::zeroed() }; files.len()];
+		let buffers_pointer:*mut libc::statx = buffers.as_mut_ptr();
+
+		let mut stats:Vec<Option<UringStat>> = Vec::with_capacity(files.len());
+		for chunk_start in (0..files.len()).step_by(self.queue_depth) {
+			let chunk_end:usize = (chunk_start + self.queue_depth).min(files.len());
+			let entries:Vec<Entry> = (chunk_start..chunk_end).map(|index| {
+				// SAFETY: The index is within the buffers, which are not moved until all operations completed.
+				let buffer:*mut types::statx = unsafe { buffers_pointer.add(index) } as *mut types::statx;
+				opcode::Statx::new(types::Fd(libc::AT_FDCWD), paths[index].as_ptr(), buffer).mask(libc::STATX_TYPE | libc::STATX_SIZE | libc::STATX_MTIME).build()
+			}).collect();
+			for (index, result) in (chunk_start..chunk_end).zip(self.run(&entries)?) {
+				let buffer:&libc::statx = &buffers[index];
+				stats.push(if result < 0 { None } else {
+					Some(UringStat {
+						size: buffer.stx_size,
+						is_dir: buffer.stx_mode as u32 & libc::S_IFMT == libc::S_IFDIR,
+						modified: UNIX_EPOCH + Duration::new(buffer.stx_mtime.tv_sec.max(0) as u64, buffer.stx_mtime.tv_nsec)
+					})
+				});
+			}
+		}
+		Ok(stats)
+	}
+
+	/// Read the contents of many files at once. Large files are read in chunks in parallel. Fails if any of the files can not be read.
+	pub fn read_many(&mut self, files:&[FileRef]) -> Result<Vec<Vec<u8>>, Box<dyn Error>> {
+		let handles:Vec<File> = UringBackend::open_all(files)?;
+		let sizes:Vec<u64> = self.sizes(files)?;
+		let mut transfers:Vec<UringTransfer> = UringBackend::chunk_transfers(&handles, &sizes);
+		self.run_transfers(&mut transfers, false, files)?;
+
+		let mut contents:Vec<Vec<u8>> = sizes.iter().map(|size| Vec::with_capacity(*size as usize)).collect();
+		for transfer in transfers {
+			contents[transfer.file_index].extend_from_slice(&transfer.buffer);
+		}
+		Ok(contents)
+	}
+
+	/// Copy many files at once. The parent dirs of the targets are created if they do not exist. Returns the total number of bytes copied.
+	pub fn copy_many(&mut self, pairs:&[(FileRef, FileRef)]) -> Result<u64, Box<dyn Error>> {
+		let sources:Vec<FileRef> = pairs.iter().map(|(source, _)| source.clone()).collect();
+		let targets:Vec<FileRef> = pairs.iter().map(|(_, target)| target.clone()).collect();
+		let source_handles:Vec<File> = UringBackend::open_all(&sources)?;
+		let sizes:Vec<u64> = self.sizes(&sources)?;
+		let mut target_handles:Vec<File> = Vec::with_capacity(targets.len());
+		for (target, size) in targets.iter().zip(&sizes) {
+			target.guarantee_parent_dir()?;
+			let handle:File = File::create(target.os_path()).map_err(|error| target.error(FileOperation::Create, error))?;
+			handle.set_len(*size)?;
+			target_handles.push(handle);
+		}
+
+		// Copy in batches of chunks to limit memory usage.
+		let mut transfers:Vec<UringTransfer> = UringBackend::chunk_transfers(&source_handles, &sizes);
+		let mut bytes_copied:u64 = 0;
+		while !transfers.is_empty() {
+			let mut batch:Vec<UringTransfer> = transfers.drain(..transfers.len().min(self.queue_depth)).collect();
+			self.run_transfers(&mut batch, false, &sources)?;
+			for transfer in &mut batch {
+				transfer.fd = target_handles[transfer.file_index].as_raw_fd();
+				transfer.done = 0;
+				bytes_copied += transfer.buffer.len() as u64;
+			}
+			self.run_transfers(&mut batch, true, &targets)?;
+		}
+		Ok(bytes_copied)
+	}
+
+	/// List all entries in the dir and its sub-dirs with their stats. Unlike the FileScanner, dirs are detected by their actual type instead of their extension. The dirs of each level are opened in batches on the ring and their entries are statted in batches. Symlinks to dirs are listed but not followed.
+	pub fn scan(&mut self, dir:&FileRef) -> Result<Vec<(FileRef, UringStat)>, Box<dyn Error>> {
+		let mut results:Vec<(FileRef, UringStat)> = Vec::new();
+		let mut pending_dirs:Vec<FileRef> = vec![dir.clone().absolute()];
+		while !pending_dirs.is_empty() {
+			let handles:Vec<OwnedFd> = self.open_dirs(&pending_dirs)?;
+			let mut entries:Vec<FileRef> = Vec::new();
+			let mut symlinks:Vec<bool> = Vec::new();
+			for (dir, handle) in pending_dirs.iter().zip(&handles) {
+				let dir_path:&str = dir.path().trim_end_matches(SEPARATOR);
+				for (name, entry_type) in UringBackend::read_dir_entries(handle).map_err(|error| dir.error(FileOperation::Scan, error))? {
+					entries.push(FileRef::new(&format!("{dir_path}{SEPARATOR}{name}")));
+					symlinks.push(entry_type == libc::DT_LNK);
+				}
+			}
+			drop(handles);
+
+			pending_dirs = Vec::new();
+			let stats:Vec<Option<UringStat>> = self.stat_many(&entries)?;
+			for ((entry, is_symlink), stat) in entries.into_iter().zip(symlinks).zip(stats) {
+				if let Some(stat) = stat {
+					if stat.is_dir && !is_symlink {
+						pending_dirs.push(entry.clone());
+					}
+					results.push((entry, stat));
+				}
+			}
+		}
+		Ok(results)
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Open all dirs for reading their entries, submitting the opens in batches.
+	fn open_dirs(&mut self, dirs:&[FileRef]) -> Result<Vec<OwnedFd>, Box<dyn Error>> {
+		let paths:Vec<CString> = dirs.iter().map(|dir| CString::new(if dir.path().is_empty() { SEPARATOR } else { dir.path() })).collect::<Result<Vec<CString>, _>>()?;
+		let mut handles:Vec<OwnedFd> = Vec::with_capacity(dirs.len());
+		for chunk_start in (0..dirs.len()).step_by(self.queue_depth) {
+			let chunk_end:usize = (chunk_start + self.queue_depth).min(dirs.len());
+			let entries:Vec<Entry> = (chunk_start..chunk_end).map(|index| {
+				opcode::OpenAt::new(types::Fd(libc::AT_FDCWD), paths[index].as_ptr()).flags(libc::O_RDONLY | libc::O_DIRECTORY | libc::O_CLOEXEC).build()
+			}).collect();
+			let results:Vec<i32> = self.run(&entries)?;
+
+			// Take ownership of every opened dir before reporting a failure, so none of them leak.
+			let mut failure:Option<(usize, i32)> = None;
+			for (index, result) in (chunk_start..chunk_end).zip(results) {
+				if result < 0 {
+					failure = failure.or(Some((index, result)));
+				} else {
+					// SAFETY: A successful openat returns a new file descriptor that nothing else owns.
+					handles.push(unsafe { OwnedFd::from_raw_fd(result) });
+				}
+			}
+			if let Some((index, result)) = failure {
+				return Err(dirs[index].error(FileOperation::Scan, IoError::from_raw_os_error(-result)));
+			}
+		}
+		Ok(handles)
+	}
+
+	/// Read the names and types of all entries in an open dir, skipping "." and "..". io_uring has no opcode for listing dirs, so this uses getdents64 directly. Names that are not valid UTF-8 are skipped.
+	fn read_dir_entries(handle:&OwnedFd) -> Result<Vec<(String, u8)>, IoError> {
+		const NAME_OFFSET:usize = 19;
+		let mut buffer:Vec<u8> = vec![0; 32 * 1024];
+		let mut entries:Vec<(String, u8)> = Vec::new();
+		loop {
+			// SAFETY: The buffer is valid for writes of its full length.
+			let length:i64 = unsafe { libc::syscall(libc::SYS_getdents64, handle.as_raw_fd(), buffer.as_mut_ptr(), buffer.len()) };
+			if length < 0 {
+				let error:IoError = IoError::last_os_error();
+				if error.kind() == ErrorKind::Interrupted {
+					continue;
+				}
+				return Err(error);
+			} else if length == 0 {
+				return Ok(entries);
+			}
+
+			// Each record is a linux_dirent64: inode (8), offset (8), record length (2), type (1) and a nul terminated name.
+			let mut offset:usize = 0;
+			while offset < length as usize {
+				let record_length:usize = u16::from_ne_bytes([buffer[offset + 16], buffer[offset + 17]]) as usize;
+				let entry_type:u8 = buffer[offset + 18];
+				let name:&[u8] = &buffer[offset + NAME_OFFSET..offset + record_length];
+				let name:&[u8] = &name[..name.iter().position(|byte| *byte == 0).unwrap_or(name.len())];
+				if name != b"." && name != b".." {
+					if let Ok(name) = std::str::from_utf8(name) {
+						entries.push((name.to_owned(), entry_type));
+					}
+				}
+				offset += record_length;
+			}
+		}
+	}
+
+	/// Open all files for reading.
+	fn open_all(files:&[FileRef]) -> Result<Vec<File>, Box<dyn Error>> {
+		files.iter().map(|file| {
+			file.require_std_file_system(FileOperation::Read)?;
+			File::open(file.os_path()).map_err(|error| file.error(FileOperation::Read, error))
+		}).collect()
+	}
+
+	/// Get the sizes of all files, failing if any does not exist.
+	fn sizes(&mut self, files:&[FileRef]) -> Result<Vec<u64>, Box<dyn Error>> {
+		files.iter().zip(self.stat_many(files)?).map(|(file, stat)| stat.map(|stat| stat.size).ok_or_else(|| file.error(FileOperation::Read, "File does not exist."))).collect()
+	}
+
+	/// Split the given files into transfers of at most one chunk each.
+	fn chunk_transfers(handles:&[File], sizes:&[u64]) -> Vec<UringTransfer> {
+		let mut transfers:Vec<UringTransfer> = Vec::new();
+		for file_index in 0..handles.len() {
+			for offset in (0..sizes[file_index]).step_by(URING_CHUNK_SIZE) {
+				let length:usize = (sizes[file_index] - offset).min(URING_CHUNK_SIZE as u64) as usize;
+				transfers.push(UringTransfer { file_index, fd: handles[file_index].as_raw_fd(), offset, buffer: vec![0; length], done: 0 });
+			}
+		}
+		transfers
+	}
+
+	/// Read or write the buffers of all transfers, resubmitting the remainder of short transfers until all are done.
+	fn run_transfers(&mut self, transfers:&mut [UringTransfer], write:bool, files:&[FileRef]) -> Result<(), Box<dyn Error>> {
+		loop {
+			let pending:Vec<usize> = (0..transfers.len()).filter(|index| transfers[*index].done < transfers[*index].buffer.len()).take(self.queue_depth).collect();
+			if pending.is_empty() {
+				return Ok(());
+			}
+			let entries:Vec<Entry> = pending.iter().map(|index| {
+				let transfer:&mut UringTransfer = &mut transfers[*index];
+				let pointer:*mut u8 = transfer.buffer[transfer.done..].as_mut_ptr();
+				let length:u32 = (transfer.buffer.len() - transfer.done) as u32;
+				let offset:u64 = transfer.offset + transfer.done as u64;
+				if write {
+					opcode::Write::new(types::Fd(transfer.fd), pointer, length).offset(offset).build()
+				} else {
+					opcode::Read::new(types::Fd(transfer.fd), pointer, length).offset(offset).build()
+				}
+			}).collect();
+			for (index, result) in pending.iter().zip(self.run(&entries)?) {
+				let transfer:&mut UringTransfer = &mut transfers[*index];
+				let operation:FileOperation = if write { FileOperation::Write } else { FileOperation::Read };
+				if result < 0 {
+					return Err(files[transfer.file_index].error(operation, IoError::from_raw_os_error(-result)));
+				} else if result == 0 {
+					return Err(files[transfer.file_index].error(operation, "File changed size during the operation."));
+				}
+				transfer.done += result as usize;
+			}
+		}
+	}
+
+	/// Submit a batch of at most `queue_depth` entries and wait for all of them. Returns the results in the order of the entries. The entries point into buffers owned by the caller, so this never returns while the kernel may still use them. Interrupted waits are retried. If the ring itself fails, the operations the kernel already accepted are awaited first and the backend refuses further work, because the entries it did not accept are still queued.
+	fn run(&mut self, entries:&[Entry]) -> Result<Vec<i32>, Box<dyn Error>> {
+		if self.failed {
+			return Err("The io_uring instance can not be used after a previous failure.".into());
+		}
+
+		let mut queued:usize = 0;
+		let mut queue_error:Option<String> = None;
+		for (index, entry) in entries.iter().enumerate() {
+			// SAFETY: All buffers referenced by the entries are owned by the caller and outlive this call, which waits for all submitted operations to complete.
+			if let Err(error) = unsafe { self.ring.submission().push(&entry.clone().user_data(index as u64)) } {
+				queue_error = Some(format!("Could not queue io_uring operation. {error}"));
+				break;
+			}
+			queued += 1;
+		}
+
+		let mut results:Vec<i32> = vec![0; entries.len()];
+		let mut completed:usize = 0;
+		while completed < queued {
+			if let Err(error) = self.ring.submit_and_wait(queued - completed) {
+				if !matches!(error.raw_os_error(), Some(libc::EINTR | libc::EAGAIN | libc::EBUSY)) {
+					self.failed = true;
+					let in_flight:usize = queued - self.ring.submission().len();
+					self.drain(in_flight, &mut results, &mut completed);
+					return Err(format!("Could not submit io_uring operations. {error}").into());
+				}
+			}
+			UringBackend::reap(&mut self.ring, &mut results, &mut completed);
+		}
+		match queue_error {
+			Some(error) => Err(error.into()),
+			None => Ok(results)
+		}
+	}
+
+	/// Wait without submitting anything until the given amount of operations completed. Aborts if the wait itself fails, as returning would free buffers the kernel may still write to.
+	fn drain(&mut self, in_flight:usize, results:&mut [i32], completed:&mut usize) {
+		UringBackend::reap(&mut self.ring, results, completed);
+		while *completed < in_flight {
+			// SAFETY: Submits nothing and only waits for completions.
+			if let Err(error) = unsafe { self.ring.submitter().enter::<libc::sigset_t>(0, (in_flight - *completed) as u32, IORING_ENTER_GETEVENTS, None) } {
+				if error.raw_os_error() != Some(libc::EINTR) {
+					std::process::abort();
+				}
+			}
+			UringBackend::reap(&mut self.ring, results, completed);
+		}
+	}
+
+	/// Store the results of all available completions.
+	fn reap(ring:&mut IoUring, results:&mut [i32], completed:&mut usize) {
+		for completion in ring.completion() {
+			results[completion.user_data() as usize] = completion.result();
+			*completed += 1;
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FileRef, TempDirRef, UringBackend, UringStat };
+
+
+
+	/// Create a backend, or None if io_uring is not available in the test environment.
+	fn create_backend() -> Option<UringBackend> {
+		UringBackend::with_queue_depth(4).ok()
+	}
+
+
+
+	#[test]
+	fn test_uring_stat_many() {
+		let Some(mut backend) = create_backend() else { return };
+		let temp_dir:TempDirRef = TempDirRef::new("uring_test_").unwrap();
+		(temp_dir.path() + "/file.txt").write("12345").unwrap();
+		let stats:Vec<Option<UringStat>> = backend.stat_many(&[temp_dir.path() + "/file.txt", temp_dir.path(), temp_dir.path() + "/missing.txt"]).unwrap();
+		assert_eq!(stats[0].map(|stat| (stat.size, stat.is_dir)), Some((5, false)));
+		assert!(stats[1].unwrap().is_dir);
+		assert!(stats[2].is_none());
+	}
+
+	#[test]
+	fn test_uring_read_and_copy_many() {
+		let Some(mut backend) = create_backend() else { return };
+		let temp_dir:TempDirRef = TempDirRef::new("uring_test_").unwrap();
+		let large_contents:Vec<u8> = (0..400_000).map(|index| (index % 251) as u8).collect();
+		let files:Vec<FileRef> = (0..6).map(|index| temp_dir.path() + &format!("/source_{index}.bin")).collect();
+		for (index, file) in files.iter().enumerate() {
+			if index == 0 { file.write_bytes(&large_contents).unwrap() } else { file.write(&format!("file {index}")).unwrap() }
+		}
+
+		let contents:Vec<Vec<u8>> = backend.read_many(&files).unwrap();
+		assert_eq!(contents[0], large_contents);
+		assert_eq!(contents[5], b"file 5");
+
+		let pairs:Vec<(FileRef, FileRef)> = files.iter().map(|file| (file.clone(), temp_dir.path() + "/copies/" + file.name())).collect();
+		assert_eq!(backend.copy_many(&pairs).unwrap(), large_contents.len() as u64 + 6 * 5);
+		assert_eq!((temp_dir.path() + "/copies/source_0.bin").read_bytes().unwrap(), large_contents);
+		assert_eq!((temp_dir.path() + "/copies/source_3.bin").read().unwrap(), "file 3");
+		assert!(backend.read_many(&[temp_dir.path() + "/missing.bin"]).is_err());
+	}
+
+	#[test]
+	fn test_uring_scan() {
+		let Some(mut backend) = create_backend() else { return };
+		let temp_dir:TempDirRef = TempDirRef::new("uring_test_").unwrap();
+		(temp_dir.path() + "/a.txt").write("a").unwrap();
+		(temp_dir.path() + "/sub/b.txt").write("bb").unwrap();
+		(temp_dir.path() + "/sub/no_extension_dir").create().unwrap();
+		std::os::unix::fs::symlink("..", (temp_dir.path() + "/sub/loop").os_path()).unwrap();
+
+		let mut entries:Vec<(String, u64, bool)> = backend.scan(&temp_dir.path()).unwrap().into_iter().map(|(entry, stat)| (entry.name().to_owned(), if stat.is_dir { 0 } else { stat.size }, stat.is_dir)).collect();
+		entries.sort();
+		assert_eq!(entries, vec![("a.txt".to_owned(), 1, false), ("b.txt".to_owned(), 2, false), ("loop".to_owned(), 0, true), ("no_extension_dir".to_owned(), 0, true), ("sub".to_owned(), 0, true)]);
+	}
+}
\ No newline at end of file